@@ -1032,6 +1032,8 @@ impl<'tu, 'ge> TypeRef<'tu, 'ge> {
 			} else {
 				"&mut self".to_string()
 			}
+		} else if is_method_const {
+			"&self".to_string()
 		} else {
 			"self".to_string()
 		}
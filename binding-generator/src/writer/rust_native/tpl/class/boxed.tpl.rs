@@ -9,7 +9,9 @@ opencv_type_boxed! { {{rust_local}} }
 impl Drop for {{rust_local}} {
 	fn drop(&mut self) {
 		extern "C" { fn cv_{{rust_local}}_delete(instance: {{rust_extern_mut}}); }
-		unsafe { cv_{{rust_local}}_delete(self.as_raw_mut_{{rust_local}}()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_{{rust_local}}_delete(self.as_raw_mut_{{rust_local}}()) };
+		}
 	}
 }
 
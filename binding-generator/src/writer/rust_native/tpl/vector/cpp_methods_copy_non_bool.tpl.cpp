@@ -10,4 +10,8 @@ const {{inner_cpp_extern_return}}* cv_{{rust_localalias}}_data(const {{cpp_full}
 		return new {{cpp_full}}(*instance);
 	}
 
+	{{cpp_extern_return}} cv_{{rust_localalias}}_from_slice(const {{inner_cpp_extern_return}}* data, size_t len) {
+		return new {{cpp_full}}(data, data + len);
+	}
+
 
@@ -241,7 +241,7 @@ pub fn gen_wrapper(opencv_header_dir: &Path, opencv: &Library, generator_build:
 		writeln!(&mut sys_rs)?;
 	}
 	writeln!(&mut hub_rs, "pub mod types;")?;
-	writeln!(&mut hub_rs, "#[doc(hidden)]")?;
+	writeln!(&mut hub_rs, "/// Raw FFI functions used by the generated bindings, the API of this module is unstable and can change between releases")?;
 	writeln!(&mut hub_rs, "pub mod sys;")?;
 
 	add_manual(&mut types_rs, "types")?;
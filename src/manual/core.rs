@@ -1,15 +1,23 @@
 pub use affine3::*;
 pub use CV_MAKETYPE as CV_MAKE_TYPE;
+pub use depth::*;
+pub use destructor_guard::*;
+pub use file_storage::*;
 pub use gpumat::*;
 pub use input_output_array::*;
+pub use lut::*;
 pub use mat::*;
 pub use matx::*;
+pub use min_max::*;
+pub use norm::*;
 pub use point::*;
 pub use point3::*;
 pub use ptr::*;
 pub use rect::*;
+pub use rng::*;
 pub use size::*;
 pub use sized::*;
+pub use sparse_mat::*;
 pub use vec::*;
 pub use vector::*;
 
@@ -30,16 +38,28 @@ macro_rules! valid_types {
 }
 
 mod affine3;
+mod colors;
+mod depth;
+mod destructor_guard;
+mod file_storage;
 mod gpumat;
 mod input_output_array;
+mod lut;
 mod mat;
 mod matx;
+mod min_max;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conv;
+mod norm;
 mod point3;
 mod point;
 pub(crate) mod ptr;
+mod range;
 mod rect;
+mod rng;
 mod size;
 mod sized;
+mod sparse_mat;
 mod vec;
 mod vector;
 
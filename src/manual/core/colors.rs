@@ -0,0 +1,44 @@
+use crate::core::Scalar;
+
+/// Named colors in the BGR channel order used by the OpenCV drawing functions
+impl Scalar {
+	#[inline]
+	pub fn black() -> Self {
+		Self::new(0., 0., 0., 0.)
+	}
+
+	#[inline]
+	pub fn white() -> Self {
+		Self::new(255., 255., 255., 0.)
+	}
+
+	#[inline]
+	pub fn red() -> Self {
+		Self::new(0., 0., 255., 0.)
+	}
+
+	#[inline]
+	pub fn green() -> Self {
+		Self::new(0., 255., 0., 0.)
+	}
+
+	#[inline]
+	pub fn blue() -> Self {
+		Self::new(255., 0., 0., 0.)
+	}
+
+	#[inline]
+	pub fn yellow() -> Self {
+		Self::new(0., 255., 255., 0.)
+	}
+
+	#[inline]
+	pub fn cyan() -> Self {
+		Self::new(255., 255., 0., 0.)
+	}
+
+	#[inline]
+	pub fn magenta() -> Self {
+		Self::new(255., 0., 255., 0.)
+	}
+}
@@ -0,0 +1,52 @@
+use std::convert::TryFrom;
+
+use crate::{
+	core,
+	Error,
+	Result,
+};
+
+/// Element depth of a `Mat`, typed alternative to the `CV_8U`...`CV_64F` constants
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Depth {
+	U8,
+	S8,
+	U16,
+	S16,
+	S32,
+	F32,
+	F64,
+}
+
+impl From<Depth> for i32 {
+	#[inline]
+	fn from(s: Depth) -> Self {
+		match s {
+			Depth::U8 => core::CV_8U,
+			Depth::S8 => core::CV_8S,
+			Depth::U16 => core::CV_16U,
+			Depth::S16 => core::CV_16S,
+			Depth::S32 => core::CV_32S,
+			Depth::F32 => core::CV_32F,
+			Depth::F64 => core::CV_64F,
+		}
+	}
+}
+
+impl TryFrom<i32> for Depth {
+	type Error = Error;
+
+	/// Converts the OpenCV depth constant, the channel count of a full type like `CV_8UC3` is ignored
+	fn try_from(typ: i32) -> Result<Self> {
+		match typ & 7 {
+			core::CV_8U => Ok(Depth::U8),
+			core::CV_8S => Ok(Depth::S8),
+			core::CV_16U => Ok(Depth::U16),
+			core::CV_16S => Ok(Depth::S16),
+			core::CV_32S => Ok(Depth::S32),
+			core::CV_32F => Ok(Depth::F32),
+			core::CV_64F => Ok(Depth::F64),
+			depth => Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported depth: {}", depth))),
+		}
+	}
+}
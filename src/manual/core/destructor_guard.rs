@@ -0,0 +1,42 @@
+//! Protection against calling into the unloaded OpenCV library from destructors
+//!
+//! Applications that load and unload a module using this crate at runtime (e.g. with `dlopen`/`dlclose`) might
+//! end up dropping OpenCV objects after the library code is gone. Enable the guard with [set_destructor_guard]
+//! and call [shutdown] right before unloading, after that all destructors leak the objects instead of calling
+//! into the library.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static GUARD_ENABLED: AtomicBool = AtomicBool::new(false);
+static LIBRARY_ALIVE: AtomicBool = AtomicBool::new(true);
+static LEAKED_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Enable or disable the destructor guard, it's disabled by default
+#[inline]
+pub fn set_destructor_guard(enabled: bool) {
+	GUARD_ENABLED.store(enabled, Ordering::SeqCst);
+}
+
+/// Mark the OpenCV library as unloaded, objects dropped after this call are leaked if the guard is enabled
+#[inline]
+pub fn shutdown() {
+	LIBRARY_ALIVE.store(false, Ordering::SeqCst);
+}
+
+/// Number of objects that were leaked by the destructor guard after [shutdown]
+#[inline]
+pub fn destructor_guard_leaked_count() -> usize {
+	LEAKED_COUNT.load(Ordering::SeqCst)
+}
+
+/// Called by the `Drop` implementations, returns `false` if the object must be leaked instead of deleted
+#[doc(hidden)]
+#[inline]
+pub fn destructor_guard_allows_delete() -> bool {
+	if GUARD_ENABLED.load(Ordering::Relaxed) && !LIBRARY_ALIVE.load(Ordering::SeqCst) {
+		LEAKED_COUNT.fetch_add(1, Ordering::SeqCst);
+		false
+	} else {
+		true
+	}
+}
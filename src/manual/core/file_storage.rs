@@ -0,0 +1,24 @@
+use crate::{
+	core::{FileNode, FileNodeTrait},
+	Result,
+};
+
+pub trait FileNodeTraitManual: FileNodeTrait {
+	/// Child nodes of a sequence or a mapping node in the document order, empty for the other node types
+	fn children(&self) -> Result<Vec<FileNode>> {
+		if self.is_seq()? {
+			(0..self.size()? as i32)
+				.map(|i| self.at(i))
+				.collect()
+		} else if self.is_map()? {
+			self.keys()?
+				.iter()
+				.map(|key| self.get(&key))
+				.collect()
+		} else {
+			Ok(vec![])
+		}
+	}
+}
+
+impl<T: FileNodeTrait> FileNodeTraitManual for T {}
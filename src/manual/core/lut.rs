@@ -0,0 +1,37 @@
+use crate::{
+	core::{self, Mat, MatView},
+	Error,
+	prelude::*,
+	Result,
+};
+
+/// Like `lut()`, but with the single-channel lookup table passed as an array
+///
+/// The same table is applied to every channel of the `CV_8U` `src`.
+#[inline]
+pub fn lut_u8(src: &Mat, table: &[u8; 256], dst: &mut Mat) -> Result<()> {
+	let table = MatView::from_slice(&table[..], 1, 256)?;
+	lut_checked(src, table.as_mat(), dst)
+}
+
+/// Like `lut()`, but validates the arguments beforehand
+///
+/// `src` must be `CV_8U` with any number of channels, `table` must have 256 elements and either 1 channel (applied
+/// to every channel of `src`) or the same number of channels as `src`.
+pub fn lut_checked(src: &Mat, table: &Mat, dst: &mut Mat) -> Result<()> {
+	let depth = src.depth()?;
+	if depth != core::CV_8U {
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		let depth = core::depth_to_string(depth)?;
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Source Mat depth must be CV_8U, but it's: {}", depth)));
+	}
+	let total = table.total()?;
+	if total != 256 {
+		return Err(Error::new(core::StsBadSize, format!("Lookup table must have 256 elements, but it has: {}", total)));
+	}
+	let (table_channels, src_channels) = (table.channels()?, src.channels()?);
+	if table_channels != 1 && table_channels != src_channels {
+		return Err(Error::new(core::BadNumChannels, format!("Lookup table must have 1 or {} channels, but it has: {}", src_channels, table_channels)));
+	}
+	core::lut(src, table, dst)
+}
@@ -3,10 +3,15 @@ use std::{
 	ffi::c_void,
 	fmt,
 	ops::Deref,
+	ptr::NonNull,
 	slice,
 };
 
+pub use channels::*;
+pub use empty_mask::*;
 pub use mat_::*;
+pub use mat_view::*;
+pub use row_iter::*;
 
 use crate::{
 	core::{
@@ -14,6 +19,7 @@ use crate::{
 		_InputArray,
 		_InputOutputArray,
 		_OutputArray,
+		Depth,
 		MatExpr,
 		MatSize,
 		MatStep,
@@ -31,7 +37,18 @@ use crate::{
 	sys,
 };
 
+mod channels;
+mod empty_mask;
 mod mat_;
+#[cfg(feature = "image")]
+mod mat_image;
+#[cfg(feature = "ndarray")]
+mod mat_ndarray;
+#[cfg(feature = "serde")]
+mod mat_serde;
+mod mat_view;
+mod ops;
+mod row_iter;
 
 /// This sealed trait is implemented for types that are valid to use as Mat elements
 pub trait DataType: Copy + private::Sealed {
@@ -176,7 +193,10 @@ fn match_is_continuous(mat: &(impl MatTrait + ?Sized)) -> Result<()> {
 	if mat.is_continuous()? {
 		Ok(())
 	} else {
-		Err(Error::new(core::StsUnmatchedSizes, "Mat is not continuous, operation is not applicable".to_string()))
+		Err(Error::new(
+			core::StsUnmatchedSizes,
+			"Mat is not continuous (e.g. it's a ROI of a bigger Mat), operation is not applicable, use try_clone() to get a continuous copy".to_string()
+		))
 	}
 }
 
@@ -224,18 +244,41 @@ impl Mat {
 			let trg = out.at_row_mut(row_n as _)?;
 			let src = row.as_ref();
 			if trg.len() != src.len() {
-				return Err(Error::new(core::StsUnmatchedSizes, format!("Unexpected number of items: {} in a row index: {}, expected: {}", trg.len(), row_n, src.len())));
+				return Err(Error::new(core::StsUnmatchedSizes, format!("Unexpected number of items: {} in a row index: {}, expected: {}", src.len(), row_n, trg.len())));
 			}
 			trg.copy_from_slice(src);
 		}
 		Ok(out)
 	}
 
+	/// Create a `Mat` header over the externally owned `data` with continuous rows, no data is copied
+	///
+	/// Useful to wrap the buffers coming from the hand-written FFI code, see also `Boxed::from_raw()` for wrapping
+	/// pointers to the C++ `cv::Mat` objects.
+	/// # Safety
+	/// Caller must ensure that `data` points to at least `rows * cols` elements of type `typ` and that it outlives
+	/// the returned `Mat` and all of its shallow copies. No dimension or type checks are performed.
+	#[inline]
+	pub unsafe fn from_raw_parts_unchecked(rows: i32, cols: i32, typ: i32, data: *mut c_void) -> Result<Self> {
+		Self::new_rows_cols_with_data(rows, cols, typ, data, core::Mat_AUTO_STEP)
+	}
+
 	pub fn try_into_typed<T: DataType>(self) -> Result<Mat_<T>> where Self: Sized {
 		self.try_into()
 	}
 }
 
+/// Create a 2-dimensional `Mat` from the rows of elements, evaluates to `Result<Mat>`
+///
+/// The element type is inferred from the values, e.g. `mat![[1u8, 2, 3], [4, 5, 6]]` creates a 2x3 `CV_8UC1` `Mat`.
+/// The data is copied, rows of different length produce an error.
+#[macro_export]
+macro_rules! mat {
+	($([$($x: expr),* $(,)?]),+ $(,)?) => {
+		$crate::core::Mat::from_slice_2d(&[$(&[$($x),*][..]),+])
+	};
+}
+
 pub(crate) mod mat_forward {
 	use super::*;
 
@@ -430,6 +473,113 @@ pub trait MatTraitManual: MatTrait {
 			.map(|x| slice::from_raw_parts_mut(convert_ptr_mut(x), width))
 	}
 
+	/// Return a complete read-only row, same as `Mat::at_row()`
+	///
+	/// Each row is contiguous in memory so this also works for non-continuous Mats like ROIs
+	#[inline]
+	fn row_typed<T: DataType>(&self, row: i32) -> Result<&[T]> {
+		self.at_row(row)
+	}
+
+	/// Return a complete writeable row, same as `Mat::at_row_mut()`
+	#[inline]
+	fn row_typed_mut<T: DataType>(&mut self, row: i32) -> Result<&mut [T]> {
+		self.at_row_mut(row)
+	}
+
+	/// Return a copy of a complete column
+	///
+	/// Unlike rows, columns are not contiguous in memory so the elements are copied into a new `Vec`. Returns an
+	/// empty `Vec` for an empty Mat.
+	fn col_typed<T: DataType>(&self, col: i32) -> Result<Vec<T>> {
+		match_format::<T>(self.typ()?)?;
+		if self.empty()? {
+			return Ok(vec![]);
+		}
+		match_indices(self, &[0, col])?;
+		(0..self.rows())
+			.map(|row| unsafe { self.at_2d_unchecked::<T>(row, col) }.map(|x| *x))
+			.collect()
+	}
+
+	/// Iterate over the complete read-only rows of a 2-dimensional Mat, also works for non-continuous Mats
+	fn rows_iter<T: DataType>(&self) -> Result<MatRowIter<'_, T>> {
+		match_format::<T>(self.typ()?)
+			.and_then(|_| match_dims(self, 2))?;
+		let (rows, width) = (self.rows() as usize, self.cols() as usize);
+		if rows == 0 || width == 0 {
+			return Ok(unsafe { MatRowIter::new(NonNull::<T>::dangling().as_ptr() as *const u8, 0, width, rows) });
+		}
+		let step = self.step1(0)? * self.elem_size1()?;
+		let data = unsafe { self.ptr(0) }?;
+		Ok(unsafe { MatRowIter::new(data, step, width, rows) })
+	}
+
+	/// Iterate over the complete writeable rows of a 2-dimensional Mat, also works for non-continuous Mats
+	fn rows_iter_mut<T: DataType>(&mut self) -> Result<MatRowIterMut<'_, T>> {
+		match_format::<T>(self.typ()?)
+			.and_then(|_| match_dims(self, 2))?;
+		let (rows, width) = (self.rows() as usize, self.cols() as usize);
+		if rows == 0 || width == 0 {
+			return Ok(unsafe { MatRowIterMut::new(NonNull::<T>::dangling().as_ptr() as *mut u8, 0, width, rows) });
+		}
+		let step = self.step1(0)? * self.elem_size1()?;
+		let data = unsafe { self.ptr_mut(0) }?;
+		Ok(unsafe { MatRowIterMut::new(data, step, width, rows) })
+	}
+
+	/// Iterate over the read-only elements of a 2-dimensional Mat in row-major order yielding `((row, col), value)`
+	///
+	/// Works for non-continuous Mats too, the FFI calls are only made once before the iteration.
+	#[inline]
+	fn iter_2d<T: DataType>(&self) -> Result<MatIter2d<'_, T>> {
+		self.rows_iter().map(MatIter2d::new)
+	}
+
+	/// Iterate over the writeable elements of a 2-dimensional Mat in row-major order yielding `((row, col), value)`
+	///
+	/// Works for non-continuous Mats too, the FFI calls are only made once before the iteration.
+	#[inline]
+	fn iter_2d_mut<T: DataType>(&mut self) -> Result<MatIter2dMut<'_, T>> {
+		self.rows_iter_mut().map(MatIter2dMut::new)
+	}
+
+	/// Call `f` with the index and the contents of each row of a 2-dimensional Mat
+	///
+	/// The row slices borrow the Mat data directly, the FFI calls are only made once before the iteration.
+	/// Iteration stops at the first error returned by `f`.
+	fn for_each_row<T: DataType, E>(&self, mut f: impl FnMut(usize, &[T]) -> Result<(), E>) -> Result<(), MatOrUser<E>> {
+		for (i, row) in self.rows_iter::<T>()?.enumerate() {
+			f(i, row).map_err(MatOrUser::User)?;
+		}
+		Ok(())
+	}
+
+	/// Like `convert_to()`, but with the typed target `depth`, the channel count is preserved
+	#[inline]
+	fn convert_to_depth(&self, dst: &mut Mat, depth: Depth, alpha: f64, beta: f64) -> Result<()> {
+		self.convert_to(dst, depth.into(), alpha, beta)
+	}
+
+	/// Like `convert_to_depth()`, but without scaling
+	#[inline]
+	fn convert_to_depth_def(&self, dst: &mut Mat, depth: Depth) -> Result<()> {
+		self.convert_to_depth(dst, depth, 1., 0.)
+	}
+
+	/// Byte steps of all the Mat dimensions, unlike `mat_step()` it contains `dims()` elements for n-dimensional Mats
+	///
+	/// Step of the last dimension is equal to `elem_size()` for a valid Mat.
+	fn steps(&self) -> Result<Vec<usize>> {
+		let elem_size1 = self.elem_size1()?;
+		(0..self.dims())
+			.map(|i| self.step1(i)?
+				.checked_mul(elem_size1)
+				.ok_or_else(|| Error::new(core::StsOutOfRange, format!("Step of the dimension: {} overflows", i)))
+			)
+			.collect()
+	}
+
 	fn size(&self) -> Result<core::Size> {
 		extern "C" { fn cv_manual_Mat_size(instance: *const c_void) -> sys::Result<core::Size>; }
 		unsafe { cv_manual_Mat_size(self.as_raw_Mat()) }
@@ -451,6 +601,29 @@ pub trait MatTraitManual: MatTrait {
 			.into_result()
 	}
 
+	/// Like `set()`, but only the elements where `mask` is non-zero are changed if it's specified
+	///
+	/// `mask` must be `CV_8U` of the same size with either 1 channel or the same number of channels as this Mat.
+	fn set_masked(&mut self, s: Scalar, mask: Option<&Mat>) -> Result<()> {
+		match mask {
+			Some(mask) => {
+				let (size, mask_size) = (self.size()?, mask.size()?);
+				if mask_size != size {
+					return Err(Error::new(core::StsUnmatchedSizes, format!("Mask size: {:?} doesn't match Mat size: {:?}", mask_size, size)));
+				}
+				let mask_channels = mask.channels()?;
+				if mask.depth()? != core::CV_8U || (mask_channels != 1 && mask_channels != self.channels()?) {
+					let mask_type = mask.typ()?;
+					#[cfg(not(ocvrs_opencv_branch_32))]
+					let mask_type = core::type_to_string(mask_type)?;
+					return Err(Error::new(core::StsUnmatchedFormats, format!("Mask type: {} must be CV_8UC1 or have the same number of channels as Mat", mask_type)));
+				}
+				self.set_to(&s, mask).map(|_| ())
+			}
+			None => self.set(s),
+		}
+	}
+
 	fn data(&self) -> Result<&u8> {
 		extern "C" { fn cv_manual_Mat_data(instance: *const c_void) -> sys::Result<*const u8>; }
 		unsafe { cv_manual_Mat_data(self.as_raw_Mat()) }
@@ -459,8 +632,11 @@ pub trait MatTraitManual: MatTrait {
 	}
 
 	fn data_typed<T: DataType>(&self) -> Result<&[T]> {
-		match_format::<T>(self.typ()?)
-			.and_then(|_| match_is_continuous(self))
+		match_format::<T>(self.typ()?)?;
+		if self.total()? == 0 {
+			return Ok(&[]);
+		}
+		match_is_continuous(self)
 			.and_then(|_| unsafe { self.data_typed_unchecked() })
 	}
 
@@ -468,12 +644,18 @@ pub trait MatTraitManual: MatTrait {
 	/// Caller must ensure that the `T` type argument corresponds to the data stored in the `Mat`
 	unsafe fn data_typed_unchecked<T: DataType>(&self) -> Result<&[T]> {
 		let total = self.total()?;
+		if total == 0 {
+			return Ok(&[]);
+		}
 		self.data().map(|x| slice::from_raw_parts(x as *const _ as *const _, total))
 	}
 
 	fn data_typed_mut<T: DataType>(&mut self) -> Result<&mut [T]> {
-		match_format::<T>(self.typ()?)
-			.and_then(|_| match_is_continuous(self))?;
+		match_format::<T>(self.typ()?)?;
+		if self.total()? == 0 {
+			return Ok(&mut []);
+		}
+		match_is_continuous(self)?;
 		unsafe { self.data_typed_unchecked_mut() }
 	}
 
@@ -481,6 +663,9 @@ pub trait MatTraitManual: MatTrait {
 	/// Caller must ensure that the `T` type argument corresponds to the data stored in the `Mat`
 	unsafe fn data_typed_unchecked_mut<T: DataType>(&mut self) -> Result<&mut [T]> {
 		let total = self.total()?;
+		if total == 0 {
+			return Ok(&mut []);
+		}
 		Ok(slice::from_raw_parts_mut(self.data_mut() as *mut _ as *mut _, total))
 	}
 
@@ -583,10 +768,66 @@ impl fmt::Debug for Mat {
 			.field("total", &self.total().map_err(|_| fmt::Error)?)
 			.field("is_continuous", &self.is_continuous().map_err(|_| fmt::Error)?)
 			.field("is_submatrix", &self.is_submatrix().map_err(|_| fmt::Error)?)
+			.field("empty", &self.empty().map_err(|_| fmt::Error)?)
+			.field("data", &self.data().map_or(std::ptr::null(), |x| x as *const u8))
 			.finish()
 	}
 }
 
+/// Maximum number of rows and columns that are printed by the `Display` implementation for `Mat`
+const DISPLAY_MAX: i32 = 16;
+
+fn display_rows<T: DataType + fmt::Display>(mat: &Mat, channels: i32, f: &mut fmt::Formatter) -> fmt::Result {
+	let rows = mat.rows();
+	f.write_str("[")?;
+	for row_n in 0..rows.min(DISPLAY_MAX) {
+		if row_n > 0 {
+			f.write_str(";\n ")?;
+		}
+		let row = mat.at_row::<T>(row_n).map_err(|_| fmt::Error)?;
+		let shown = row.len().min((DISPLAY_MAX * channels) as usize);
+		for (i, x) in row[..shown].iter().enumerate() {
+			if i > 0 {
+				f.write_str(", ")?;
+			}
+			fmt::Display::fmt(x, f)?;
+		}
+		if shown < row.len() {
+			f.write_str(", ...")?;
+		}
+	}
+	if rows > DISPLAY_MAX {
+		f.write_str(";\n ...")?;
+	}
+	f.write_str("]")
+}
+
+/// Prints the elements of the 2-dimensional `Mat` in the same layout as the default `cv::format` does
+///
+/// Only the first 16 rows and columns are printed, the rest is replaced with ellipsis.
+impl fmt::Display for Mat {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.empty().map_err(|_| fmt::Error)? {
+			return f.write_str("[]");
+		}
+		if self.dims() > 2 {
+			return fmt::Debug::fmt(self, f);
+		}
+		let channels = self.channels().map_err(|_| fmt::Error)?;
+		let mat = self.reshape(1, 0).map_err(|_| fmt::Error)?;
+		match self.depth().map_err(|_| fmt::Error)? {
+			core::CV_8U => display_rows::<u8>(&mat, channels, f),
+			core::CV_8S => display_rows::<i8>(&mat, channels, f),
+			core::CV_16U => display_rows::<u16>(&mat, channels, f),
+			core::CV_16S => display_rows::<i16>(&mat, channels, f),
+			core::CV_32S => display_rows::<i32>(&mat, channels, f),
+			core::CV_32F => display_rows::<f32>(&mat, channels, f),
+			core::CV_64F => display_rows::<f64>(&mat, channels, f),
+			_ => fmt::Debug::fmt(self, f),
+		}
+	}
+}
+
 pub trait UMatTraitManual: UMatTrait {
 	#[inline]
 	fn size(&self) -> Result<core::Size> {
@@ -0,0 +1,92 @@
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+	types::VectorOfMat,
+};
+
+/// Split multi-channel `src` into single-channel `Mat`s, one per channel
+pub fn split_to_vec(src: &Mat) -> Result<Vec<Mat>> {
+	let mut out = VectorOfMat::new();
+	core::split(src, &mut out)?;
+	Ok(out.to_vec())
+}
+
+/// Merge the single or multi-channel `mats` into one `Mat` with the channels of all inputs
+///
+/// All `mats` must have the same size and depth, the errors report the index of the first offending input.
+pub fn merge_from_slice(mats: &[Mat]) -> Result<Mat> {
+	let first = mats.first()
+		.ok_or_else(|| Error::new(core::StsBadArg, "Can't merge an empty list of Mats".to_string()))?;
+	let (size, depth) = (first.size()?, first.depth()?);
+	let mut src = VectorOfMat::with_capacity(mats.len());
+	for (i, mat) in mats.iter().enumerate() {
+		let mat_size = mat.size()?;
+		if mat_size != size {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Size of Mat at index {}: {:?} doesn't match the size of Mat at index 0: {:?}", i, mat_size, size)));
+		}
+		let mat_depth = mat.depth()?;
+		if mat_depth != depth {
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			let (mat_depth, depth) = (core::depth_to_string(mat_depth)?, core::depth_to_string(depth)?);
+			return Err(Error::new(core::StsUnmatchedFormats, format!("Depth of Mat at index {}: {} doesn't match the depth of Mat at index 0: {}", i, mat_depth, depth)));
+		}
+		src.push(Mat::copy(mat)?);
+	}
+	let mut out = Mat::default();
+	core::merge(&src, &mut out)?;
+	Ok(out)
+}
+
+/// Like `mix_channels()`, but validates the `from_to` pairs against the channel counts before calling OpenCV
+///
+/// Channels are numbered continuously through all of the `src` (and all of the `dst`) `Mat`s. Each pair copies the
+/// `src` channel `.0` into the `dst` channel `.1`, `-1` as `.0` fills the `dst` channel with zeros. All `dst` `Mat`s
+/// must be allocated beforehand with the same size and depth as `src`, they are modified in place.
+pub fn mix_channels_checked(src: &[Mat], dst: &mut [Mat], from_to: &[(i32, i32)]) -> Result<()> {
+	let first = src.first()
+		.ok_or_else(|| Error::new(core::StsBadArg, "At least one source Mat is required".to_string()))?;
+	let (size, depth) = (first.size()?, first.depth()?);
+	let mut src_channels = 0;
+	let mut src_vec = VectorOfMat::with_capacity(src.len());
+	for (i, mat) in src.iter().enumerate() {
+		check_mix_channels_mat("source", i, mat, size, depth)?;
+		src_channels += mat.channels()?;
+		src_vec.push(Mat::copy(mat)?);
+	}
+	let mut dst_channels = 0;
+	let mut dst_vec = VectorOfMat::with_capacity(dst.len());
+	for (i, mat) in dst.iter().enumerate() {
+		check_mix_channels_mat("destination", i, mat, size, depth)?;
+		dst_channels += mat.channels()?;
+		// header copies share the data with `dst`, so the results are written directly there
+		dst_vec.push(Mat::copy(mat)?);
+	}
+	let mut pairs = Vec::with_capacity(from_to.len() * 2);
+	for &(from, to) in from_to {
+		if from < -1 || from >= src_channels {
+			return Err(Error::new(core::StsOutOfRange, format!("Source channel of the pair: ({}, {}) is out of range -1..{}", from, to, src_channels)));
+		}
+		if to < 0 || to >= dst_channels {
+			return Err(Error::new(core::StsOutOfRange, format!("Destination channel of the pair: ({}, {}) is out of range 0..{}", from, to, dst_channels)));
+		}
+		pairs.push(from);
+		pairs.push(to);
+	}
+	core::mix_channels(&src_vec, &mut dst_vec, &pairs)
+}
+
+fn check_mix_channels_mat(kind: &str, i: usize, mat: &Mat, size: core::Size, depth: i32) -> Result<()> {
+	let mat_size = mat.size()?;
+	if mat.empty()? || mat_size != size {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Size of {} Mat at index {}: {:?} doesn't match the size of source Mat at index 0: {:?}", kind, i, mat_size, size)));
+	}
+	let mat_depth = mat.depth()?;
+	if mat_depth != depth {
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		let (mat_depth, depth) = (core::depth_to_string(mat_depth)?, core::depth_to_string(depth)?);
+		return Err(Error::new(core::StsUnmatchedFormats, format!("Depth of {} Mat at index {}: {} doesn't match the depth of source Mat at index 0: {}", kind, i, mat_depth, depth)));
+	}
+	Ok(())
+}
@@ -0,0 +1,19 @@
+use once_cell::sync::Lazy;
+
+use crate::core::Mat;
+
+struct EmptyMat(Mat);
+
+// The wrapped Mat is empty, so it owns no data and no reference counter, and it's only ever handed out as a shared
+// reference, which limits it to the const C++ methods.
+unsafe impl Sync for EmptyMat {}
+
+static EMPTY_MASK: Lazy<EmptyMat> = Lazy::new(|| EmptyMat(Mat::default()));
+
+/// Shared empty `Mat` to pass as the "no mask" argument, e.g. to `detect()` or `match_()`
+///
+/// It's created once on first use and the same instance is returned on every call from any thread.
+#[inline]
+pub fn empty_mask() -> &'static Mat {
+	&EMPTY_MASK.0
+}
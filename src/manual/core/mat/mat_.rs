@@ -3,6 +3,7 @@ use std::{
 	ffi::c_void,
 	fmt,
 	marker::PhantomData,
+	ops::{Deref, Index, IndexMut},
 };
 
 use crate::{
@@ -12,7 +13,7 @@ use crate::{
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
 };
 
-use super::{DataType, match_dims, match_format, match_is_continuous, match_total, MatTraitManual};
+use super::{DataType, match_dims, match_format, match_indices, match_is_continuous, match_total, MatTraitManual};
 
 /// [docs.opencv.org](https://docs.opencv.org/master/df/dfc/classcv_1_1Mat__.html)
 ///
@@ -42,6 +43,19 @@ impl<T: DataType> From<Mat_<T>> for Mat {
 }
 
 impl<T: DataType> Mat_<T> {
+	/// Create a new `rows` x `cols` matrix filled with zeros
+	pub fn zeros(rows: i32, cols: i32) -> Result<Self> {
+		Mat::new_rows_cols_with_default(rows, cols, T::typ(), Default::default())
+			.map(|inner| Self { inner, _type: PhantomData })
+	}
+
+	/// Create a new single row matrix with the data copied from `s`
+	#[inline]
+	pub fn from_slice(s: &[T]) -> Result<Self> {
+		Mat::from_slice(s)
+			.map(|inner| Self { inner, _type: PhantomData })
+	}
+
 	#[inline]
 	pub fn into_untyped(self) -> Mat {
 		self.into()
@@ -78,6 +92,18 @@ impl<T: DataType> Mat_<T> {
 		unsafe { self.at_unchecked_mut(i0) }
 	}
 
+	#[inline(always)]
+	pub fn at_2d(&self, row: i32, col: i32) -> Result<&T> {
+		match_indices(self, &[row, col])
+			.and_then(|_| unsafe { self.at_2d_unchecked(row, col) })
+	}
+
+	#[inline(always)]
+	pub fn at_2d_mut(&mut self, row: i32, col: i32) -> Result<&mut T> {
+		match_indices(self, &[row, col])?;
+		unsafe { self.at_2d_unchecked_mut(row, col) }
+	}
+
 	pub fn data_typed(&self) -> Result<&[T]> {
 		match_is_continuous(self)
 			.and_then(|_| unsafe { self.data_typed_unchecked() })
@@ -89,6 +115,32 @@ impl<T: DataType> Mat_<T> {
 	}
 }
 
+impl<T> Deref for Mat_<T> {
+	type Target = Mat;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		&self.inner
+	}
+}
+
+/// Indexing by `(row, col)`, panics if the index is out of bounds
+impl<T: DataType> Index<(usize, usize)> for Mat_<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+		self.at_2d(row as i32, col as i32).unwrap_or_else(|e| panic!("{}", e))
+	}
+}
+
+impl<T: DataType> IndexMut<(usize, usize)> for Mat_<T> {
+	#[inline]
+	fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+		self.at_2d_mut(row as i32, col as i32).unwrap_or_else(|e| panic!("{}", e))
+	}
+}
+
 impl<T> MatTrait for Mat_<T> {
 	#[inline]
 	fn as_raw_Mat(&self) -> *const c_void { self.inner.as_raw_Mat() }
@@ -185,3 +237,9 @@ impl<T> fmt::Debug for Mat_<T> {
 		self.inner.fmt(f)
 	}
 }
+
+impl<T> fmt::Display for Mat_<T> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.inner, f)
+	}
+}
@@ -0,0 +1,77 @@
+use std::slice;
+
+use image::{DynamicImage, GrayImage, RgbaImage, RgbImage};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+impl Mat {
+	/// Create a new `Mat` with the data copied from `image`
+	///
+	/// Supported formats are `Luma8` (becomes `CV_8UC1`), `Rgb8` (`CV_8UC3`) and `Rgba8` (`CV_8UC4`). Color
+	/// channels are reordered to BGR/BGRA as expected by OpenCV.
+	pub fn from_image(image: &DynamicImage) -> Result<Mat> {
+		let (typ, channels, data) = match image {
+			DynamicImage::ImageLuma8(img) => (core::CV_8UC1, 1, img.as_raw()),
+			DynamicImage::ImageRgb8(img) => (core::CV_8UC3, 3, img.as_raw()),
+			DynamicImage::ImageRgba8(img) => (core::CV_8UC4, 4, img.as_raw()),
+			_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported image color type: {:?}", image.color()))),
+		};
+		let (width, height) = (image.width() as usize, image.height() as usize);
+		let mut out = Mat::new_rows_cols_with_default(height as i32, width as i32, typ, Default::default())?;
+		if width == 0 || height == 0 {
+			return Ok(out);
+		}
+		let row_len = width * channels;
+		for (row_n, src) in data.chunks_exact(row_len).enumerate() {
+			// row_n is within bounds because the Mat has the same dimensions as the image
+			let dst = unsafe { slice::from_raw_parts_mut(out.ptr_mut(row_n as i32)? as *mut u8, row_len) };
+			dst.copy_from_slice(src);
+			swap_red_blue(dst, channels);
+		}
+		Ok(out)
+	}
+
+	/// Copy the `Mat` data to a new `DynamicImage`
+	///
+	/// Supported types are `CV_8UC1` (becomes `Luma8`), `CV_8UC3` (`Rgb8`) and `CV_8UC4` (`Rgba8`). The `Mat`
+	/// data is expected in BGR/BGRA order and is reordered to RGB/RGBA.
+	pub fn to_image(&self) -> Result<DynamicImage> {
+		let typ = self.typ()?;
+		let channels = match typ {
+			core::CV_8UC1 => 1,
+			core::CV_8UC3 => 3,
+			core::CV_8UC4 => 4,
+			_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Mat type must be CV_8UC1, CV_8UC3 or CV_8UC4, but it's: {}", typ))),
+		};
+		let (width, height) = (self.cols().max(0) as u32, self.rows().max(0) as u32);
+		let row_len = width as usize * channels;
+		let mut data = Vec::with_capacity(row_len * height as usize);
+		if row_len > 0 {
+			for row_n in 0..height as i32 {
+				let start = data.len();
+				// row_n is within bounds and each row holds `cols * channels` bytes for the supported types
+				data.extend_from_slice(unsafe { slice::from_raw_parts(self.ptr(row_n)? as *const u8, row_len) });
+				swap_red_blue(&mut data[start..], channels);
+			}
+		}
+		let image = match channels {
+			1 => GrayImage::from_raw(width, height, data).map(DynamicImage::ImageLuma8),
+			3 => RgbImage::from_raw(width, height, data).map(DynamicImage::ImageRgb8),
+			_ => RgbaImage::from_raw(width, height, data).map(DynamicImage::ImageRgba8),
+		};
+		image.ok_or_else(|| Error::new(core::StsError, "Can't create image from the Mat data".to_string()))
+	}
+}
+
+/// Convert the pixel data between RGB(A) and BGR(A) in place, single channel data is left intact
+#[inline]
+fn swap_red_blue(data: &mut [u8], channels: usize) {
+	if channels >= 3 {
+		data.chunks_exact_mut(channels).for_each(|px| px.swap(0, 2));
+	}
+}
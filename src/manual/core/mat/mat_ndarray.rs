@@ -0,0 +1,78 @@
+use std::{
+	mem,
+	ptr::NonNull,
+};
+
+use ndarray::{ArrayBase, ArrayView2, ArrayView3, Data, Ix2, ShapeBuilder};
+
+use crate::{
+	core::{self, DataType, Mat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+use super::{match_dims, match_format};
+
+impl Mat {
+	/// Borrow the data of a 2-dimensional `Mat` as `ArrayView2`, no data is copied
+	///
+	/// `T` must match the `Mat` type exactly, e.g. `f32` for `CV_32FC1` or `Vec3b` for `CV_8UC3`. Non-continuous
+	/// `Mat`s (like ROIs) are returned as strided views.
+	pub fn try_as_array<T: DataType>(&self) -> Result<ArrayView2<'_, T>> {
+		match_format::<T>(self.typ()?)?;
+		match_dims(self, 2)?;
+		let row_stride = self.row_stride::<T>()?;
+		let shape = (self.rows() as usize, self.cols() as usize).strides((row_stride, 1));
+		Ok(unsafe { ArrayView2::from_shape_ptr(shape, self.data_ptr::<T>()?) })
+	}
+
+	/// Borrow the data of a 2-dimensional multichannel `Mat` as `ArrayView3` with the shape of (rows, cols, channels),
+	/// no data is copied
+	///
+	/// `T` is a single channel type that must match the `Mat` depth, e.g. `u8` for `CV_8UC3`.
+	pub fn try_as_array3<T: DataType>(&self) -> Result<ArrayView3<'_, T>> {
+		if T::channels() != 1 || T::depth() != self.depth()? {
+			return Err(Error::new(
+				core::StsUnmatchedFormats,
+				format!("Mat depth is: {}, but requested type is: {} with {} channels", self.depth()?, T::depth(), T::channels()),
+			));
+		}
+		match_dims(self, 2)?;
+		let channels = self.channels()? as usize;
+		let row_stride = self.row_stride::<T>()?;
+		let shape = (self.rows() as usize, self.cols() as usize, channels).strides((row_stride, channels, 1));
+		Ok(unsafe { ArrayView3::from_shape_ptr(shape, self.data_ptr::<T>()?) })
+	}
+
+	/// Create a new `Mat` with the data copied from the 2-dimensional `array`
+	pub fn from_array<S: Data<Elem=T>, T: DataType>(array: &ArrayBase<S, Ix2>) -> Result<Mat> {
+		let (rows, cols) = array.dim();
+		let mut out = Mat::new_rows_cols_with_default(rows as i32, cols as i32, T::typ(), Default::default())?;
+		for (row_n, row) in array.outer_iter().enumerate() {
+			out.at_row_mut::<T>(row_n as i32)?
+				.iter_mut()
+				.zip(row.iter())
+				.for_each(|(dst, src)| *dst = *src);
+		}
+		Ok(out)
+	}
+
+	/// Distance between the starts of consecutive rows in the units of `T`
+	fn row_stride<T>(&self) -> Result<usize> {
+		let step = self.step1(0)? * self.elem_size1()?;
+		if step % mem::size_of::<T>() == 0 {
+			Ok(step / mem::size_of::<T>())
+		} else {
+			Err(Error::new(core::StsUnmatchedSizes, format!("Mat row step: {} is not a multiple of the element size: {}", step, mem::size_of::<T>())))
+		}
+	}
+
+	fn data_ptr<T>(&self) -> Result<*const T> {
+		Ok(if self.total()? == 0 {
+			NonNull::dangling().as_ptr()
+		} else {
+			self.data()? as *const u8 as *const T
+		})
+	}
+}
@@ -0,0 +1,62 @@
+use std::slice;
+
+use serde::{de::Error as _, Deserialize, Deserializer, ser::Error as _, Serialize, Serializer};
+
+use crate::{
+	core::{Mat, MatView},
+	prelude::*,
+};
+
+#[derive(Serialize)]
+struct MatRef<'d> {
+	rows: i32,
+	cols: i32,
+	typ: i32,
+	data: &'d [u8],
+}
+
+#[derive(Deserialize)]
+struct MatOwned {
+	rows: i32,
+	cols: i32,
+	typ: i32,
+	data: Vec<u8>,
+}
+
+/// Serialized as a struct with `rows`, `cols`, `typ` and raw `data` bytes, only 2-dimensional `Mat`s are supported
+impl Serialize for Mat {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		if self.dims() > 2 {
+			return Err(S::Error::custom(format!("Only 2-dimensional Mat can be serialized, but it has: {} dims", self.dims())));
+		}
+		let continuous;
+		let mat = if self.is_continuous().map_err(S::Error::custom)? {
+			self
+		} else {
+			continuous = self.try_clone().map_err(S::Error::custom)?;
+			&continuous
+		};
+		let len = mat.total().map_err(S::Error::custom)? * mat.elem_size().map_err(S::Error::custom)?;
+		let data = if len == 0 {
+			&[][..]
+		} else {
+			let ptr = mat.data().map_err(S::Error::custom)?;
+			unsafe { slice::from_raw_parts(ptr as *const u8, len) }
+		};
+		MatRef {
+			rows: mat.rows(),
+			cols: mat.cols(),
+			typ: mat.typ().map_err(S::Error::custom)?,
+			data,
+		}.serialize(serializer)
+	}
+}
+
+impl<'de> Deserialize<'de> for Mat {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let mat = MatOwned::deserialize(deserializer)?;
+		MatView::from_bytes(&mat.data, mat.rows, mat.cols, mat.typ)
+			.and_then(|view| view.try_clone())
+			.map_err(D::Error::custom)
+	}
+}
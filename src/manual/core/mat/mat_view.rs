@@ -0,0 +1,157 @@
+use std::{
+	ffi::c_void,
+	fmt,
+	marker::PhantomData,
+	slice,
+};
+
+use crate::{
+	core::{self, _InputArray, Mat, Rect, Size, ToInputArray},
+	Error,
+	prelude::*,
+	Result,
+};
+
+use super::match_is_continuous;
+
+/// Read-only `Mat` header over the data borrowed from a Rust slice or from another `Mat`
+///
+/// No data is copied on construction, the view can't outlive the borrowed data. Use `try_clone()` to get an
+/// owned deep copy of the data.
+///
+/// The view doesn't dereference to `Mat` because OpenCV doesn't track the borrowed data, so any header derived
+/// from it (e.g. by `row()` or `roi()`) could be used to write into it or outlive it. It can be passed wherever
+/// `ToInputArray` is accepted, and the line detectors accept it via `detect_view()`.
+///
+/// ```compile_fail
+/// use opencv::core::{Mat, MatView};
+///
+/// fn escape() -> opencv::Result<Mat> {
+///     let data = vec![0u8; 4];
+///     let view = MatView::from_slice(&data, 2, 2)?;
+///     view.row(0)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use opencv::core::MatView;
+///
+/// let view = {
+///     let data = vec![0u8; 4];
+///     MatView::from_slice(&data, 2, 2).unwrap()
+/// };
+/// ```
+pub struct MatView<'a> {
+	inner: Mat,
+	_data: PhantomData<&'a [u8]>,
+}
+
+impl<'a> MatView<'a> {
+	/// Create a view of `rows` x `cols` elements of type `T` over `data`
+	pub fn from_slice<T: DataType>(data: &'a [T], rows: i32, cols: i32) -> Result<Self> {
+		let data = unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, data.len() * std::mem::size_of::<T>()) };
+		Self::from_bytes(data, rows, cols, T::typ())
+	}
+
+	/// Create a view of `rows` x `cols` elements of OpenCV type `typ` (e.g. `CV_8UC3`) over raw bytes
+	///
+	/// `data` must be aligned for the depth of `typ`, e.g. to 4 bytes for `CV_32F`.
+	pub fn from_bytes(data: &'a [u8], rows: i32, cols: i32, typ: i32) -> Result<Self> {
+		if rows < 0 || cols < 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid Mat dimensions: {}x{}", rows, cols)));
+		}
+		let inner = unsafe { Mat::new_rows_cols_with_data(rows, cols, typ, data.as_ptr() as *mut c_void, core::Mat_AUTO_STEP) }?;
+		let align = inner.elem_size1()?;
+		if align > 0 && data.as_ptr() as usize % align != 0 {
+			return Err(Error::new(core::StsBadArg, format!("Data pointer: {:p} is not aligned to: {} bytes required by the Mat depth", data.as_ptr(), align)));
+		}
+		let expected = inner.total()? * inner.elem_size()?;
+		if expected != data.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Slice length is: {} bytes, but Mat requires: {} bytes", data.len(), expected)));
+		}
+		match_is_continuous(&inner)?;
+		Ok(Self { inner, _data: PhantomData })
+	}
+
+	/// Create a view of the `rect` region of `parent`, the parent can't be modified while the view exists
+	///
+	/// ```compile_fail
+	/// use opencv::{core::{Mat, MatView, Rect}, prelude::*};
+	///
+	/// let mut parent = Mat::default();
+	/// let view = MatView::roi(&parent, Rect::new(0, 0, 0, 0)).unwrap();
+	/// parent.set_to(&opencv::core::Scalar::all(0.), &Mat::default()).unwrap();
+	/// drop(view);
+	/// ```
+	pub fn roi(parent: &'a Mat, rect: Rect) -> Result<Self> {
+		let size = parent.size()?;
+		let in_bounds = rect.x >= 0 && rect.y >= 0 && rect.width >= 0 && rect.height >= 0
+			&& rect.x.checked_add(rect.width).map_or(false, |right| right <= size.width)
+			&& rect.y.checked_add(rect.height).map_or(false, |bottom| bottom <= size.height);
+		if !in_bounds {
+			return Err(Error::new(core::StsOutOfRange, format!("ROI: {:?} is out of Mat bounds: {:?}", rect, size)));
+		}
+		Mat::roi(parent, rect)
+			.map(|inner| Self { inner, _data: PhantomData })
+	}
+
+	#[inline]
+	pub fn rows(&self) -> i32 {
+		self.inner.rows()
+	}
+
+	#[inline]
+	pub fn cols(&self) -> i32 {
+		self.inner.cols()
+	}
+
+	#[inline]
+	pub fn size(&self) -> Result<Size> {
+		self.inner.size()
+	}
+
+	#[inline]
+	pub fn typ(&self) -> Result<i32> {
+		self.inner.typ()
+	}
+
+	/// Return the viewed elements, the view must be continuous (which is not the case for most ROIs)
+	#[inline]
+	pub fn data_typed<T: DataType>(&self) -> Result<&[T]> {
+		self.inner.data_typed()
+	}
+
+	/// Return an owned deep copy of the viewed data
+	#[inline]
+	pub fn try_clone(&self) -> Result<Mat> {
+		self.inner.try_clone()
+	}
+
+	/// Underlying `Mat` header for passing to the functions that accept only `&Mat`
+	///
+	/// Must only be used for the calls that don't write into the data and don't keep the derived headers after
+	/// returning.
+	#[inline]
+	pub(crate) fn as_mat(&self) -> &Mat {
+		&self.inner
+	}
+}
+
+impl ToInputArray for MatView<'_> {
+	#[inline]
+	fn input_array(&self) -> Result<_InputArray> {
+		self.inner.input_array()
+	}
+}
+
+impl fmt::Debug for MatView<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.inner.fmt(f)
+	}
+}
+
+impl fmt::Display for MatView<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(&self.inner, f)
+	}
+}
@@ -0,0 +1,96 @@
+use std::ops::{Add, Div, Mul, Neg, Not, Sub};
+
+use crate::{
+	core::{self, Mat, MatExpr, Scalar},
+	Result,
+};
+
+macro_rules! mat_op {
+	($trait: ident, $method: ident, $lhs: ty, $rhs: ty, $func: ident) => {
+		impl $trait<$rhs> for $lhs {
+			type Output = Result<MatExpr>;
+
+			#[inline]
+			fn $method(self, rhs: $rhs) -> Self::Output {
+				core::$func(self, rhs)
+			}
+		}
+	};
+}
+
+mat_op!(Add, add, &Mat, &Mat, add_mat_mat);
+mat_op!(Add, add, &Mat, &MatExpr, add_mat_matexpr);
+mat_op!(Add, add, &Mat, Scalar, add_mat_scalar);
+mat_op!(Add, add, &MatExpr, &Mat, add_matexpr_mat);
+mat_op!(Add, add, &MatExpr, &MatExpr, add_matexpr_matexpr);
+mat_op!(Add, add, &MatExpr, Scalar, add_matexpr_scalar);
+mat_op!(Add, add, Scalar, &Mat, add_scalar_mat);
+mat_op!(Add, add, Scalar, &MatExpr, add_scalar_matexpr);
+
+mat_op!(Sub, sub, &Mat, &Mat, sub_mat_mat);
+mat_op!(Sub, sub, &Mat, &MatExpr, sub_mat_matexpr);
+mat_op!(Sub, sub, &Mat, Scalar, sub_mat_scalar);
+mat_op!(Sub, sub, &MatExpr, &Mat, sub_matexpr_mat);
+mat_op!(Sub, sub, &MatExpr, &MatExpr, sub_matexpr_matexpr);
+mat_op!(Sub, sub, &MatExpr, Scalar, sub_matexpr_scalar);
+mat_op!(Sub, sub, Scalar, &Mat, sub_scalar_mat);
+mat_op!(Sub, sub, Scalar, &MatExpr, sub_scalar_matexpr);
+
+// like in C++ multiplication of 2 matrices is a matrix product, use `MatTrait::mul()` for per-element product
+mat_op!(Mul, mul, &Mat, &Mat, mul_mat_mat);
+mat_op!(Mul, mul, &Mat, &MatExpr, mul_mat_matexpr);
+mat_op!(Mul, mul, &Mat, f64, mul_mat_f64);
+mat_op!(Mul, mul, &MatExpr, &Mat, mul_matexpr_mat);
+mat_op!(Mul, mul, &MatExpr, &MatExpr, mul_matexpr_matexpr);
+mat_op!(Mul, mul, &MatExpr, f64, mul_matexpr_f64);
+mat_op!(Mul, mul, f64, &Mat, mul_f64_mat);
+mat_op!(Mul, mul, f64, &MatExpr, mul_f64_matexpr);
+
+mat_op!(Div, div, &Mat, &Mat, div_mat_mat);
+mat_op!(Div, div, &Mat, &MatExpr, div_mat_matexpr);
+mat_op!(Div, div, &Mat, f64, div_mat_f64);
+mat_op!(Div, div, &MatExpr, &Mat, div_matexpr_mat);
+mat_op!(Div, div, &MatExpr, &MatExpr, div_matexpr_matexpr);
+mat_op!(Div, div, &MatExpr, f64, div_matexpr_f64);
+mat_op!(Div, div, f64, &Mat, div_f64_mat);
+mat_op!(Div, div, f64, &MatExpr, div_f64_matexpr);
+
+impl Neg for &Mat {
+	type Output = Result<MatExpr>;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		core::sub_mat(self)
+	}
+}
+
+impl Neg for &MatExpr {
+	type Output = Result<MatExpr>;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		core::sub_matexpr(self)
+	}
+}
+
+/// Per-element bitwise inversion
+impl Not for &Mat {
+	type Output = Result<MatExpr>;
+
+	fn not(self) -> Self::Output {
+		let mut out = Mat::default();
+		core::bitwise_not(self, &mut out, core::empty_mask())?;
+		MatExpr::from_mat(&out)
+	}
+}
+
+/// Per-element bitwise inversion, evaluates the expression first
+impl Not for &MatExpr {
+	type Output = Result<MatExpr>;
+
+	fn not(self) -> Self::Output {
+		let mut out = Mat::default();
+		core::bitwise_not(self, &mut out, core::empty_mask())?;
+		MatExpr::from_mat(&out)
+	}
+}
@@ -0,0 +1,252 @@
+use std::{
+	error,
+	fmt,
+	iter::FusedIterator,
+	marker::PhantomData,
+	slice,
+};
+
+use crate::Error;
+
+/// Iterator over the rows of a 2-dimensional `Mat`, created by `MatTraitManual::rows_iter()`
+pub struct MatRowIter<'m, T> {
+	data: *const u8,
+	step: usize,
+	width: usize,
+	front: usize,
+	back: usize,
+	_d: PhantomData<&'m T>,
+}
+
+impl<'m, T> MatRowIter<'m, T> {
+	/// # Safety
+	/// `data` must point to `rows` rows of `width` elements of `T`, each row starting `step` bytes after the previous one
+	pub(crate) unsafe fn new(data: *const u8, step: usize, width: usize, rows: usize) -> Self {
+		Self { data, step, width, front: 0, back: rows, _d: PhantomData }
+	}
+
+	#[inline]
+	unsafe fn row(&self, i: usize) -> &'m [T] {
+		slice::from_raw_parts(self.data.add(i * self.step) as *const T, self.width)
+	}
+}
+
+impl<'m, T> Iterator for MatRowIter<'m, T> {
+	type Item = &'m [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let out = unsafe { self.row(self.front) };
+			self.front += 1;
+			Some(out)
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for MatRowIter<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(unsafe { self.row(self.back) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<T> ExactSizeIterator for MatRowIter<'_, T> {}
+
+impl<T> FusedIterator for MatRowIter<'_, T> {}
+
+/// Iterator over the mutable rows of a 2-dimensional `Mat`, created by `MatTraitManual::rows_iter_mut()`
+pub struct MatRowIterMut<'m, T> {
+	data: *mut u8,
+	step: usize,
+	width: usize,
+	front: usize,
+	back: usize,
+	_d: PhantomData<&'m mut T>,
+}
+
+impl<'m, T> MatRowIterMut<'m, T> {
+	/// # Safety
+	/// `data` must point to `rows` rows of `width` elements of `T`, each row starting `step` bytes after the previous one,
+	/// rows must not overlap
+	pub(crate) unsafe fn new(data: *mut u8, step: usize, width: usize, rows: usize) -> Self {
+		Self { data, step, width, front: 0, back: rows, _d: PhantomData }
+	}
+
+	#[inline]
+	unsafe fn row(&mut self, i: usize) -> &'m mut [T] {
+		slice::from_raw_parts_mut(self.data.add(i * self.step) as *mut T, self.width)
+	}
+}
+
+impl<'m, T> Iterator for MatRowIterMut<'m, T> {
+	type Item = &'m mut [T];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			let out = unsafe { self.row(self.front) };
+			self.front += 1;
+			Some(out)
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<T> DoubleEndedIterator for MatRowIterMut<'_, T> {
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			Some(unsafe { self.row(self.back) })
+		} else {
+			None
+		}
+	}
+}
+
+impl<T> ExactSizeIterator for MatRowIterMut<'_, T> {}
+
+impl<T> FusedIterator for MatRowIterMut<'_, T> {}
+
+/// Iterator over the elements of a 2-dimensional `Mat` together with their `(row, col)` positions in row-major
+/// order, created by `MatTraitManual::iter_2d()`
+pub struct MatIter2d<'m, T> {
+	rows: MatRowIter<'m, T>,
+	row: slice::Iter<'m, T>,
+	row_idx: usize,
+	col_idx: usize,
+	remaining: usize,
+}
+
+impl<'m, T> MatIter2d<'m, T> {
+	pub(crate) fn new(rows: MatRowIter<'m, T>) -> Self {
+		let remaining = rows.len() * rows.width;
+		Self { rows, row: [].iter(), row_idx: 0, col_idx: 0, remaining }
+	}
+}
+
+impl<'m, T> Iterator for MatIter2d<'m, T> {
+	type Item = ((usize, usize), &'m T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(val) = self.row.next() {
+				let out = ((self.row_idx - 1, self.col_idx), val);
+				self.col_idx += 1;
+				self.remaining -= 1;
+				return Some(out);
+			}
+			self.row = self.rows.next()?.iter();
+			self.row_idx += 1;
+			self.col_idx = 0;
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<T> ExactSizeIterator for MatIter2d<'_, T> {}
+
+impl<T> FusedIterator for MatIter2d<'_, T> {}
+
+/// Iterator over the mutable elements of a 2-dimensional `Mat` together with their `(row, col)` positions in
+/// row-major order, created by `MatTraitManual::iter_2d_mut()`
+pub struct MatIter2dMut<'m, T> {
+	rows: MatRowIterMut<'m, T>,
+	row: slice::IterMut<'m, T>,
+	row_idx: usize,
+	col_idx: usize,
+	remaining: usize,
+}
+
+impl<'m, T> MatIter2dMut<'m, T> {
+	pub(crate) fn new(rows: MatRowIterMut<'m, T>) -> Self {
+		let remaining = rows.len() * rows.width;
+		Self { rows, row: [].iter_mut(), row_idx: 0, col_idx: 0, remaining }
+	}
+}
+
+impl<'m, T> Iterator for MatIter2dMut<'m, T> {
+	type Item = ((usize, usize), &'m mut T);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(val) = self.row.next() {
+				let out = ((self.row_idx - 1, self.col_idx), val);
+				self.col_idx += 1;
+				self.remaining -= 1;
+				return Some(out);
+			}
+			self.row = self.rows.next()?.iter_mut();
+			self.row_idx += 1;
+			self.col_idx = 0;
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<T> ExactSizeIterator for MatIter2dMut<'_, T> {}
+
+impl<T> FusedIterator for MatIter2dMut<'_, T> {}
+
+/// Error returned by `MatTraitManual::for_each_row()`, either from OpenCV or from the user callback
+#[derive(Debug)]
+pub enum MatOrUser<E> {
+	Mat(Error),
+	User(E),
+}
+
+impl<E> From<Error> for MatOrUser<E> {
+	#[inline]
+	fn from(e: Error) -> Self {
+		MatOrUser::Mat(e)
+	}
+}
+
+impl<E: fmt::Display> fmt::Display for MatOrUser<E> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			MatOrUser::Mat(e) => e.fmt(f),
+			MatOrUser::User(e) => e.fmt(f),
+		}
+	}
+}
+
+impl<E: error::Error + 'static> error::Error for MatOrUser<E> {
+	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+		match self {
+			MatOrUser::Mat(e) => Some(e),
+			MatOrUser::User(e) => Some(e),
+		}
+	}
+}
@@ -1,15 +1,18 @@
 use std::{
+	convert::TryFrom,
 	ffi::c_void,
 	fmt,
 	mem::ManuallyDrop,
+	ops::Mul,
 };
 
 use num_traits::{One, Zero};
 
 use crate::{
-	core::{self, ToInputArray, ToInputOutputArray, ToOutputArray},
+	core::{self, DataType, Mat, ToInputArray, ToInputOutputArray, ToOutputArray, ValidVecType, Vec2, Vec3, Vec4},
 	Error,
 	manual::core::sized::*,
+	prelude::*,
 	Result,
 	sys::Result as SysResult,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
@@ -134,6 +137,92 @@ impl<T: ValidMatxType, A: SizedArray<T>> Default for Matx<T, A> {
 	}
 }
 
+impl<T: ValidMatxType + DataType, A: SizedArray<T>> Matx<T, A> {
+	/// Copy the elements into a new single-channel `Mat` of the same dimensions
+	pub fn to_mat(&self) -> Result<Mat> {
+		let mut out = Mat::new_rows_cols_with_default(A::ROWS as i32, A::COLS as i32, T::typ(), core::Scalar::all(0.))?;
+		out.data_typed_mut::<T>()?.copy_from_slice(self.val());
+		Ok(out)
+	}
+}
+
+impl<T: ValidMatxType + DataType, A: SizedArray<T>> TryFrom<&Mat> for Matx<T, A> {
+	type Error = Error;
+
+	/// Copy the elements of a continuous single-channel `Mat` with matching dimensions and element type
+	fn try_from(mat: &Mat) -> Result<Self> {
+		let size = mat.size()?;
+		if size.height as usize != A::ROWS || size.width as usize != A::COLS {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Mat size: {}x{} doesn't match Matx size: {}x{}", size.height, size.width, A::ROWS, A::COLS)));
+		}
+		let mut out = Self::default();
+		out.val_mut().copy_from_slice(mat.data_typed::<T>()?);
+		Ok(out)
+	}
+}
+
+impl<T: ValidMatxType + DataType, A: SizedArray<T>> TryFrom<Matx<T, A>> for Mat {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(s: Matx<T, A>) -> Result<Self> {
+		s.to_mat()
+	}
+}
+
+/// `lhs` (`n` x `k`) * `rhs` (`k` x `m`), both in row-major order
+#[inline]
+fn mul_row_major<T: ValidMatxType>(lhs: &[T], rhs: &[T], out: &mut [T], k: usize, m: usize) {
+	out.iter_mut()
+		.enumerate()
+		.for_each(|(i, out)| {
+			let (row, col) = (i / m, i % m);
+			*out = (0..k).fold(T::zero(), |acc, j| acc + lhs[row * k + j] * rhs[j * m + col]);
+		});
+}
+
+macro_rules! matx_mul_matx {
+	($lhs: ident, $rhs: ident => $out: ident, $k: expr, $m: expr) => {
+		impl<T: ValidMatxType> Mul<Matx<T, $rhs>> for Matx<T, $lhs> {
+			type Output = Matx<T, $out>;
+
+			fn mul(self, rhs: Matx<T, $rhs>) -> Self::Output {
+				let mut out = Self::Output::default();
+				mul_row_major(self.val(), rhs.val(), out.val_mut(), $k, $m);
+				out
+			}
+		}
+	};
+}
+
+matx_mul_matx!(SizedArray22, SizedArray22 => SizedArray22, 2, 2);
+matx_mul_matx!(SizedArray22, SizedArray23 => SizedArray23, 2, 3);
+matx_mul_matx!(SizedArray23, SizedArray33 => SizedArray23, 3, 3);
+matx_mul_matx!(SizedArray33, SizedArray33 => SizedArray33, 3, 3);
+matx_mul_matx!(SizedArray33, SizedArray34 => SizedArray34, 3, 4);
+matx_mul_matx!(SizedArray34, SizedArray44 => SizedArray34, 4, 4);
+matx_mul_matx!(SizedArray44, SizedArray44 => SizedArray44, 4, 4);
+
+macro_rules! matx_mul_vec {
+	($lhs: ident, $rhs: ident => $out: ident, $k: expr) => {
+		impl<T: ValidMatxType + ValidVecType> Mul<$rhs<T>> for Matx<T, $lhs> {
+			type Output = $out<T>;
+
+			fn mul(self, rhs: $rhs<T>) -> Self::Output {
+				let mut out = Self::Output::default();
+				mul_row_major(self.val(), &rhs.0, &mut out.0, $k, 1);
+				out
+			}
+		}
+	};
+}
+
+matx_mul_vec!(SizedArray22, Vec2 => Vec2, 2);
+matx_mul_vec!(SizedArray23, Vec3 => Vec2, 3);
+matx_mul_vec!(SizedArray33, Vec3 => Vec3, 3);
+matx_mul_vec!(SizedArray34, Vec4 => Vec3, 4);
+matx_mul_vec!(SizedArray44, Vec4 => Vec4, 4);
+
 impl<T: ValidMatxType, A: SizedArray<T>> std::ops::Index<(usize, usize)> for Matx<T, A> {
 	type Output = T;
 
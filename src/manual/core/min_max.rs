@@ -0,0 +1,75 @@
+use crate::{
+	core::{self, Mat, Point, ToInputArray},
+	Error,
+	prelude::*,
+	Result,
+	sys,
+};
+
+/// Result of `min_max_loc_masked()`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MinMaxResult {
+	pub min_val: f64,
+	pub max_val: f64,
+	pub min_loc: Point,
+	pub max_loc: Point,
+}
+
+/// Result of `min_max_idx_masked()`, the indices have one element per `Mat` dimension
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinMaxIdxResult {
+	pub min_val: f64,
+	pub max_val: f64,
+	pub min_idx: Vec<i32>,
+	pub max_idx: Vec<i32>,
+}
+
+fn check_min_max_src(src: &Mat) -> Result<()> {
+	if src.empty()? {
+		return Err(Error::new(core::StsBadArg, "Can't find the minimum and maximum of an empty Mat".to_string()));
+	}
+	let channels = src.channels()?;
+	if channels != 1 {
+		return Err(Error::new(core::BadNumChannels, format!("Mat must have a single channel to find its minimum and maximum, but it has: {}", channels)));
+	}
+	Ok(())
+}
+
+/// Like `min_max_loc()`, but returns the values in a struct and accepts `None` instead of an empty `mask`
+///
+/// `src` must be a non-empty single-channel 2-dimensional `Mat`, use `min_max_idx_masked()` for more dimensions.
+pub fn min_max_loc_masked(src: &Mat, mask: Option<&Mat>) -> Result<MinMaxResult> {
+	check_min_max_src(src)?;
+	let dims = src.dims();
+	if dims > 2 {
+		return Err(Error::new(core::StsBadArg, format!("Mat must have at most 2 dimensions, but it has: {}, use min_max_idx_masked() instead", dims)));
+	}
+	let mut out = MinMaxResult { min_val: 0., max_val: 0., min_loc: Point::default(), max_loc: Point::default() };
+	let mask = mask.unwrap_or(core::empty_mask());
+	core::min_max_loc(src, &mut out.min_val, &mut out.max_val, &mut out.min_loc, &mut out.max_loc, mask)?;
+	Ok(out)
+}
+
+/// Like `min_max_idx()`, but returns the values in a struct with the indices sized to the `src` dimensions and
+/// accepts `None` instead of an empty `mask`
+///
+/// `src` must be a non-empty single-channel `Mat`.
+pub fn min_max_idx_masked(src: &Mat, mask: Option<&Mat>) -> Result<MinMaxIdxResult> {
+	check_min_max_src(src)?;
+	let dims = src.dims().max(2) as usize;
+	let mut out = MinMaxIdxResult { min_val: 0., max_val: 0., min_idx: vec![0; dims], max_idx: vec![0; dims] };
+	let mask = mask.unwrap_or(core::empty_mask());
+	let (src, mask) = (src.input_array()?, mask.input_array()?);
+	// the generated `min_max_idx()` takes the index arguments as `&mut i32`, but OpenCV writes one element per dimension
+	unsafe {
+		sys::cv_minMaxIdx_const__InputArrayR_doubleX_doubleX_intX_intX_const__InputArrayR(
+			src.as_raw__InputArray(),
+			&mut out.min_val,
+			&mut out.max_val,
+			out.min_idx.as_mut_ptr(),
+			out.max_idx.as_mut_ptr(),
+			mask.as_raw__InputArray(),
+		)
+	}.into_result()?;
+	Ok(out)
+}
@@ -0,0 +1,91 @@
+//! Conversions between the OpenCV fixed size types and their `nalgebra` counterparts
+
+use nalgebra::{Matrix3, Matrix4, Point2, Point3, Scalar, Vector3};
+
+use crate::{
+	core::{self, DataType, Mat, Matx33, Matx44, Point3_, Point_, ValidMatxType, ValidPoint3Type, ValidPointType, ValidVecType, Vec3},
+	Error,
+	prelude::*,
+	Result,
+};
+
+impl<T: ValidPointType + Scalar> From<Point_<T>> for Point2<T> {
+	#[inline]
+	fn from(s: Point_<T>) -> Self {
+		Point2::new(s.x, s.y)
+	}
+}
+
+impl<T: ValidPointType + Scalar> From<Point2<T>> for Point_<T> {
+	#[inline]
+	fn from(s: Point2<T>) -> Self {
+		Point_::new(s.x, s.y)
+	}
+}
+
+impl<T: ValidPoint3Type + Scalar> From<Point3_<T>> for Point3<T> {
+	#[inline]
+	fn from(s: Point3_<T>) -> Self {
+		Point3::new(s.x, s.y, s.z)
+	}
+}
+
+impl<T: ValidPoint3Type + Scalar> From<Point3<T>> for Point3_<T> {
+	#[inline]
+	fn from(s: Point3<T>) -> Self {
+		Point3_::new(s.x, s.y, s.z)
+	}
+}
+
+impl<T: ValidVecType + Scalar> From<Vec3<T>> for Vector3<T> {
+	#[inline]
+	fn from(s: Vec3<T>) -> Self {
+		Vector3::from(s.0)
+	}
+}
+
+impl<T: ValidVecType + Scalar> From<Vector3<T>> for Vec3<T> {
+	#[inline]
+	fn from(s: Vector3<T>) -> Self {
+		Vec3::from([s.x, s.y, s.z])
+	}
+}
+
+macro_rules! matx_nalgebra {
+	($matx: ident, $matrix: ident, $dim: expr) => {
+		impl<T: ValidMatxType + Scalar> From<$matx<T>> for $matrix<T> {
+			#[inline]
+			fn from(s: $matx<T>) -> Self {
+				$matrix::from_row_slice(&s.val)
+			}
+		}
+
+		impl<T: ValidMatxType + Scalar> From<$matrix<T>> for $matx<T> {
+			fn from(s: $matrix<T>) -> Self {
+				let mut out = $matx::all(T::zero());
+				for (i, row) in s.row_iter().enumerate() {
+					out.val[i * $dim..(i + 1) * $dim].iter_mut().zip(row.iter()).for_each(|(dst, src)| *dst = *src);
+				}
+				out
+			}
+		}
+	};
+}
+
+matx_nalgebra!(Matx33, Matrix3, 3);
+matx_nalgebra!(Matx44, Matrix4, 4);
+
+impl Mat {
+	/// Copy the data of a 3x3 single-channel `Mat` (e.g. a camera matrix or a homography) into `Matrix3`
+	pub fn try_to_matrix3<T: DataType + Scalar>(&self) -> Result<Matrix3<T>> {
+		let size = self.size()?;
+		if size != core::Size::new(3, 3) {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Mat size must be 3x3, but it's: {}x{}", size.height, size.width)));
+		}
+		let mut out = Vec::with_capacity(9);
+		for row in 0..3 {
+			out.extend_from_slice(self.at_row::<T>(row)?);
+		}
+		Ok(Matrix3::from_row_slice(&out))
+	}
+}
@@ -0,0 +1,55 @@
+use crate::{
+	core::{self, Mat, NormTypes, ToInputArray, ToInputOutputArray},
+	Result,
+};
+
+/// Norm used by `norm_masked()`, `norm2_masked()` and `normalize_masked()`, typed alternative to the `NORM_*`
+/// constants
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NormType {
+	L1,
+	L2,
+	L2Sqr,
+	Inf,
+	/// Number of differing bits, only for `CV_8U` data
+	Hamming,
+	/// Like `Hamming`, but compares pairs of bits, only for `CV_8U` data
+	Hamming2,
+	/// Range normalization, only for `normalize_masked()`
+	MinMax,
+}
+
+impl From<NormType> for i32 {
+	#[inline]
+	fn from(s: NormType) -> Self {
+		(match s {
+			NormType::L1 => NormTypes::NORM_L1,
+			NormType::L2 => NormTypes::NORM_L2,
+			NormType::L2Sqr => NormTypes::NORM_L2SQR,
+			NormType::Inf => NormTypes::NORM_INF,
+			NormType::Hamming => NormTypes::NORM_HAMMING,
+			NormType::Hamming2 => NormTypes::NORM_HAMMING2,
+			NormType::MinMax => NormTypes::NORM_MINMAX,
+		}) as i32
+	}
+}
+
+/// Like `norm()`, but with a typed `norm_type` and `None` instead of an empty `mask`
+#[inline]
+pub fn norm_masked(src: &dyn ToInputArray, norm_type: NormType, mask: Option<&Mat>) -> Result<f64> {
+	core::norm(src, norm_type.into(), mask.unwrap_or(core::empty_mask()))
+}
+
+/// Like `norm2()`, but with a typed `norm_type` and `None` instead of an empty `mask`
+#[inline]
+pub fn norm2_masked(src1: &dyn ToInputArray, src2: &dyn ToInputArray, norm_type: NormType, mask: Option<&Mat>) -> Result<f64> {
+	core::norm2(src1, src2, norm_type.into(), mask.unwrap_or(core::empty_mask()))
+}
+
+/// Like `normalize()`, but with a typed `norm_type` and `None` instead of an empty `mask`
+///
+/// Pass -1 as `dtype` to keep the depth of `src`.
+#[inline]
+pub fn normalize_masked(src: &dyn ToInputArray, dst: &mut dyn ToInputOutputArray, alpha: f64, beta: f64, norm_type: NormType, dtype: i32, mask: Option<&Mat>) -> Result<()> {
+	core::normalize(src, dst, alpha, beta, norm_type.into(), dtype, mask.unwrap_or(core::empty_mask()))
+}
@@ -1,4 +1,4 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use num_traits::{NumCast, ToPrimitive};
 
@@ -129,6 +129,20 @@ impl<T> Div<T> for Point_<T>
 	}
 }
 
+impl<T> Neg for Point_<T>
+	where
+		T: ValidPointType + Neg<Output=T>,
+{
+	type Output = Point_<T>;
+
+	fn neg(self) -> Self::Output {
+		Self {
+			x: -self.x,
+			y: -self.y,
+		}
+	}
+}
+
 impl<T> AddAssign for Point_<T>
 	where
 		T: ValidPointType + AddAssign,
@@ -1,4 +1,4 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use num_traits::{NumCast, ToPrimitive, Zero};
 
@@ -127,6 +127,21 @@ impl<T> Div<T> for Point3_<T>
 	}
 }
 
+impl<T> Neg for Point3_<T>
+	where
+		T: ValidPoint3Type + Neg<Output=T>,
+{
+	type Output = Point3_<T>;
+
+	fn neg(self) -> Self::Output {
+		Self {
+			x: -self.x,
+			y: -self.y,
+			z: -self.z,
+		}
+	}
+}
+
 impl<T> AddAssign for Point3_<T>
 	where
 		T: ValidPoint3Type + AddAssign,
@@ -7,6 +7,7 @@ use std::{
 pub use ptr_extern::{PtrExtern, PtrExternCtor};
 
 use crate::{
+	core,
 	Result,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
 };
@@ -116,6 +117,8 @@ impl<T: ?Sized> OpenCVTypeExternContainer for Ptr<T> where Self: PtrExtern {
 
 impl<T: ?Sized> Drop for Ptr<T> where Self: PtrExtern {
 	fn drop(&mut self) {
-		unsafe { self.extern_delete() }
+		if core::destructor_guard_allows_delete() {
+			unsafe { self.extern_delete() }
+		}
 	}
 }
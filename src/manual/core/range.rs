@@ -0,0 +1,33 @@
+use std::{convert::TryFrom, fmt, ops};
+
+use crate::{
+	core::{Range, RangeTrait},
+	Error,
+	Result,
+};
+
+impl Range {
+	/// Convert to the equivalent Rust range, `Range::all()` becomes `i32::MIN..i32::MAX`
+	#[inline]
+	pub fn to_std(&self) -> ops::Range<i32> {
+		self.start()..self.end()
+	}
+}
+
+impl TryFrom<ops::Range<i32>> for Range {
+	type Error = Error;
+
+	#[inline]
+	fn try_from(r: ops::Range<i32>) -> Result<Self> {
+		Range::new(r.start, r.end)
+	}
+}
+
+impl fmt::Debug for Range {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Range")
+			.field("start", &self.start())
+			.field("end", &self.end())
+			.finish()
+	}
+}
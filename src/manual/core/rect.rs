@@ -7,8 +7,9 @@ use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
 use num_traits::{NumCast, ToPrimitive};
 
 use crate::{
-	core::{Point_, prelude::*, RotatedRect, Size_, ValidPointType, ValidSizeType},
+	core::{Point2f, Point_, prelude::*, RotatedRect, Size_, ValidPointType, ValidSizeType},
 	opencv_type_simple_generic,
+	Result,
 };
 
 valid_types!(ValidRectType: i32, f32, f64);
@@ -81,6 +82,23 @@ impl<T: ValidRectType> Rect_<T> {
 		self.x <= pt.x && pt.x < self.x + self.width && self.y <= pt.y && pt.y < self.y + self.height
 	}
 
+	/// Intersection of the rectangles, `None` if they don't overlap (rectangles that only touch by the edge don't)
+	#[inline]
+	pub fn intersect(&self, other: &Self) -> Option<Self> {
+		let out = *self & *other;
+		if out.empty() {
+			None
+		} else {
+			Some(out)
+		}
+	}
+
+	/// Minimal rectangle containing both rectangles, same as `self | other`
+	#[inline]
+	pub fn union(&self, other: &Self) -> Self {
+		*self | *other
+	}
+
 	#[inline]
 	pub fn to<D: ValidRectType + NumCast>(&self) -> Option<Rect_<D>> where T: ToPrimitive {
 		Some(Rect_ { x: D::from(self.x)?, y: D::from(self.y)?, width: D::from(self.width)?, height: D::from(self.height)? })
@@ -232,6 +250,20 @@ impl<T: ValidRectType> BitAndAssign for Rect_<T> {
 	}
 }
 
+pub trait RotatedRectTraitManual: RotatedRectTrait {
+	/// 4 vertices of the rectangle in the order: bottom left, top left, top right, bottom right
+	///
+	/// Safe alternative to `points()` that doesn't require a preallocated slice.
+	#[inline]
+	fn points_array(&self) -> Result<[Point2f; 4]> {
+		let mut out = [Point2f::default(); 4];
+		self.points(&mut out)?;
+		Ok(out)
+	}
+}
+
+impl<T: RotatedRectTrait> RotatedRectTraitManual for T {}
+
 impl fmt::Debug for RotatedRect {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		f.debug_struct("RotatedRect")
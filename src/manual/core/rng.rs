@@ -0,0 +1,51 @@
+use crate::{
+	core::{self, RNGTrait, ToInputArray, ToInputOutputArray},
+	Result,
+};
+
+/// Distribution used by `RNG::fill()`, typed alternative to the `RNG_UNIFORM` and `RNG_NORMAL` constants
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RngDistType {
+	/// Uniform distribution, `a` and `b` of `fill()` are the inclusive lower and exclusive upper boundaries
+	Uniform,
+	/// Normal distribution, `a` and `b` of `fill()` are the mean and the standard deviation
+	Normal,
+}
+
+impl From<RngDistType> for i32 {
+	#[inline]
+	fn from(s: RngDistType) -> Self {
+		match s {
+			RngDistType::Uniform => core::RNG_UNIFORM,
+			RngDistType::Normal => core::RNG_NORMAL,
+		}
+	}
+}
+
+pub trait RNGTraitManual: RNGTrait {
+	/// Like `fill()`, but with a typed distribution
+	#[inline]
+	fn fill_dist(&mut self, mat: &mut dyn ToInputOutputArray, dist: RngDistType, a: &dyn ToInputArray, b: &dyn ToInputArray, saturate_range: bool) -> Result<()> {
+		self.fill(mat, dist.into(), a, b, saturate_range)
+	}
+
+	/// Uniformly distributed integer in the `[a, b)` range
+	#[inline]
+	fn uniform_i32(&mut self, a: i32, b: i32) -> Result<i32> {
+		self.uniform(a, b)
+	}
+
+	/// Uniformly distributed `f32` in the `[a, b)` range
+	#[inline]
+	fn uniform_f32(&mut self, a: f32, b: f32) -> Result<f32> {
+		self.uniform_1(a, b)
+	}
+
+	/// Uniformly distributed `f64` in the `[a, b)` range
+	#[inline]
+	fn uniform_f64(&mut self, a: f64, b: f64) -> Result<f64> {
+		self.uniform_2(a, b)
+	}
+}
+
+impl<T: RNGTrait> RNGTraitManual for T {}
@@ -0,0 +1,139 @@
+use std::{
+	ffi::c_void,
+	marker::PhantomData,
+	slice,
+};
+
+use crate::{
+	core::{self, DataType, SparseMat, SparseMatConstIterator, SparseMatTrait},
+	Error,
+	prelude::*,
+	Result,
+	sys,
+};
+
+/// Maximum number of dimensions of a `SparseMat`, matches `CV_MAX_DIM`
+const MAX_DIM: usize = 32;
+
+impl SparseMat {
+	/// Create a new sparse matrix with the dimensions of `sizes` and the element type `typ`
+	pub fn new_sizes(sizes: &[i32], typ: i32) -> Result<SparseMat> {
+		if sizes.is_empty() || sizes.len() > MAX_DIM {
+			return Err(Error::new(core::StsBadArg, format!("SparseMat dims must be within 1..={}, but it's: {}", MAX_DIM, sizes.len())));
+		}
+		Self::new(sizes.len() as i32, &sizes[0], typ)
+	}
+}
+
+fn match_sparse_indices(mat: &(impl SparseMatTrait + ?Sized), idx: &[i32]) -> Result<()> {
+	let dims = mat.dims()?;
+	if idx.len() != dims as usize {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("SparseMat dims is: {}, but requested dims is: {}", dims, idx.len())));
+	}
+	for (dim, &i) in idx.iter().enumerate() {
+		let size = mat.size_1(dim as i32)?;
+		if i < 0 || i >= size {
+			return Err(Error::new(core::StsOutOfRange, format!("Index: {} along dimension: {} out of bounds 0..{}", i, dim, size)));
+		}
+	}
+	Ok(())
+}
+
+fn match_sparse_format<T: DataType>(mat: &(impl SparseMatTrait + ?Sized)) -> Result<()> {
+	let mat_type = mat.typ()?;
+	if mat_type == T::typ() {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsUnmatchedFormats, format!("SparseMat type is: {}, but requested type is: {}", mat_type, T::typ())))
+	}
+}
+
+pub trait SparseMatTraitManual: SparseMatTrait {
+	/// Return the element at `idx` or `None` if it's not stored in the matrix
+	fn find<T: DataType>(&self, idx: &[i32]) -> Result<Option<&T>> {
+		extern "C" { fn cv_manual_SparseMat_find(instance: *const c_void, idx: *const i32) -> sys::Result<*const u8>; }
+		match_sparse_format::<T>(self)?;
+		match_sparse_indices(self, idx)?;
+		unsafe { cv_manual_SparseMat_find(self.as_raw_SparseMat(), idx.as_ptr()) }.into_result()
+			.map(|ptr| unsafe { (ptr as *const T).as_ref() })
+	}
+
+	/// Return the element at `idx`, zero (default) if it's not stored in the matrix
+	#[inline]
+	fn value<T: DataType + Default>(&self, idx: &[i32]) -> Result<T> {
+		self.find(idx).map(|v| v.copied().unwrap_or_default())
+	}
+
+	/// Return the mutable reference to the element at `idx`, the element is created (zero-initialized) if it
+	/// doesn't exist yet
+	fn ref_value<T: DataType>(&mut self, idx: &[i32]) -> Result<&mut T> {
+		extern "C" { fn cv_manual_SparseMat_ref(instance: *mut c_void, idx: *const i32) -> sys::Result<*mut u8>; }
+		match_sparse_format::<T>(self)?;
+		match_sparse_indices(self, idx)?;
+		unsafe { cv_manual_SparseMat_ref(self.as_raw_mut_SparseMat(), idx.as_ptr()) }.into_result()
+			.and_then(|ptr| unsafe { (ptr as *mut T).as_mut() }.ok_or_else(|| Error::new(core::StsNullPtr, "Function returned Null pointer".to_string())))
+	}
+
+	/// Iterate over the stored (non-zero) elements of a single-channel matrix yielding `(index, value)` pairs,
+	/// the order is unspecified
+	///
+	/// Supported depths are `CV_8U`, `CV_8S`, `CV_16U`, `CV_16S`, `CV_32S`, `CV_32F` and `CV_64F`.
+	fn nodes(&self) -> Result<SparseMatNodeIter<'_>> {
+		let channels = self.channels()?;
+		if channels != 1 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("SparseMat must be single-channel, but it has: {} channels", channels)));
+		}
+		let depth = self.depth()?;
+		match depth {
+			core::CV_8U | core::CV_8S | core::CV_16U | core::CV_16S | core::CV_32S | core::CV_32F | core::CV_64F => {}
+			_ => return Err(Error::new(core::StsUnsupportedFormat, format!("Unsupported SparseMat depth: {}", depth))),
+		}
+		Ok(SparseMatNodeIter {
+			iter: self.begin()?,
+			dims: self.dims()? as usize,
+			depth,
+			_d: PhantomData,
+		})
+	}
+}
+
+impl<T: SparseMatTrait + ?Sized> SparseMatTraitManual for T {}
+
+/// Iterator over the stored elements of a `SparseMat`, created by `SparseMatTraitManual::nodes()`
+pub struct SparseMatNodeIter<'m> {
+	iter: SparseMatConstIterator,
+	dims: usize,
+	depth: i32,
+	_d: PhantomData<&'m SparseMat>,
+}
+
+impl Iterator for SparseMatNodeIter<'_> {
+	type Item = (Vec<i32>, f64);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		extern "C" {
+			fn cv_manual_SparseMatConstIterator_has_elements(instance: *const c_void) -> bool;
+			fn cv_manual_SparseMatConstIterator_idx(instance: *const c_void) -> *const i32;
+			fn cv_manual_SparseMatConstIterator_incr(instance: *mut c_void);
+		}
+		if !unsafe { cv_manual_SparseMatConstIterator_has_elements(self.iter.as_raw_SparseMatConstIterator()) } {
+			return None;
+		}
+		let idx = unsafe { slice::from_raw_parts(cv_manual_SparseMatConstIterator_idx(self.iter.as_raw_SparseMatConstIterator()), self.dims) }.to_vec();
+		let ptr = self.iter.ptr() as *const u8;
+		let value = unsafe {
+			match self.depth {
+				core::CV_8U => *ptr as f64,
+				core::CV_8S => *(ptr as *const i8) as f64,
+				core::CV_16U => *(ptr as *const u16) as f64,
+				core::CV_16S => *(ptr as *const i16) as f64,
+				core::CV_32S => *(ptr as *const i32) as f64,
+				core::CV_32F => *(ptr as *const f32) as f64,
+				core::CV_64F => *(ptr as *const f64),
+				_ => unreachable!("SparseMat depth is checked in nodes()"),
+			}
+		};
+		unsafe { cv_manual_SparseMatConstIterator_incr(self.iter.as_raw_mut_SparseMatConstIterator()) };
+		Some((idx, value))
+	}
+}
@@ -1,4 +1,7 @@
-use std::ffi::c_void;
+use std::{
+	ffi::c_void,
+	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
+};
 
 use crate::{
 	core::{_InputArray, ToInputArray},
@@ -103,6 +106,69 @@ impl<T: ValidScalarType> From<(T, T, T, T)> for Scalar_<T> {
 	}
 }
 
+impl<T> Add for Scalar_<T>
+	where
+		T: ValidScalarType + AddAssign,
+{
+	type Output = Scalar_<T>;
+
+	fn add(mut self, rhs: Scalar_<T>) -> Self::Output {
+		self += rhs;
+		self
+	}
+}
+
+impl<T> Sub for Scalar_<T>
+	where
+		T: ValidScalarType + SubAssign,
+{
+	type Output = Scalar_<T>;
+
+	fn sub(mut self, rhs: Scalar_<T>) -> Self::Output {
+		self -= rhs;
+		self
+	}
+}
+
+impl<T> Mul<T> for Scalar_<T>
+	where
+		T: ValidScalarType + MulAssign,
+{
+	type Output = Scalar_<T>;
+
+	fn mul(mut self, rhs: T) -> Self::Output {
+		self *= rhs;
+		self
+	}
+}
+
+impl<T> AddAssign for Scalar_<T>
+	where
+		T: ValidScalarType + AddAssign,
+{
+	fn add_assign(&mut self, rhs: Scalar_<T>) {
+		self.0.iter_mut().zip(rhs.0.iter()).for_each(|(l, &r)| *l += r);
+	}
+}
+
+impl<T> SubAssign for Scalar_<T>
+	where
+		T: ValidScalarType + SubAssign,
+{
+	fn sub_assign(&mut self, rhs: Scalar_<T>) {
+		self.0.iter_mut().zip(rhs.0.iter()).for_each(|(l, &r)| *l -= r);
+	}
+}
+
+impl<T> MulAssign<T> for Scalar_<T>
+	where
+		T: ValidScalarType + MulAssign,
+{
+	fn mul_assign(&mut self, rhs: T) {
+		self.0.iter_mut().for_each(|l| *l *= rhs);
+	}
+}
+
 impl ToInputArray for Scalar_<f64> {
 	fn input_array(&self) -> Result<_InputArray> {
 		extern "C" { fn cv_Scalar_input_array(instance: *const Scalar_<f64>) -> sys::Result<*mut c_void>; }
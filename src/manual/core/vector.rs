@@ -5,6 +5,7 @@ use std::{
 	iter::FromIterator,
 	marker::PhantomData,
 	mem::ManuallyDrop,
+	ops::{Index, IndexMut},
 	slice,
 };
 
@@ -12,6 +13,7 @@ pub use iter::{VectorIterator, VectorRefIterator};
 pub use vector_extern::{VectorElement, VectorExtern, VectorExternCopyNonBool};
 
 use crate::{
+	core,
 	platform_types::size_t,
 	Result,
 	traits::{Boxed, OpenCVType, OpenCVTypeArg, OpenCVTypeExternContainer},
@@ -171,6 +173,15 @@ impl<T: VectorElement> Vector<T> where Self: VectorExtern<T> {
 		}
 	}
 
+	/// Get mutable reference to the element at the specified `index`
+	///
+	/// This method is only available for OpenCV types that are Copy, with the exception of bool
+	/// because bool is handled in a special way on the C++ side.
+	pub fn get_mut(&mut self, index: size_t) -> Result<&mut T> where Self: VectorExternCopyNonBool<T> {
+		vector_index_check(index, self.len())?;
+		Ok(&mut self.as_mut_slice()[index])
+	}
+
 	pub fn to_vec(&self) -> Vec<T> {
 		T::opencv_vector_to_vec(self)
 	}
@@ -190,6 +201,13 @@ impl<T: VectorElement> From<Vector<T>> for Vec<T> where Vector<T>: VectorExtern<
 	}
 }
 
+impl<T: VectorElement> From<&Vector<T>> for Vec<T> where Vector<T>: VectorExtern<T> {
+	#[inline]
+	fn from(from: &Vector<T>) -> Self {
+		from.to_vec()
+	}
+}
+
 impl<T: VectorElement> From<Vec<<T as OpenCVType<'_>>::Arg>> for Vector<T> where Vector<T>: VectorExtern<T> {
 	#[inline]
 	fn from(from: Vec<<T as OpenCVType<'_>>::Arg>) -> Self {
@@ -197,6 +215,14 @@ impl<T: VectorElement> From<Vec<<T as OpenCVType<'_>>::Arg>> for Vector<T> where
 	}
 }
 
+/// Copies the elements in a single call, available for the same types as `as_slice()`
+impl<T: VectorElement> From<&[T]> for Vector<T> where Self: VectorExtern<T> + VectorExternCopyNonBool<T> {
+	#[inline]
+	fn from(from: &[T]) -> Self {
+		unsafe { Self::from_raw(Self::extern_from_slice(from.as_ptr(), from.len())) }
+	}
+}
+
 impl<'a, T: VectorElement> FromIterator<<T as OpenCVType<'a>>::Arg> for Vector<T> where Self: VectorExtern<T> {
 	#[inline]
 	fn from_iter<I: IntoIterator<Item=<T as OpenCVType<'a>>::Arg>>(s: I) -> Vector<T> {
@@ -218,6 +244,24 @@ impl<T: VectorElement> Borrow<[T]> for Vector<T> where Self: VectorExtern<T> + V
 	}
 }
 
+/// Panics if `index` is out of bounds, use `get()` for the checked access
+impl<T: VectorElement> Index<size_t> for Vector<T> where Self: VectorExtern<T> + VectorExternCopyNonBool<T> {
+	type Output = T;
+
+	#[inline]
+	fn index(&self, index: size_t) -> &T {
+		&self.as_slice()[index]
+	}
+}
+
+/// Panics if `index` is out of bounds, use `get_mut()` for the checked access
+impl<T: VectorElement> IndexMut<size_t> for Vector<T> where Self: VectorExtern<T> + VectorExternCopyNonBool<T> {
+	#[inline]
+	fn index_mut(&mut self, index: size_t) -> &mut T {
+		&mut self.as_mut_slice()[index]
+	}
+}
+
 impl<T: VectorElement + fmt::Debug> fmt::Debug for Vector<T> where Self: VectorExtern<T> {
 	#[inline]
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -227,7 +271,9 @@ impl<T: VectorElement + fmt::Debug> fmt::Debug for Vector<T> where Self: VectorE
 
 impl<T: VectorElement> Drop for Vector<T> where Self: VectorExtern<T> {
 	fn drop(&mut self) {
-		unsafe { self.extern_delete() }
+		if core::destructor_guard_allows_delete() {
+			unsafe { self.extern_delete() }
+		}
 	}
 }
 
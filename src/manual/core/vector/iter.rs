@@ -40,18 +40,21 @@ impl<T: VectorElement> Iterator for VectorIterator<T> where Vector<T>: VectorExt
 	type Item = T;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		let out = self.vec.get(self.i);
-		self.i += 1;
-		out.ok()
+		let out = self.vec.get(self.i).ok();
+		if out.is_some() {
+			self.i += 1;
+		}
+		out
 	}
 
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let len = self.vec.len() - self.i;
+		let len = self.vec.len().saturating_sub(self.i);
 		(len, Some(len))
 	}
 
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		self.vec.get(n).ok()
+		self.i = self.i.saturating_add(n).min(self.vec.len());
+		self.next()
 	}
 }
 
@@ -74,18 +77,21 @@ impl<T: VectorElement> Iterator for VectorRefIterator<'_, T> where Vector<T>: Ve
 	type Item = T;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		let out = self.vec.get(self.i);
-		self.i += 1;
-		out.ok()
+		let out = self.vec.get(self.i).ok();
+		if out.is_some() {
+			self.i += 1;
+		}
+		out
 	}
 
 	fn size_hint(&self) -> (usize, Option<usize>) {
-		let len = self.vec.len() - self.i;
+		let len = self.vec.len().saturating_sub(self.i);
 		(len, Some(len))
 	}
 
 	fn nth(&mut self, n: usize) -> Option<Self::Item> {
-		self.vec.get(n).ok()
+		self.i = self.i.saturating_add(n).min(self.vec.len());
+		self.next()
 	}
 }
 
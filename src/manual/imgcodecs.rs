@@ -0,0 +1,135 @@
+use std::{
+	fs,
+	iter::FusedIterator,
+	path::{Path, PathBuf},
+	vec,
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	imgcodecs,
+	prelude::*,
+	Result,
+};
+
+/// Iterator lazily reading the images from a list of files
+///
+/// When a file can't be read the iterator yields a single `Err` and then stops.
+pub struct ImageSequence {
+	paths: vec::IntoIter<PathBuf>,
+	flags: i32,
+	done: bool,
+}
+
+impl ImageSequence {
+	/// Iterate over the files in `paths` in the specified order
+	pub fn new(paths: Vec<PathBuf>) -> Self {
+		Self { paths: paths.into_iter(), flags: imgcodecs::IMREAD_COLOR, done: false }
+	}
+
+	/// Iterate over the files matching `pattern` sorted by their paths
+	///
+	/// Wildcards `*` and `?` are only supported in the file name part of the `pattern`, e.g. `frames/img_*.png`.
+	pub fn from_glob(pattern: &str) -> Result<Self> {
+		let pattern = Path::new(pattern);
+		let name = pattern.file_name()
+			.and_then(|name| name.to_str())
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Pattern has no file name: {}", pattern.display())))?;
+		let dir = match pattern.parent() {
+			Some(dir) if dir.as_os_str().is_empty() => Path::new("."),
+			Some(dir) => dir,
+			None => Path::new("."),
+		};
+		if dir.to_str().map_or(false, |dir| dir.contains(&['*', '?'][..])) {
+			return Err(Error::new(core::StsBadArg, format!("Wildcards are only supported in the file name: {}", pattern.display())));
+		}
+		let entries = fs::read_dir(dir)
+			.map_err(|e| Error::new(core::StsError, format!("Can't read directory: {}: {}", dir.display(), e)))?;
+		let mut paths = vec![];
+		for entry in entries {
+			let entry = entry.map_err(|e| Error::new(core::StsError, format!("Can't read directory: {}: {}", dir.display(), e)))?;
+			if entry.file_name().to_str().map_or(false, |file_name| wildcard_match(name.as_bytes(), file_name.as_bytes())) {
+				paths.push(dir.join(entry.file_name()));
+			}
+		}
+		paths.sort();
+		Ok(Self::new(paths))
+	}
+
+	/// Flags passed to `imread()`, `IMREAD_COLOR` by default
+	#[inline]
+	pub fn with_flags(mut self, flags: i32) -> Self {
+		self.flags = flags;
+		self
+	}
+
+	/// Paths of the images that are not read yet
+	#[inline]
+	pub fn remaining_paths(&self) -> &[PathBuf] {
+		self.paths.as_slice()
+	}
+
+	fn read(&self, path: &Path) -> Result<Mat> {
+		let path_str = path.to_str()
+			.ok_or_else(|| Error::new(core::StsBadArg, format!("Path is not valid UTF-8: {}", path.display())))?;
+		let out = imgcodecs::imread(path_str, self.flags)?;
+		if out.empty()? {
+			return Err(Error::new(core::StsObjectNotFound, format!("Can't read image: {}", path.display())));
+		}
+		Ok(out)
+	}
+}
+
+impl Iterator for ImageSequence {
+	type Item = Result<Mat>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let out = self.paths.next().map(|path| self.read(&path));
+		if !matches!(out, Some(Ok(_))) {
+			self.done = true;
+		}
+		out
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.done {
+			(0, Some(0))
+		} else {
+			(0, Some(self.paths.len()))
+		}
+	}
+}
+
+impl FusedIterator for ImageSequence {}
+
+/// Match `name` against `pattern` where `*` matches any run of characters and `?` a single one
+fn wildcard_match(pattern: &[u8], name: &[u8]) -> bool {
+	let (mut p, mut n) = (0, 0);
+	let mut backtrack = None;
+	while n < name.len() {
+		match pattern.get(p) {
+			Some(b'*') => {
+				backtrack = Some((p, n));
+				p += 1;
+			}
+			Some(&c) if c == b'?' || c == name[n] => {
+				p += 1;
+				n += 1;
+			}
+			_ => match backtrack {
+				Some((star_p, star_n)) => {
+					backtrack = Some((star_p, star_n + 1));
+					p = star_p + 1;
+					n = star_n + 1;
+				}
+				None => return false,
+			},
+		}
+	}
+	pattern[p..].iter().all(|&c| c == b'*')
+}
@@ -0,0 +1,79 @@
+use crate::{
+	core::{self, Point2f},
+	Error,
+	Result,
+};
+
+pub use binary_descriptor::*;
+pub use csv::*;
+pub use dataset::*;
+pub use draw::*;
+pub use filter::*;
+pub use keyframe::*;
+pub use keyline::*;
+pub use line_detector::*;
+pub use lsd_detector::*;
+pub use mask::*;
+pub use matcher::*;
+pub use merge::*;
+pub use render::*;
+
+mod binary_descriptor;
+mod csv;
+mod dataset;
+mod draw;
+#[cfg(feature = "serde")]
+pub mod export;
+mod filter;
+mod keyframe;
+mod keyline;
+mod line_detector;
+mod lsd_detector;
+mod mask;
+mod matcher;
+mod merge;
+pub mod registry;
+mod render;
+
+#[inline]
+fn check_positive(name: &str, val: i32) -> Result<()> {
+	if val >= 1 {
+		Ok(())
+	} else {
+		Err(Error::new(core::StsBadArg, format!("{} must be at least 1, but it's: {}", name, val)))
+	}
+}
+
+/// Clip the segment to the rectangle `0..=max.x` x `0..=max.y` (Liang-Barsky)
+///
+/// Returns `None` if the segment lies fully outside or has non-finite coordinates. The ends that are inside are
+/// returned unchanged.
+pub(super) fn clip_segment(start: Point2f, end: Point2f, max: Point2f) -> Option<(Point2f, Point2f)> {
+	let (dx, dy) = (end.x - start.x, end.y - start.y);
+	if !(start.x.is_finite() && start.y.is_finite() && dx.is_finite() && dy.is_finite()) {
+		return None;
+	}
+	let (mut t0, mut t1) = (0f32, 1f32);
+	for &(p, q) in &[(-dx, start.x), (dx, max.x - start.x), (-dy, start.y), (dy, max.y - start.y)] {
+		if p == 0. {
+			if q < 0. {
+				return None;
+			}
+		} else {
+			let r = q / p;
+			if p < 0. {
+				t0 = t0.max(r);
+			} else {
+				t1 = t1.min(r);
+			}
+		}
+	}
+	if t0 > t1 {
+		return None;
+	}
+	let at = |t: f32| Point2f::new(start.x + t * dx, start.y + t * dy);
+	Some((
+		if t0 > 0. { at(t0) } else { start },
+		if t1 < 1. { at(t1) } else { end },
+	))
+}
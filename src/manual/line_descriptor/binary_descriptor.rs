@@ -0,0 +1,261 @@
+use std::{
+	ffi::c_void,
+	ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+	core::{self, Mat, MatView},
+	Error,
+	line_descriptor::{BinaryDescriptor, BinaryDescriptor_Params, BinaryDescriptor_ParamsTrait, BinaryDescriptorTrait, KeyLine},
+	prelude::*,
+	Result,
+	sys,
+	traits::Boxed,
+	types::{PtrOfBinaryDescriptor, VectorOfKeyLine},
+};
+
+use super::check_positive;
+
+pub trait BinaryDescriptorTraitManual: BinaryDescriptorTrait {
+	/// Like `detect()`, but `None` can be passed instead of an empty `mask`
+	#[inline]
+	fn detect_masked(&mut self, image: &Mat, keylines: &mut VectorOfKeyLine, mask: Option<&Mat>) -> Result<()> {
+		self.detect(image, keylines, mask.unwrap_or(core::empty_mask()))
+	}
+
+	/// Like `detect_masked()` without a mask, but on the borrowed `image` view
+	#[inline]
+	fn detect_view(&mut self, image: &MatView, keylines: &mut VectorOfKeyLine) -> Result<()> {
+		self.detect_masked(image.as_mat(), keylines, None)
+	}
+
+	/// Like `detect_masked()`, but only the base octave (original image resolution) is processed
+	///
+	/// The number of octaves is temporarily set to 1 and restored afterwards, even if the detection fails.
+	fn detect_base_octave(&mut self, image: &Mat, keylines: &mut VectorOfKeyLine, mask: Option<&Mat>) -> Result<()> {
+		let num_of_octaves = self.get_num_of_octaves()?;
+		self.set_num_of_octaves(1)?;
+		let mut guard = OctavesGuard { detector: self, num_of_octaves };
+		guard.detect_masked(image, keylines, mask)
+	}
+
+	/// Return a copy of all the parameters of this descriptor
+	///
+	/// Includes every `BinaryDescriptor_Params` field: number of octaves, band width, reduction ratio and `ksize_`.
+	fn params(&self) -> Result<BinaryDescriptor_Params> {
+		extern "C" { fn cv_manual_BinaryDescriptor_params(instance: *const c_void) -> sys::Result<*mut c_void>; }
+		unsafe { cv_manual_BinaryDescriptor_params(self.as_raw_BinaryDescriptor()) }.into_result()
+			.map(|ptr| unsafe { BinaryDescriptor_Params::from_raw(ptr) })
+	}
+
+	/// Create a new independent descriptor with the same parameters, see `params()`
+	///
+	/// All of the configuration is carried over, the intermediate per-image state (e.g. the image pyramid) is not,
+	/// but it's recomputed on every call anyway. Useful to give each worker thread its own instance.
+	fn try_clone(&self) -> Result<PtrOfBinaryDescriptor> {
+		BinaryDescriptor::create_binary_descriptor_1(self.params()?)
+	}
+
+	/// Number of bits in a single binary descriptor (256 for LBD, i.e. `BINARY_DESCRIPTOR_BYTES * 8`)
+	///
+	/// Same as `descriptor_size()`, which despite its name already reports bits and not bytes. Only meaningful for
+	/// the binary descriptors, with `return_float_descr` each descriptor is a row of `f32` values (see
+	/// `compute_float()`) that this number doesn't describe.
+	#[inline]
+	fn descriptor_bits(&self) -> Result<i32> {
+		self.descriptor_size()
+	}
+
+	/// Compute the float descriptors (`return_float_descr` mode) of the `keylines` detected in the `image`
+	///
+	/// Returns the `CV_32FC1` descriptor matrix with one row per keyline together with the keylines that the
+	/// descriptors were computed for, `compute()` can drop some of the input ones.
+	fn compute_float(&self, image: &Mat, keylines: &VectorOfKeyLine) -> Result<(Mat, Vec<KeyLine>)> {
+		let mut keylines = keylines.clone();
+		let mut descriptors = Mat::default();
+		self.compute(image, &mut keylines, &mut descriptors, true)?;
+		let typ = descriptors.typ()?;
+		if !descriptors.empty()? && typ != core::CV_32FC1 {
+			#[cfg(not(ocvrs_opencv_branch_32))]
+			let typ = core::type_to_string(typ)?;
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Float descriptors must be CV_32FC1, but Mat type is: {}", typ)));
+		}
+		if descriptors.rows() as usize != keylines.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Number of descriptors: {} doesn't match the number of keylines: {}", descriptors.rows(), keylines.len())));
+		}
+		Ok((descriptors, keylines.to_vec()))
+	}
+
+	/// Detect lines in each of the `images` in parallel, returning the keylines in the order of `images`
+	///
+	/// Each rayon worker gets its own descriptor created with all of the parameters of this one (see `params()`),
+	/// so the results are the same as from `detect()`.
+	#[cfg(feature = "rayon")]
+	fn detect_parallel(&self, images: &[Mat]) -> Result<Vec<Vec<KeyLine>>> {
+		// neither Mat nor BinaryDescriptor_Params are Sync, so share plain values and give each worker its own Mat
+		// header sharing the image data
+		let params = self.params()?;
+		let (num_of_octave, width_of_band, reduction_ratio, ksize) = (params.num_of_octave_(), params.width_of_band_(), params.reduction_ratio(), params.ksize_());
+		let images = images.iter()
+			.map(Mat::copy)
+			.collect::<Result<Vec<_>>>()?;
+		images.into_par_iter()
+			.map_init(
+				|| {
+					let mut params = BinaryDescriptor_Params::default()?;
+					params.set_num_of_octave_(num_of_octave);
+					params.set_width_of_band_(width_of_band);
+					params.set_reduction_ratio(reduction_ratio);
+					params.set_ksize_(ksize);
+					BinaryDescriptor::new(&params)
+				},
+				|detector, image| {
+					let detector = detector.as_mut()
+						.map_err(|e| Error::new(e.code, e.message.clone()))?;
+					let mut keylines = VectorOfKeyLine::new();
+					detector.detect_masked(&image, &mut keylines, None)?;
+					Ok(keylines.to_vec())
+				},
+			)
+			.collect()
+	}
+}
+
+impl<T: BinaryDescriptorTrait> BinaryDescriptorTraitManual for T {}
+
+/// Restores the number of octaves of the wrapped detector on drop
+struct OctavesGuard<'d, T: BinaryDescriptorTrait + ?Sized> {
+	detector: &'d mut T,
+	num_of_octaves: i32,
+}
+
+impl<T: BinaryDescriptorTrait + ?Sized> Deref for OctavesGuard<'_, T> {
+	type Target = T;
+
+	#[inline]
+	fn deref(&self) -> &Self::Target {
+		self.detector
+	}
+}
+
+impl<T: BinaryDescriptorTrait + ?Sized> DerefMut for OctavesGuard<'_, T> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.detector
+	}
+}
+
+impl<T: BinaryDescriptorTrait + ?Sized> Drop for OctavesGuard<'_, T> {
+	fn drop(&mut self) {
+		// the setter only fails on the OpenCV exception which can't happen for the previously valid value
+		let _ = self.detector.set_num_of_octaves(self.num_of_octaves);
+	}
+}
+
+/// Number of bytes in a single binary descriptor computed by `BinaryDescriptor`
+pub const BINARY_DESCRIPTOR_BYTES: usize = 32;
+
+/// Copy the 256-bit binary descriptor at `row` of the `CV_8UC1` `descriptors` computed by `BinaryDescriptor`
+pub fn binary_descriptor_row(descriptors: &Mat, row: i32) -> Result<[u8; BINARY_DESCRIPTOR_BYTES]> {
+	let typ = descriptors.typ()?;
+	if typ != core::CV_8UC1 {
+		#[cfg(not(ocvrs_opencv_branch_32))]
+		let typ = core::type_to_string(typ)?;
+		return Err(Error::new(core::StsUnsupportedFormat, format!("Binary descriptors must be CV_8UC1, but Mat type is: {}", typ)));
+	}
+	if descriptors.cols() != BINARY_DESCRIPTOR_BYTES as i32 {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("Binary descriptors must have {} columns, but Mat has: {}", BINARY_DESCRIPTOR_BYTES, descriptors.cols())));
+	}
+	if row < 0 || row >= descriptors.rows() {
+		return Err(Error::new(core::StsOutOfRange, format!("Descriptor row: {} is out of bounds: 0..{}", row, descriptors.rows())));
+	}
+	let mut out = [0; BINARY_DESCRIPTOR_BYTES];
+	out.copy_from_slice(descriptors.at_row::<u8>(row)?);
+	Ok(out)
+}
+
+/// Number of differing bits between two binary descriptors
+#[inline]
+pub fn hamming_distance(a: &[u8; BINARY_DESCRIPTOR_BYTES], b: &[u8; BINARY_DESCRIPTOR_BYTES]) -> u32 {
+	a.iter().zip(b.iter())
+		.map(|(a, b)| (a ^ b).count_ones())
+		.sum()
+}
+
+/// Hamming distance between the binary descriptors at rows `i` and `j` of `descriptors`
+///
+/// Same validation rules as in [binary_descriptor_row] apply.
+pub fn descriptor_distance_mat(descriptors: &Mat, i: i32, j: i32) -> Result<u32> {
+	Ok(hamming_distance(&binary_descriptor_row(descriptors, i)?, &binary_descriptor_row(descriptors, j)?))
+}
+
+impl BinaryDescriptor_Params {
+	/// Start building new parameters, the values that are not set explicitly keep their OpenCV defaults
+	#[inline]
+	pub fn builder() -> BinaryDescriptorParamsBuilder {
+		BinaryDescriptorParamsBuilder::default()
+	}
+}
+
+/// Builder for [BinaryDescriptor_Params]
+#[derive(Clone, Debug, Default)]
+pub struct BinaryDescriptorParamsBuilder {
+	num_of_octaves: Option<i32>,
+	width_of_band: Option<i32>,
+	reduction_ratio: Option<i32>,
+	ksize: Option<i32>,
+}
+
+impl BinaryDescriptorParamsBuilder {
+	/// Number of octaves in the image pyramid
+	#[inline]
+	pub fn num_of_octaves(mut self, num_of_octaves: i32) -> Self {
+		self.num_of_octaves = Some(num_of_octaves);
+		self
+	}
+
+	/// Width of the bands of the line support region
+	#[inline]
+	pub fn width_of_band(mut self, width_of_band: i32) -> Self {
+		self.width_of_band = Some(width_of_band);
+		self
+	}
+
+	/// Scale factor between consecutive octaves of the image pyramid
+	#[inline]
+	pub fn reduction_ratio(mut self, reduction_ratio: i32) -> Self {
+		self.reduction_ratio = Some(reduction_ratio);
+		self
+	}
+
+	/// Size of the Gaussian kernel used to blur the octave images
+	#[inline]
+	pub fn ksize(mut self, ksize: i32) -> Self {
+		self.ksize = Some(ksize);
+		self
+	}
+
+	pub fn build(self) -> Result<BinaryDescriptor_Params> {
+		let mut out = BinaryDescriptor_Params::default()?;
+		if let Some(num_of_octaves) = self.num_of_octaves {
+			check_positive("num_of_octaves", num_of_octaves)?;
+			out.set_num_of_octave_(num_of_octaves);
+		}
+		if let Some(width_of_band) = self.width_of_band {
+			check_positive("width_of_band", width_of_band)?;
+			out.set_width_of_band_(width_of_band);
+		}
+		if let Some(reduction_ratio) = self.reduction_ratio {
+			check_positive("reduction_ratio", reduction_ratio)?;
+			out.set_reduction_ratio(reduction_ratio);
+		}
+		if let Some(ksize) = self.ksize {
+			check_positive("ksize", ksize)?;
+			out.set_ksize_(ksize);
+		}
+		Ok(out)
+	}
+}
@@ -0,0 +1,118 @@
+use std::{
+	io::{self, BufRead, BufReader, Read, Write},
+	str::FromStr,
+};
+
+use crate::{
+	core::Point2f,
+	line_descriptor::KeyLine,
+	types::VectorOfKeyLine,
+};
+
+/// Column names of the CSV produced by `write_keylines_csv()`, in order
+pub const KEYLINES_CSV_HEADER: [&str; 17] = [
+	"angle",
+	"class_id",
+	"octave",
+	"pt_x",
+	"pt_y",
+	"response",
+	"size",
+	"start_point_x",
+	"start_point_y",
+	"end_point_x",
+	"end_point_y",
+	"s_point_in_octave_x",
+	"s_point_in_octave_y",
+	"e_point_in_octave_x",
+	"e_point_in_octave_y",
+	"line_length",
+	"num_of_pixels",
+];
+
+/// Write `keylines` as CSV with a header row followed by one row per `KeyLine`
+///
+/// Floating point values are written in the shortest form that parses back to the same `f32`, so
+/// `read_keylines_csv()` restores them exactly.
+pub fn write_keylines_csv<W: Write>(w: &mut W, keylines: &VectorOfKeyLine) -> io::Result<()> {
+	writeln!(w, "{}", KEYLINES_CSV_HEADER.join(","))?;
+	for k in keylines {
+		writeln!(
+			w,
+			"{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+			k.angle,
+			k.class_id,
+			k.octave,
+			k.pt.x,
+			k.pt.y,
+			k.response,
+			k.size,
+			k.start_point_x,
+			k.start_point_y,
+			k.end_point_x,
+			k.end_point_y,
+			k.s_point_in_octave_x,
+			k.s_point_in_octave_y,
+			k.e_point_in_octave_x,
+			k.e_point_in_octave_y,
+			k.line_length,
+			k.num_of_pixels,
+		)?;
+	}
+	Ok(())
+}
+
+/// Read the keylines written by `write_keylines_csv()`
+///
+/// The header row must match [KEYLINES_CSV_HEADER], empty lines are skipped. Malformed input results in an
+/// error of `io::ErrorKind::InvalidData` kind.
+pub fn read_keylines_csv<R: Read>(r: R) -> io::Result<VectorOfKeyLine> {
+	let mut lines = BufReader::new(r).lines();
+	let header = lines.next().transpose()?.unwrap_or_default();
+	if header.trim_end().split(',').ne(KEYLINES_CSV_HEADER.iter().copied()) {
+		return Err(invalid_data(format!("Unexpected CSV header: {}", header)));
+	}
+	let mut out = VectorOfKeyLine::new();
+	for (i, line) in lines.enumerate() {
+		let line = line?;
+		let line = line.trim_end();
+		if line.is_empty() {
+			continue;
+		}
+		let row = i + 2;
+		let fields = line.split(',').collect::<Vec<_>>();
+		if fields.len() != KEYLINES_CSV_HEADER.len() {
+			return Err(invalid_data(format!("Row: {} has {} fields, expected: {}", row, fields.len(), KEYLINES_CSV_HEADER.len())));
+		}
+		let field = |n: usize| parse_field::<f32>(fields[n], row, n);
+		out.push(KeyLine {
+			angle: field(0)?,
+			class_id: parse_field(fields[1], row, 1)?,
+			octave: parse_field(fields[2], row, 2)?,
+			pt: Point2f::new(field(3)?, field(4)?),
+			response: field(5)?,
+			size: field(6)?,
+			start_point_x: field(7)?,
+			start_point_y: field(8)?,
+			end_point_x: field(9)?,
+			end_point_y: field(10)?,
+			s_point_in_octave_x: field(11)?,
+			s_point_in_octave_y: field(12)?,
+			e_point_in_octave_x: field(13)?,
+			e_point_in_octave_y: field(14)?,
+			line_length: field(15)?,
+			num_of_pixels: parse_field(fields[16], row, 16)?,
+		});
+	}
+	Ok(out)
+}
+
+fn parse_field<T: FromStr>(s: &str, row: usize, col: usize) -> io::Result<T> {
+	s.trim().parse()
+		.map_err(|_| invalid_data(format!("Invalid value: {} for column: {} in row: {}", s, KEYLINES_CSV_HEADER[col], row)))
+}
+
+#[inline]
+fn invalid_data(msg: String) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, msg)
+}
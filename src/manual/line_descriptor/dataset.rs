@@ -0,0 +1,90 @@
+use std::{
+	convert::TryInto,
+	fs,
+};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	line_descriptor::BinaryDescriptorMatcherTrait,
+	prelude::*,
+	Result,
+	types::VectorOfMat,
+};
+
+const MAGIC: &[u8; 8] = b"OCVRSLD1";
+
+/// Save binary line descriptor matrices (as passed to `BinaryDescriptorMatcher::add()`) to the file at `path`
+///
+/// Each matrix must be `CV_8UC1`. The format is a simple length-prefixed little-endian binary, use
+/// [load_descriptors] or `BinaryDescriptorMatcherTraitManual::load_dataset()` to read it back.
+pub fn save_descriptors(path: &str, descriptors: &VectorOfMat) -> Result<()> {
+	let mut out = MAGIC.to_vec();
+	out.extend_from_slice(&(descriptors.len() as u32).to_le_bytes());
+	for (i, desc) in descriptors.iter().enumerate() {
+		if desc.typ()? != core::CV_8UC1 {
+			return Err(Error::new(core::StsUnsupportedFormat, format!("Descriptors at index: {} must be CV_8UC1", i)));
+		}
+		let desc = if desc.is_continuous()? { desc } else { desc.try_clone()? };
+		out.extend_from_slice(&desc.rows().to_le_bytes());
+		out.extend_from_slice(&desc.cols().to_le_bytes());
+		out.extend_from_slice(desc.data_typed::<u8>()?);
+	}
+	fs::write(path, out)
+		.map_err(|e| Error::new(core::StsError, format!("Can't write descriptors to: {}, error: {}", path, e)))
+}
+
+/// Load binary line descriptor matrices saved by [save_descriptors]
+pub fn load_descriptors(path: &str) -> Result<VectorOfMat> {
+	let data = fs::read(path)
+		.map_err(|e| Error::new(core::StsError, format!("Can't read descriptors from: {}, error: {}", path, e)))?;
+	let mut reader = Reader { data: &data, path };
+	if reader.take(MAGIC.len())? != MAGIC {
+		return Err(Error::new(core::StsParseError, format!("File: {} doesn't contain line descriptors", path)));
+	}
+	let count = u32::from_le_bytes(reader.take(4)?.try_into().expect("Slice of size 4"));
+	// every entry has at least the 8 bytes of its size
+	if (count as usize).checked_mul(8).map_or(true, |min_len| min_len > reader.data.len()) {
+		return Err(Error::new(core::StsParseError, format!("Invalid descriptors count: {} in: {}", count, path)));
+	}
+	let mut out = VectorOfMat::with_capacity(count as usize);
+	for _ in 0..count {
+		let rows = reader.take_i32()?;
+		let cols = reader.take_i32()?;
+		let len = if rows >= 0 && cols >= 0 {
+			(rows as usize).checked_mul(cols as usize).filter(|&len| len <= reader.data.len())
+		} else {
+			None
+		};
+		let len = len.ok_or_else(|| Error::new(core::StsParseError, format!("Invalid descriptors size: {}x{} in: {}", rows, cols, path)))?;
+		let mut desc = Mat::new_rows_cols_with_default(rows, cols, core::CV_8UC1, Default::default())?;
+		desc.data_typed_mut::<u8>()?.copy_from_slice(reader.take(len)?);
+		out.push(desc);
+	}
+	Ok(out)
+}
+
+struct Reader<'d> {
+	data: &'d [u8],
+	path: &'d str,
+}
+
+impl<'d> Reader<'d> {
+	fn take(&mut self, len: usize) -> Result<&'d [u8]> {
+		if self.data.len() < len {
+			return Err(Error::new(core::StsParseError, format!("Unexpected end of file: {}", self.path)));
+		}
+		let (out, rest) = self.data.split_at(len);
+		self.data = rest;
+		Ok(out)
+	}
+
+	fn take_i32(&mut self) -> Result<i32> {
+		self.take(4).map(|b| i32::from_le_bytes(b.try_into().expect("Slice of size 4")))
+	}
+}
+
+pub(super) fn load_dataset(matcher: &mut (impl BinaryDescriptorMatcherTrait + ?Sized), path: &str) -> Result<()> {
+	matcher.add(&load_descriptors(path)?)?;
+	matcher.train()
+}
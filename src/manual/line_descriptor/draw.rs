@@ -0,0 +1,130 @@
+use crate::{
+	core::{Mat, Point, Point2f, Scalar},
+	imgproc,
+	line_descriptor::{
+		self,
+		DrawLinesMatchesFlags,
+		DrawLinesMatchesFlags_DEFAULT,
+		DrawLinesMatchesFlags_DRAW_OVER_OUTIMG,
+		DrawLinesMatchesFlags_NOT_DRAW_SINGLE_LINES,
+	},
+	prelude::*,
+	Result,
+	types::VectorOfKeyLine,
+};
+
+use super::clip_segment;
+
+impl DrawLinesMatchesFlags {
+	/// Output image matrix will be created (Mat::create), two source images, matches, and single keylines will be drawn
+	pub const DEFAULT: i32 = DrawLinesMatchesFlags_DEFAULT;
+	/// Output image matrix will not be created, matches will be drawn on existing content of output image
+	pub const DRAW_OVER_OUTIMG: i32 = DrawLinesMatchesFlags_DRAW_OVER_OUTIMG;
+	/// Single keylines will not be drawn
+	pub const NOT_DRAW_SINGLE_LINES: i32 = DrawLinesMatchesFlags_NOT_DRAW_SINGLE_LINES;
+}
+
+/// Color of the keylines drawn by `draw_keylines_colored()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineColor {
+	/// Separate random color for each keyline
+	Random,
+	/// Same color for all keylines, channels are in RGB order
+	Rgb(u8, u8, u8),
+}
+
+impl From<LineColor> for Scalar {
+	/// Converts to the BGR `Scalar` used by the drawing functions, `Random` becomes the `Scalar::all(-1.)` sentinel
+	#[inline]
+	fn from(s: LineColor) -> Self {
+		match s {
+			LineColor::Random => Scalar::all(-1.),
+			LineColor::Rgb(r, g, b) => Scalar::new(f64::from(b), f64::from(g), f64::from(r), 0.),
+		}
+	}
+}
+
+/// Like `draw_keylines()`, but with an explicit [LineColor] instead of the `Scalar` with a special `-1` value
+#[inline]
+pub fn draw_keylines_colored(image: &Mat, keylines: &VectorOfKeyLine, out_image: &mut Mat, color: LineColor, flags: i32) -> Result<()> {
+	line_descriptor::draw_keylines(image, keylines, out_image, color.into(), flags)
+}
+
+/// Like `draw_keylines()`, but allocates and returns the output image
+///
+/// With `DrawLinesMatchesFlags::DRAW_OVER_OUTIMG` the keylines are drawn over a copy of `image`.
+pub fn draw_keylines_to_new(image: &Mat, keylines: &VectorOfKeyLine, color: Scalar, flags: i32) -> Result<Mat> {
+	let mut out = if flags & DrawLinesMatchesFlags::DRAW_OVER_OUTIMG != 0 {
+		image.try_clone()?
+	} else {
+		Mat::default()
+	};
+	line_descriptor::draw_keylines(image, keylines, &mut out, color, flags)?;
+	Ok(out)
+}
+
+/// Length of the arrows drawn by `draw_keylines_checked()` to indicate off-screen lines
+const OFFSCREEN_ARROW_LENGTH: f32 = 8.;
+
+/// Like `draw_keylines()`, but clips the keylines to the `image` bounds beforehand
+///
+/// Keylines that are fully outside of the image are skipped. When `indicate_offscreen` is `true` a small arrow
+/// is drawn at the image border in the direction of each off-screen line part.
+pub fn draw_keylines_checked(
+	image: &Mat,
+	keylines: &VectorOfKeyLine,
+	out_image: &mut Mat,
+	color: Scalar,
+	flags: i32,
+	indicate_offscreen: bool,
+) -> Result<()> {
+	let size = image.size()?;
+	let mut clipped = VectorOfKeyLine::with_capacity(keylines.len());
+	let mut arrows = vec![];
+	for mut keyline in keylines {
+		let start = Point2f::new(keyline.start_point_x, keyline.start_point_y);
+		let end = Point2f::new(keyline.end_point_x, keyline.end_point_y);
+		if let Some((clipped_start, clipped_end)) = clip_segment(start, end, Point2f::new((size.width - 1) as f32, (size.height - 1) as f32)) {
+			if indicate_offscreen {
+				if clipped_start != start {
+					arrows.push((clipped_start, start));
+				}
+				if clipped_end != end {
+					arrows.push((clipped_end, end));
+				}
+			}
+			keyline.start_point_x = clipped_start.x;
+			keyline.start_point_y = clipped_start.y;
+			keyline.end_point_x = clipped_end.x;
+			keyline.end_point_y = clipped_end.y;
+			clipped.push(keyline);
+		} else if indicate_offscreen {
+			let mid = Point2f::new((start.x + end.x) / 2., (start.y + end.y) / 2.);
+			let border = Point2f::new(
+				mid.x.max(0.).min((size.width - 1) as f32),
+				mid.y.max(0.).min((size.height - 1) as f32),
+			);
+			arrows.push((border, mid));
+		}
+	}
+	line_descriptor::draw_keylines(image, &clipped, out_image, color, flags)?;
+	let arrow_color = if color == Scalar::all(-1.) {
+		Scalar::new(0., 0., 255., 0.)
+	} else {
+		color
+	};
+	for (tip, outside) in arrows {
+		let (dx, dy) = (outside.x - tip.x, outside.y - tip.y);
+		let len = dx.hypot(dy);
+		if len > 0. {
+			let tail = Point2f::new(tip.x - dx / len * OFFSCREEN_ARROW_LENGTH, tip.y - dy / len * OFFSCREEN_ARROW_LENGTH);
+			imgproc::arrowed_line(out_image, to_point(tail), to_point(tip), arrow_color, 1, imgproc::LINE_8, 0, 0.5)?;
+		}
+	}
+	Ok(())
+}
+
+#[inline]
+fn to_point(p: Point2f) -> Point {
+	Point::new(p.x.round() as i32, p.y.round() as i32)
+}
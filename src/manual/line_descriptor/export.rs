@@ -0,0 +1,182 @@
+//! Export of detected lines to COCO-style JSON for external evaluation tools
+//!
+//! Each line is an annotation of the single "line" category with a 2-point polyline `segmentation`, its `bbox`
+//! and an optional `score`. The document types also implement `Serialize` and `Deserialize` for use with other
+//! serde formats.
+
+use std::{collections::HashMap, fs};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	core::{self, Point2f, Size},
+	Error,
+	line_descriptor::KeyLine,
+	Result,
+	types::VectorOfKeyLine,
+};
+
+/// Category id used for all line annotations
+pub const LINE_CATEGORY_ID: u64 = 1;
+
+/// Single line annotation, `segmentation` is a list of `[x0, y0, x1, y1]` polylines
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CocoLineAnnotation {
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub id: Option<u64>,
+	pub image_id: u64,
+	#[serde(default = "line_category_id")]
+	pub category_id: u64,
+	pub segmentation: Vec<[f32; 4]>,
+	pub bbox: [f32; 4],
+	#[serde(default)]
+	pub area: f32,
+	#[serde(default)]
+	pub iscrowd: u8,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub score: Option<f32>,
+}
+
+/// Entry of the `images` section
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CocoImage {
+	pub id: u64,
+	#[serde(default)]
+	pub file_name: String,
+	#[serde(default)]
+	pub width: i32,
+	#[serde(default)]
+	pub height: i32,
+}
+
+/// Entry of the `categories` section
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CocoCategory {
+	pub id: u64,
+	pub name: String,
+	pub supercategory: String,
+}
+
+/// Complete COCO document with `images`, `annotations` and `categories` sections
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CocoDocument {
+	pub images: Vec<CocoImage>,
+	pub annotations: Vec<CocoLineAnnotation>,
+	#[serde(default)]
+	pub categories: Vec<CocoCategory>,
+}
+
+#[inline]
+fn line_category_id() -> u64 {
+	LINE_CATEGORY_ID
+}
+
+/// Convert `keylines` of the image `image_id` into a JSON array of COCO annotations without `id`s
+///
+/// `scores` must have the same length as `keylines` if specified, otherwise `response` of each line is used.
+pub fn to_coco_lines(image_id: u64, keylines: &VectorOfKeyLine, scores: Option<&[f32]>) -> Result<serde_json::Value> {
+	serde_json::to_value(coco_annotations(image_id, keylines, scores)?)
+		.map_err(|e| Error::new(core::StsError, format!("Can't serialize COCO annotations: {}", e)))
+}
+
+fn coco_annotations(image_id: u64, keylines: &VectorOfKeyLine, scores: Option<&[f32]>) -> Result<Vec<CocoLineAnnotation>> {
+	if let Some(scores) = scores {
+		if scores.len() != keylines.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Number of scores: {} doesn't match the number of keylines: {}", scores.len(), keylines.len())));
+		}
+	}
+	let out = keylines.iter()
+		.enumerate()
+		.map(|(i, keyline)| {
+			let (x0, y0, x1, y1) = (keyline.start_point_x, keyline.start_point_y, keyline.end_point_x, keyline.end_point_y);
+			CocoLineAnnotation {
+				id: None,
+				image_id,
+				category_id: LINE_CATEGORY_ID,
+				segmentation: vec![[x0, y0, x1, y1]],
+				bbox: [x0.min(x1), y0.min(y1), (x1 - x0).abs(), (y1 - y0).abs()],
+				area: 0.,
+				iscrowd: 0,
+				score: Some(scores.map_or(keyline.response, |s| s[i])),
+			}
+		})
+		.collect();
+	Ok(out)
+}
+
+/// Accumulates the lines of many images into a single COCO document
+#[derive(Clone, Debug, Default)]
+pub struct CocoWriter {
+	images: Vec<CocoImage>,
+	annotations: Vec<CocoLineAnnotation>,
+}
+
+impl CocoWriter {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add the image and its lines, see [to_coco_lines] for the `scores` requirements
+	pub fn add_image(&mut self, image_id: u64, file_name: &str, size: Size, keylines: &VectorOfKeyLine, scores: Option<&[f32]>) -> Result<()> {
+		for mut annotation in coco_annotations(image_id, keylines, scores)? {
+			annotation.id = Some(self.annotations.len() as u64 + 1);
+			self.annotations.push(annotation);
+		}
+		self.images.push(CocoImage {
+			id: image_id,
+			file_name: file_name.to_string(),
+			width: size.width,
+			height: size.height,
+		});
+		Ok(())
+	}
+
+	/// Build the complete document with the single "line" category
+	pub fn document(&self) -> CocoDocument {
+		CocoDocument {
+			images: self.images.clone(),
+			annotations: self.annotations.clone(),
+			categories: vec![CocoCategory { id: LINE_CATEGORY_ID, name: "line".to_string(), supercategory: "line".to_string() }],
+		}
+	}
+
+	/// Write the JSON document to the file at `path`
+	pub fn write(&self, path: &str) -> Result<()> {
+		let data = serde_json::to_vec_pretty(&self.document())
+			.map_err(|e| Error::new(core::StsError, format!("Can't serialize COCO document: {}", e)))?;
+		fs::write(path, data)
+			.map_err(|e| Error::new(core::StsError, format!("Can't write COCO document to: {}, error: {}", path, e)))
+	}
+}
+
+/// Read the lines from the COCO JSON file at `path` (e.g. written by [CocoWriter]) grouped by image id
+///
+/// See [from_coco_document] for the details.
+pub fn from_coco_lines(path: &str) -> Result<HashMap<u64, VectorOfKeyLine>> {
+	let data = fs::read(path)
+		.map_err(|e| Error::new(core::StsError, format!("Can't read COCO document from: {}, error: {}", path, e)))?;
+	let doc = serde_json::from_slice(&data)
+		.map_err(|e| Error::new(core::StsParseError, format!("Can't parse COCO document: {}, error: {}", path, e)))?;
+	from_coco_document(doc)
+}
+
+/// Extract the lines from the COCO document `doc` grouped by image id
+///
+/// Every image from the `images` section is present in the output even if it has no lines. `score` of each
+/// annotation is stored in the `response` field.
+pub fn from_coco_document(doc: CocoDocument) -> Result<HashMap<u64, VectorOfKeyLine>> {
+	let mut out = doc.images.iter()
+		.map(|image| (image.id, VectorOfKeyLine::new()))
+		.collect::<HashMap<_, _>>();
+	for annotation in doc.annotations {
+		let [x0, y0, x1, y1] = *annotation.segmentation.first()
+			.ok_or_else(|| Error::new(core::StsParseError, format!("Invalid COCO annotation for image: {}, segmentation must be a 2-point polyline", annotation.image_id)))?;
+		let mut keyline = KeyLine::from_points(Point2f::new(x0, y0), Point2f::new(x1, y1));
+		if let Some(score) = annotation.score {
+			keyline.response = score;
+		}
+		out.entry(annotation.image_id).or_insert_with(VectorOfKeyLine::new).push(keyline);
+	}
+	Ok(out)
+}
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use crate::{
+	line_descriptor::KeyLine,
+	types::VectorOfKeyLine,
+};
+
+/// Return a new vector with only the keylines that have `line_length` of at least `min_length`
+pub fn filter_keylines_by_length(keylines: &VectorOfKeyLine, min_length: f32) -> VectorOfKeyLine {
+	keylines.iter()
+		.filter(|keyline| keyline.line_length >= min_length)
+		.collect()
+}
+
+/// Return a new vector with only the keylines that were detected in the specified `octave`
+pub fn filter_keylines_by_octave(keylines: &VectorOfKeyLine, octave: i32) -> VectorOfKeyLine {
+	keylines.iter()
+		.filter(|keyline| keyline.octave == octave)
+		.collect()
+}
+
+/// Bucket keylines by their `class_id`, each bucket holds the same line detected in different octaves
+///
+/// The keylines within each bucket keep their original relative order.
+pub fn group_by_class_id(keylines: &VectorOfKeyLine) -> HashMap<i32, Vec<KeyLine>> {
+	let mut out = HashMap::<_, Vec<_>>::new();
+	for keyline in keylines {
+		out.entry(keyline.class_id).or_default().push(keyline);
+	}
+	out
+}
@@ -0,0 +1,185 @@
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	core::{self, Mat, Point2f, Size},
+	Error,
+	Result,
+	types::{VectorOfKeyLine, VectorOfPoint2f},
+};
+
+use super::clip_segment;
+
+/// Result of [KeyframeSelector::consider] together with the fraction of newly covered grid cells
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KeyframeDecision {
+	Accepted(f32),
+	Rejected(f32),
+}
+
+impl KeyframeDecision {
+	#[inline]
+	pub fn is_accepted(&self) -> bool {
+		matches!(self, KeyframeDecision::Accepted(_))
+	}
+
+	/// Fraction of the grid cells covered by the frame lines that weren't covered by the previous keyframes
+	#[inline]
+	pub fn new_coverage(&self) -> f32 {
+		match *self {
+			KeyframeDecision::Accepted(c) | KeyframeDecision::Rejected(c) => c,
+		}
+	}
+}
+
+/// Selects keyframes whose lines add enough new coverage of the reference frame
+///
+/// Coverage is tracked in an occupancy grid of `grid` cells, each cell is `cell_size` x `cell_size` pixels of the
+/// reference frame (1 by default).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(try_from = "KeyframeSelectorState", into = "KeyframeSelectorState"))]
+pub struct KeyframeSelector {
+	grid: Size,
+	cell_size: f32,
+	min_new_coverage: f32,
+	occupied: Vec<bool>,
+}
+
+impl KeyframeSelector {
+	/// Create a selector accepting frames with at least `min_new_coverage` fraction (0..=1) of newly covered cells
+	///
+	/// The grid can have at most `i32::MAX` cells.
+	pub fn new(grid: Size, min_new_coverage: f32) -> Result<Self> {
+		let area = if grid.width > 0 && grid.height > 0 {
+			grid.width.checked_mul(grid.height)
+		} else {
+			None
+		};
+		let area = area.ok_or_else(|| Error::new(core::StsBadArg, format!("Invalid grid size: {:?}", grid)))?;
+		if !(0. ..=1.).contains(&min_new_coverage) {
+			return Err(Error::new(core::StsOutOfRange, format!("Minimum new coverage must be within 0..=1, but it's: {}", min_new_coverage)));
+		}
+		Ok(Self {
+			grid,
+			cell_size: 1.,
+			min_new_coverage,
+			occupied: vec![false; area as usize],
+		})
+	}
+
+	/// Set the size of a grid cell in pixels of the reference frame
+	pub fn with_cell_size(mut self, cell_size: f32) -> Result<Self> {
+		if !(cell_size.is_finite() && cell_size > 0.) {
+			return Err(Error::new(core::StsBadArg, format!("Cell size must be positive, but it's: {}", cell_size)));
+		}
+		self.cell_size = cell_size;
+		Ok(self)
+	}
+
+	/// Evaluate the frame with the specified `keylines`, `homography` (3x3) maps them to the reference frame
+	///
+	/// When the frame is accepted the grid is updated with its coverage.
+	pub fn consider(&mut self, keylines: &VectorOfKeyLine, homography: Option<&Mat>) -> Result<KeyframeDecision> {
+		let mut points = VectorOfPoint2f::with_capacity(keylines.len() * 2);
+		for keyline in keylines {
+			points.push(Point2f::new(keyline.start_point_x, keyline.start_point_y));
+			points.push(Point2f::new(keyline.end_point_x, keyline.end_point_y));
+		}
+		if let (Some(homography), false) = (homography, points.is_empty()) {
+			let mut warped = VectorOfPoint2f::with_capacity(points.len());
+			core::perspective_transform(&points, &mut warped, homography)?;
+			points = warped;
+		}
+		let points = points.to_vec();
+		let mut cells = vec![];
+		for segment in points.chunks_exact(2) {
+			self.rasterize(segment[0], segment[1], &mut cells);
+		}
+		cells.sort_unstable();
+		cells.dedup();
+		let new_cells = cells.iter().filter(|&&i| !self.occupied[i]).count();
+		let new_coverage = if cells.is_empty() {
+			0.
+		} else {
+			new_cells as f32 / cells.len() as f32
+		};
+		Ok(if new_cells > 0 && new_coverage >= self.min_new_coverage {
+			cells.into_iter().for_each(|i| self.occupied[i] = true);
+			KeyframeDecision::Accepted(new_coverage)
+		} else {
+			KeyframeDecision::Rejected(new_coverage)
+		})
+	}
+
+	/// Fraction of the whole grid covered by the accepted keyframes
+	pub fn coverage(&self) -> f32 {
+		self.occupied.iter().filter(|&&x| x).count() as f32 / self.occupied.len() as f32
+	}
+
+	/// Clear the grid as if no keyframes were accepted
+	pub fn reset(&mut self) {
+		self.occupied.iter_mut().for_each(|x| *x = false);
+	}
+
+	/// Push indices of the grid cells crossed by the segment, sampled at half-cell steps
+	fn rasterize(&self, start: Point2f, end: Point2f, out: &mut Vec<usize>) {
+		let start = Point2f::new(start.x / self.cell_size, start.y / self.cell_size);
+		let end = Point2f::new(end.x / self.cell_size, end.y / self.cell_size);
+		let (start, end) = match clip_segment(start, end, Point2f::new(self.grid.width as f32, self.grid.height as f32)) {
+			Some(clipped) => clipped,
+			None => return,
+		};
+		let (sx, sy, ex, ey) = (start.x, start.y, end.x, end.y);
+		let steps = ((ex - sx).hypot(ey - sy) * 2.).ceil().max(1.) as usize;
+		for step in 0..=steps {
+			let t = step as f32 / steps as f32;
+			let (x, y) = ((sx + t * (ex - sx)).floor(), (sy + t * (ey - sy)).floor());
+			if x >= 0. && y >= 0. && x < self.grid.width as f32 && y < self.grid.height as f32 {
+				out.push(y as usize * self.grid.width as usize + x as usize);
+			}
+		}
+	}
+}
+
+/// Serialized form of [KeyframeSelector], validated on deserialization
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct KeyframeSelectorState {
+	grid_width: i32,
+	grid_height: i32,
+	cell_size: f32,
+	min_new_coverage: f32,
+	occupied: Vec<bool>,
+}
+
+#[cfg(feature = "serde")]
+impl From<KeyframeSelector> for KeyframeSelectorState {
+	fn from(s: KeyframeSelector) -> Self {
+		Self {
+			grid_width: s.grid.width,
+			grid_height: s.grid.height,
+			cell_size: s.cell_size,
+			min_new_coverage: s.min_new_coverage,
+			occupied: s.occupied,
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<KeyframeSelectorState> for KeyframeSelector {
+	type Error = Error;
+
+	fn try_from(s: KeyframeSelectorState) -> Result<Self> {
+		let mut out = Self::new(Size::new(s.grid_width, s.grid_height), s.min_new_coverage)?
+			.with_cell_size(s.cell_size)?;
+		if s.occupied.len() != out.occupied.len() {
+			return Err(Error::new(core::StsUnmatchedSizes, format!("Occupancy grid must have: {} cells, but it has: {}", out.occupied.len(), s.occupied.len())));
+		}
+		out.occupied = s.occupied;
+		Ok(out)
+	}
+}
@@ -0,0 +1,219 @@
+use std::{f32::consts::PI, fmt};
+
+use crate::{
+	core::{self, Point2f, Rect, Size, Vec4f, Vec4i},
+	Error,
+	line_descriptor::KeyLine,
+	Result,
+	types::VectorOfKeyLine,
+};
+
+/// Maximum difference in radians between the undirected angles of the lines for [KeyLine::segment_overlap] to
+/// consider them parallel
+pub const SEGMENT_OVERLAP_ANGLE_TOL: f32 = PI / 180.;
+
+impl KeyLine {
+	/// Create a `KeyLine` in octave 0 from its endpoints in the original image
+	///
+	/// Fields are populated the same way as `LSDDetector` does it, `class_id` is set to -1 and `response` and
+	/// `num_of_pixels` are left at 0.
+	pub fn from_points(start: Point2f, end: Point2f) -> KeyLine {
+		let (dx, dy) = (end.x - start.x, end.y - start.y);
+		KeyLine {
+			angle: dy.atan2(dx),
+			class_id: -1,
+			octave: 0,
+			pt: Point2f::new((start.x + end.x) / 2., (start.y + end.y) / 2.),
+			response: 0.,
+			size: (dx * dy).abs(),
+			start_point_x: start.x,
+			start_point_y: start.y,
+			end_point_x: end.x,
+			end_point_y: end.y,
+			s_point_in_octave_x: start.x,
+			s_point_in_octave_y: start.y,
+			e_point_in_octave_x: end.x,
+			e_point_in_octave_y: end.y,
+			line_length: dx.hypot(dy),
+			num_of_pixels: 0,
+		}
+	}
+
+	/// Create a `KeyLine` from its endpoints in the original image with all the derived fields filled in
+	///
+	/// This is the preferred way of constructing `KeyLine`s by hand because it keeps the fields consistent with
+	/// each other: `pt` is the midpoint, `angle` is in radians, `response` is the length relative to the bigger
+	/// image dimension and `num_of_pixels` is the number of pixels of the rasterized line. Octave coordinates are
+	/// equal to the original ones, use [KeyLine::with_octave_scaled] to derive them for `octave > 0`.
+	pub fn try_new(start: Point2f, end: Point2f, image_size: Size, class_id: i32, octave: i32) -> Result<KeyLine> {
+		if !(start.x.is_finite() && start.y.is_finite() && end.x.is_finite() && end.y.is_finite()) {
+			return Err(Error::new(core::StsBadArg, format!("KeyLine endpoints must be finite, got: {:?} and {:?}", start, end)));
+		}
+		if start == end {
+			return Err(Error::new(core::StsBadArg, format!("KeyLine must have non-zero length, both endpoints are: {:?}", start)));
+		}
+		if image_size.width <= 0 || image_size.height <= 0 {
+			return Err(Error::new(core::StsBadArg, format!("Invalid image size: {:?}", image_size)));
+		}
+		if octave < 0 {
+			return Err(Error::new(core::StsBadArg, format!("KeyLine octave must be non-negative, got: {}", octave)));
+		}
+		let mut out = Self::from_points(start, end);
+		out.class_id = class_id;
+		out.octave = octave;
+		out.response = out.line_length / image_size.width.max(image_size.height) as f32;
+		let (dx, dy) = ((end.x.round() - start.x.round()).abs(), (end.y.round() - start.y.round()).abs());
+		out.num_of_pixels = dx.max(dy) as i32 + 1;
+		Ok(out)
+	}
+
+	/// Euclidean distance between the start and end points in the original image
+	///
+	/// Unlike `line_length` it's always computed from the coordinates, so it's valid for manually built
+	/// `KeyLine`s too. Note that `line_length` of detected lines is measured in the octave image.
+	#[inline]
+	pub fn length(&self) -> f32 {
+		(self.end_point_x - self.start_point_x).hypot(self.end_point_y - self.start_point_y)
+	}
+
+	/// Line `angle` converted to degrees
+	#[inline]
+	pub fn angle_degrees(&self) -> f32 {
+		self.angle.to_degrees()
+	}
+
+	/// Undirected line `angle` in radians in range `[0, PI)`
+	///
+	/// Lines differing only in the order of the endpoints get the same value. Angles that end up exactly at or
+	/// rounded up to `PI` are mapped to 0.
+	pub fn normalized_angle(&self) -> f32 {
+		let out = self.angle.rem_euclid(PI);
+		if out >= PI || out == 0. {
+			0.
+		} else {
+			out
+		}
+	}
+
+	/// Axis-aligned integer rectangle containing both endpoints in the original image
+	///
+	/// Coordinates are rounded outwards, horizontal and vertical lines produce a rectangle of height or width 1.
+	pub fn bounding_rect(&self) -> Rect {
+		let (min_x, max_x) = (self.start_point_x.min(self.end_point_x), self.start_point_x.max(self.end_point_x));
+		let (min_y, max_y) = (self.start_point_y.min(self.end_point_y), self.start_point_y.max(self.end_point_y));
+		let (x, y) = (min_x.floor() as i32, min_y.floor() as i32);
+		Rect::new(x, y, (max_x.ceil() as i32 - x).max(1), (max_y.ceil() as i32 - y).max(1))
+	}
+
+	/// Endpoints in the original image as `[start_x, start_y, end_x, end_y]` rounded to the nearest integer
+	#[inline]
+	pub fn to_vec4i(&self) -> Vec4i {
+		Vec4i::from([
+			self.start_point_x.round() as i32,
+			self.start_point_y.round() as i32,
+			self.end_point_x.round() as i32,
+			self.end_point_y.round() as i32,
+		])
+	}
+
+	/// Compare with `other` allowing the absolute difference of up to `eps` in each of the float fields
+	///
+	/// Integer fields (`class_id`, `octave` and `num_of_pixels`) must match exactly.
+	pub fn approx_eq(&self, other: &KeyLine, eps: f32) -> bool {
+		let close = |a: f32, b: f32| (a - b).abs() <= eps;
+		self.class_id == other.class_id
+			&& self.octave == other.octave
+			&& self.num_of_pixels == other.num_of_pixels
+			&& close(self.angle, other.angle)
+			&& close(self.pt.x, other.pt.x)
+			&& close(self.pt.y, other.pt.y)
+			&& close(self.response, other.response)
+			&& close(self.size, other.size)
+			&& close(self.start_point_x, other.start_point_x)
+			&& close(self.start_point_y, other.start_point_y)
+			&& close(self.end_point_x, other.end_point_x)
+			&& close(self.end_point_y, other.end_point_y)
+			&& close(self.s_point_in_octave_x, other.s_point_in_octave_x)
+			&& close(self.s_point_in_octave_y, other.s_point_in_octave_y)
+			&& close(self.e_point_in_octave_x, other.e_point_in_octave_x)
+			&& close(self.e_point_in_octave_y, other.e_point_in_octave_y)
+			&& close(self.line_length, other.line_length)
+	}
+
+	/// Overlap of the segments along the direction of `self` as the intersection over union of their extents,
+	/// in range `[0, 1]`
+	///
+	/// Both segments are projected onto the direction of `self` using the endpoints in the original image. The
+	/// result is 0 when the undirected angles of the lines differ by more than [SEGMENT_OVERLAP_ANGLE_TOL] radians
+	/// or when `self` has zero length. Perpendicular offset between the lines is not taken into account.
+	pub fn segment_overlap(&self, other: &KeyLine) -> f32 {
+		let (dx, dy) = (self.end_point_x - self.start_point_x, self.end_point_y - self.start_point_y);
+		let len = dx.hypot(dy);
+		if len <= 0. {
+			return 0.;
+		}
+		let other_angle = (other.end_point_y - other.start_point_y).atan2(other.end_point_x - other.start_point_x);
+		let diff = (dy.atan2(dx) - other_angle).abs() % PI;
+		if diff.min(PI - diff) > SEGMENT_OVERLAP_ANGLE_TOL {
+			return 0.;
+		}
+		let (dir_x, dir_y) = (dx / len, dy / len);
+		let project = |x: f32, y: f32| (x - self.start_point_x) * dir_x + (y - self.start_point_y) * dir_y;
+		let (a_min, a_max) = (0f32, len);
+		let (b_start, b_end) = (project(other.start_point_x, other.start_point_y), project(other.end_point_x, other.end_point_y));
+		let (b_min, b_max) = (b_start.min(b_end), b_start.max(b_end));
+		let intersection = (a_max.min(b_max) - a_min.max(b_min)).max(0.);
+		let union = a_max.max(b_max) - a_min.min(b_min);
+		(intersection / union).min(1.)
+	}
+
+	/// Return a copy with the octave coordinates derived from the original ones for the pyramid with the specified
+	/// `reduction_ratio` between octaves
+	pub fn with_octave_scaled(&self, reduction_ratio: f32) -> KeyLine {
+		let scale = reduction_ratio.powi(self.octave);
+		KeyLine {
+			s_point_in_octave_x: self.start_point_x / scale,
+			s_point_in_octave_y: self.start_point_y / scale,
+			e_point_in_octave_x: self.end_point_x / scale,
+			e_point_in_octave_y: self.end_point_y / scale,
+			..*self
+		}
+	}
+}
+
+/// Copy the `keylines` into a new `VectorOfKeyLine` in a single bulk copy
+///
+/// Use `Vec::from(&vector)` or `vector.to_vec()` for the opposite direction.
+pub fn to_vector_of_keyline(keylines: &[KeyLine]) -> VectorOfKeyLine {
+	VectorOfKeyLine::from(keylines)
+}
+
+/// Replace `angle` of each of the `keylines` with [KeyLine::normalized_angle], endpoints are left untouched
+pub fn normalize_angles(keylines: &mut VectorOfKeyLine) {
+	keylines.as_mut_slice().iter_mut().for_each(|keyline| keyline.angle = keyline.normalized_angle());
+}
+
+/// Endpoints in the original image as `[start_x, start_y, end_x, end_y]`, the layout used by e.g. `HoughLinesP()`
+impl From<KeyLine> for Vec4f {
+	#[inline]
+	fn from(s: KeyLine) -> Self {
+		Vec4f::from([s.start_point_x, s.start_point_y, s.end_point_x, s.end_point_y])
+	}
+}
+
+/// Compact single-line representation with the angle in degrees
+impl fmt::Display for KeyLine {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"KeyLine[({:.1},{:.1})->({:.1},{:.1}), len={:.1}, angle={:.1}deg, oct={}]",
+			self.start_point_x,
+			self.start_point_y,
+			self.end_point_x,
+			self.end_point_y,
+			self.line_length,
+			self.angle_degrees(),
+			self.octave,
+		)
+	}
+}
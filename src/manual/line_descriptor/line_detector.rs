@@ -0,0 +1,33 @@
+use crate::{
+	core::Mat,
+	line_descriptor::{BinaryDescriptor, BinaryDescriptorTraitManual},
+	Result,
+	types::{PtrOfBinaryDescriptor, VectorOfKeyLine},
+};
+
+/// Line detector that keeps its whole configuration (including pyramid parameters) internally
+///
+/// Unlike the raw `detect()` methods of the OpenCV detectors, implementors of this trait can be called
+/// uniformly from different places in the code and are guaranteed to use the same image pyramid.
+pub trait LineDetector {
+	/// Detect lines in the `image`, optionally limiting the search to the non-zero pixels of the `mask`
+	fn detect_keylines(&mut self, image: &Mat, mask: Option<&Mat>) -> Result<VectorOfKeyLine>;
+}
+
+impl LineDetector for BinaryDescriptor {
+	#[inline]
+	fn detect_keylines(&mut self, image: &Mat, mask: Option<&Mat>) -> Result<VectorOfKeyLine> {
+		let mut out = VectorOfKeyLine::new();
+		self.detect_masked(image, &mut out, mask)?;
+		Ok(out)
+	}
+}
+
+impl LineDetector for PtrOfBinaryDescriptor {
+	#[inline]
+	fn detect_keylines(&mut self, image: &Mat, mask: Option<&Mat>) -> Result<VectorOfKeyLine> {
+		let mut out = VectorOfKeyLine::new();
+		self.detect_masked(image, &mut out, mask)?;
+		Ok(out)
+	}
+}
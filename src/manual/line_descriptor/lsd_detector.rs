@@ -0,0 +1,324 @@
+use crate::{
+	core::{self, Mat, MatView, Rect},
+	Error,
+	line_descriptor::{KeyLine, LineDetector, LSDDetector, LSDDetectorTrait, LSDParam},
+	Result,
+	types::VectorOfKeyLine,
+};
+
+use super::check_positive;
+
+impl LSDParam {
+	/// Preset trading accuracy for speed
+	///
+	/// Values: `scale` 0.5, `sigma_scale` 0.6, `quant` 3.0, `ang_th` 30.0, `log_eps` 0.0, `density_th` 0.8,
+	/// `n_bins` 256.
+	pub fn fast() -> Self {
+		Self {
+			scale: 0.5,
+			sigma_scale: 0.6,
+			quant: 3.,
+			ang_th: 30.,
+			log_eps: 0.,
+			density_th: 0.8,
+			n_bins: 256,
+		}
+	}
+
+	/// Preset for the precise localization of lines
+	///
+	/// Values: `scale` 1.0, `sigma_scale` 0.6, `quant` 1.0, `ang_th` 22.5, `log_eps` 1.0, `density_th` 0.7,
+	/// `n_bins` 2048.
+	pub fn high_precision() -> Self {
+		Self {
+			scale: 1.,
+			sigma_scale: 0.6,
+			quant: 1.,
+			ang_th: 22.5,
+			log_eps: 1.,
+			density_th: 0.7,
+			n_bins: 2048,
+		}
+	}
+
+	/// Check that all of the parameters are within their valid ranges
+	///
+	/// Requirements: `scale` > 0, `sigma_scale` > 0, `quant` >= 0, `ang_th` in (0, 180), `log_eps` is finite,
+	/// `density_th` in [0, 1], `n_bins` > 0. The returned error names the first offending field.
+	///
+	/// The generated constructors `LSDDetector::new()` and `LSDDetector::create_lsd_detector_with_params()` pass the
+	/// params to OpenCV as is, use `LSDDetector::new_checked()`, `LSDDetector::create_lsd_detector_checked()` or
+	/// `ConfiguredLsdDetector::builder()` to have them validated.
+	pub fn validate(&self) -> Result<()> {
+		fn check(name: &str, val: f64, valid: bool, range: &str) -> Result<()> {
+			if valid && val.is_finite() {
+				Ok(())
+			} else {
+				Err(Error::new(core::StsBadArg, format!("LSDParam::{} must be {}, but it's: {}", name, range, val)))
+			}
+		}
+		check("scale", self.scale, self.scale > 0., "> 0")?;
+		check("sigma_scale", self.sigma_scale, self.sigma_scale > 0., "> 0")?;
+		check("quant", self.quant, self.quant >= 0., ">= 0")?;
+		check("ang_th", self.ang_th, self.ang_th > 0. && self.ang_th < 180., "in (0, 180)")?;
+		check("log_eps", self.log_eps, true, "finite")?;
+		check("density_th", self.density_th, (0. ..=1.).contains(&self.density_th), "in [0, 1]")?;
+		check("n_bins", f64::from(self.n_bins), self.n_bins > 0, "> 0")
+	}
+}
+
+impl LSDDetector {
+	/// Like `LSDDetector::new()`, but validates the `params` beforehand, see [LSDParam::validate]
+	pub fn new_checked(params: LSDParam) -> Result<Self> {
+		params.validate()?;
+		Self::new(params)
+	}
+
+	/// Like `LSDDetector::create_lsd_detector_with_params()`, but validates the `params` beforehand, see
+	/// [LSDParam::validate]
+	pub fn create_lsd_detector_checked(params: LSDParam) -> Result<core::Ptr<LSDDetector>> {
+		params.validate()?;
+		Self::create_lsd_detector_with_params(params)
+	}
+}
+
+pub trait LSDDetectorTraitManual: LSDDetectorTrait {
+	/// Like `detect()`, but `None` can be passed instead of an empty `mask`
+	#[inline]
+	fn detect_masked(&mut self, image: &Mat, keylines: &mut VectorOfKeyLine, scale: i32, num_octaves: i32, mask: Option<&Mat>) -> Result<()> {
+		self.detect(image, keylines, scale, num_octaves, mask.unwrap_or(core::empty_mask()))
+	}
+
+	/// Like `detect_masked()` without a mask, but on the borrowed `image` view
+	#[inline]
+	fn detect_view(&mut self, image: &MatView, keylines: &mut VectorOfKeyLine, scale: i32, num_octaves: i32) -> Result<()> {
+		self.detect_masked(image.as_mat(), keylines, scale, num_octaves, None)
+	}
+
+	/// Detect lines only in the `roi` region of `image` without copying the pixel data
+	///
+	/// Coordinates of the resulting `keylines` (including the octave ones) are translated back into the full
+	/// `image` coordinate space.
+	fn detect_roi(&mut self, image: &Mat, roi: Rect, keylines: &mut VectorOfKeyLine, scale: i32, num_octaves: i32) -> Result<()> {
+		let view = MatView::roi(image, roi)?;
+		let mut found = VectorOfKeyLine::new();
+		self.detect_view(&view, &mut found, scale, num_octaves)?;
+		let (dx, dy) = (roi.x as f32, roi.y as f32);
+		*keylines = found.into_iter()
+			.map(|mut keyline| {
+				let octave_scale = (scale as f32).powi(keyline.octave);
+				keyline.start_point_x += dx;
+				keyline.start_point_y += dy;
+				keyline.end_point_x += dx;
+				keyline.end_point_y += dy;
+				keyline.pt.x += dx;
+				keyline.pt.y += dy;
+				keyline.s_point_in_octave_x += dx / octave_scale;
+				keyline.s_point_in_octave_y += dy / octave_scale;
+				keyline.e_point_in_octave_x += dx / octave_scale;
+				keyline.e_point_in_octave_y += dy / octave_scale;
+				keyline
+			})
+			.collect();
+		Ok(())
+	}
+
+	/// Detect lines in the whole `image` returning them as an owned `Vec`
+	fn detect_lines(&mut self, image: &Mat, scale: i32, num_octaves: i32) -> Result<Vec<KeyLine>> {
+		let mut out = VectorOfKeyLine::new();
+		self.detect_masked(image, &mut out, scale, num_octaves, None)?;
+		Ok(out.to_vec())
+	}
+
+	/// Like `detect_lines()`, but returns `None` when no lines were found
+	#[inline]
+	fn detect_nonempty(&mut self, image: &Mat, scale: i32, num_octaves: i32) -> Result<Option<Vec<KeyLine>>> {
+		self.detect_lines(image, scale, num_octaves)
+			.map(|lines| if lines.is_empty() { None } else { Some(lines) })
+	}
+}
+
+impl<T: LSDDetectorTrait> LSDDetectorTraitManual for T {}
+
+/// `LSDDetector` bundled together with the pyramid parameters that are otherwise passed to each `detect()` call
+///
+/// Use [ConfiguredLsdDetector::builder] to create an instance. The raw `LSDDetector` is still available
+/// through [ConfiguredLsdDetector::detector_mut] for the advanced use cases.
+pub struct ConfiguredLsdDetector {
+	detector: LSDDetector,
+	params: LSDParam,
+	octaves: i32,
+	octave_scale: i32,
+}
+
+impl ConfiguredLsdDetector {
+	/// Start building a new detector, by default it uses `LSDParam::default()`, 1 octave and octave scale of 2
+	#[inline]
+	pub fn builder() -> ConfiguredLsdDetectorBuilder {
+		ConfiguredLsdDetectorBuilder::default()
+	}
+
+	/// Detect lines in the `image` using the stored number of octaves and octave scale
+	pub fn detect(&mut self, image: &Mat, mask: Option<&Mat>) -> Result<VectorOfKeyLine> {
+		let mut out = VectorOfKeyLine::new();
+		self.detector.detect(image, &mut out, self.octave_scale, self.octaves, mask.unwrap_or(core::empty_mask()))?;
+		Ok(out)
+	}
+
+	/// LSD parameters that the underlying detector was created with
+	#[inline]
+	pub fn params(&self) -> LSDParam {
+		self.params
+	}
+
+	/// Number of octaves in the image pyramid
+	#[inline]
+	pub fn octaves(&self) -> i32 {
+		self.octaves
+	}
+
+	/// Set the number of octaves in the image pyramid, must be at least 1
+	pub fn set_octaves(&mut self, octaves: i32) -> Result<()> {
+		check_positive("octaves", octaves)?;
+		self.octaves = octaves;
+		Ok(())
+	}
+
+	/// Scale factor between consecutive octaves of the image pyramid
+	#[inline]
+	pub fn octave_scale(&self) -> i32 {
+		self.octave_scale
+	}
+
+	/// Set the scale factor between consecutive octaves of the image pyramid, must be at least 1
+	pub fn set_octave_scale(&mut self, octave_scale: i32) -> Result<()> {
+		check_positive("octave_scale", octave_scale)?;
+		self.octave_scale = octave_scale;
+		Ok(())
+	}
+
+	/// Underlying raw detector
+	#[inline]
+	pub fn detector(&self) -> &LSDDetector {
+		&self.detector
+	}
+
+	/// Underlying raw detector, mutable
+	#[inline]
+	pub fn detector_mut(&mut self) -> &mut LSDDetector {
+		&mut self.detector
+	}
+
+	/// Consume the wrapper returning the underlying raw detector
+	#[inline]
+	pub fn into_detector(self) -> LSDDetector {
+		self.detector
+	}
+}
+
+impl LineDetector for ConfiguredLsdDetector {
+	#[inline]
+	fn detect_keylines(&mut self, image: &Mat, mask: Option<&Mat>) -> Result<VectorOfKeyLine> {
+		self.detect(image, mask)
+	}
+}
+
+/// Builder for [ConfiguredLsdDetector]
+#[derive(Clone, Debug)]
+pub struct ConfiguredLsdDetectorBuilder {
+	params: Option<LSDParam>,
+	octaves: i32,
+	octave_scale: i32,
+}
+
+impl ConfiguredLsdDetectorBuilder {
+	/// LSD parameters, `LSDParam::default()` is used if not set
+	#[inline]
+	pub fn params(mut self, params: LSDParam) -> Self {
+		self.params = Some(params);
+		self
+	}
+
+	/// Number of octaves in the image pyramid
+	#[inline]
+	pub fn octaves(mut self, octaves: i32) -> Self {
+		self.octaves = octaves;
+		self
+	}
+
+	/// Scale factor between consecutive octaves of the image pyramid
+	#[inline]
+	pub fn octave_scale(mut self, octave_scale: i32) -> Self {
+		self.octave_scale = octave_scale;
+		self
+	}
+
+	pub fn build(self) -> Result<ConfiguredLsdDetector> {
+		check_positive("octaves", self.octaves)?;
+		check_positive("octave_scale", self.octave_scale)?;
+		let params = match self.params {
+			Some(params) => params,
+			None => LSDParam::default()?,
+		};
+		Ok(ConfiguredLsdDetector {
+			detector: LSDDetector::new_checked(params)?,
+			params,
+			octaves: self.octaves,
+			octave_scale: self.octave_scale,
+		})
+	}
+}
+
+impl Default for ConfiguredLsdDetectorBuilder {
+	#[inline]
+	fn default() -> Self {
+		Self {
+			params: None,
+			octaves: 1,
+			octave_scale: 2,
+		}
+	}
+}
+
+/// `LSDDetector` for the per-frame processing that reuses its output buffer between calls
+///
+/// The buffer keeps its capacity across frames, so no reallocation happens once it has grown to fit the biggest
+/// frame result.
+pub struct LineDetectLoop {
+	detector: LSDDetector,
+	keylines: VectorOfKeyLine,
+}
+
+impl LineDetectLoop {
+	#[inline]
+	pub fn new(detector: LSDDetector) -> Self {
+		Self { detector, keylines: VectorOfKeyLine::new() }
+	}
+
+	/// Detect lines in the `frame` returning them as a slice borrowed from the internal buffer
+	///
+	/// The previous results are discarded.
+	pub fn process(&mut self, frame: &Mat, scale: i32, num_octaves: i32) -> Result<&[KeyLine]> {
+		self.keylines.clear();
+		self.detector.detect(frame, &mut self.keylines, scale, num_octaves, core::empty_mask())?;
+		Ok(self.keylines.as_slice())
+	}
+
+	/// Results of the last `process()` call
+	#[inline]
+	pub fn keylines(&self) -> &VectorOfKeyLine {
+		&self.keylines
+	}
+
+	/// Underlying raw detector, mutable
+	#[inline]
+	pub fn detector_mut(&mut self) -> &mut LSDDetector {
+		&mut self.detector
+	}
+
+	/// Consume the loop returning the underlying raw detector
+	#[inline]
+	pub fn into_detector(self) -> LSDDetector {
+		self.detector
+	}
+}
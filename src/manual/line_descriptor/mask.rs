@@ -0,0 +1,24 @@
+use crate::{
+	core::{self, Mat, Point, Scalar, Size},
+	Error,
+	imgproc,
+	Result,
+	types::{VectorOfPoint, VectorOfVectorOfPoint},
+};
+
+/// Create a single-channel 8-bit mask of the specified `size` with the `polygon` area filled with 255
+///
+/// The result can be passed directly as the `mask` argument of the `detect()` methods to limit the line
+/// search to the polygon. Parts of the polygon outside of the mask are clipped.
+pub fn make_polygon_mask(size: Size, polygon: &VectorOfPoint) -> Result<Mat> {
+	if size.width <= 0 || size.height <= 0 {
+		return Err(Error::new(core::StsBadSize, format!("Invalid mask size: {:?}", size)));
+	}
+	let mut out = Mat::new_rows_cols_with_default(size.height, size.width, core::CV_8UC1, Scalar::all(0.))?;
+	if !polygon.is_empty() {
+		let mut polygons = VectorOfVectorOfPoint::with_capacity(1);
+		polygons.push(polygon.clone());
+		imgproc::fill_poly(&mut out, &polygons, Scalar::all(255.), imgproc::LINE_8, 0, Point::default())?;
+	}
+	Ok(out)
+}
@@ -0,0 +1,98 @@
+use std::sync::{Mutex, MutexGuard};
+
+use crate::{
+	core::{self, DMatch, Mat},
+	Error,
+	line_descriptor::{BinaryDescriptorMatcher, BinaryDescriptorMatcherTrait},
+	prelude::*,
+	Result,
+	types::{VectorOfDMatch, VectorOfMat, VectorOfVectorOfDMatch},
+};
+
+pub trait BinaryDescriptorMatcherTraitManual: BinaryDescriptorMatcherTrait {
+	/// For each query descriptor find `k` best matching training descriptors, returning owned `Vec`s
+	///
+	/// Matches are computed without a mask and with `compact_result = false` so the outer `Vec` has an entry for
+	/// every query descriptor.
+	fn knn_match_vec(&self, query: &Mat, train: &Mat, k: i32) -> Result<Vec<Vec<DMatch>>> {
+		let mut out = VectorOfVectorOfDMatch::new();
+		self.knn_match(query, train, &mut out, k, core::empty_mask(), false)?;
+		Ok(out.into_iter().map(|m| m.to_vec()).collect())
+	}
+
+	/// For each query descriptor find all training descriptors within `max_distance` (Hamming), returning owned `Vec`s
+	///
+	/// Matches are computed without a mask and with `compact_result = false` so the outer `Vec` has an entry for
+	/// every query descriptor.
+	fn radius_match_vec(&self, query: &Mat, train: &Mat, max_distance: f32) -> Result<Vec<Vec<DMatch>>> {
+		let mut out = VectorOfVectorOfDMatch::new();
+		self.radius_match(query, train, &mut out, max_distance, core::empty_mask(), false)?;
+		Ok(out.into_iter().map(|m| m.to_vec()).collect())
+	}
+
+	/// Add the descriptors saved with `save_descriptors()` to the internal dataset and train it
+	///
+	/// The matcher doesn't expose its dataset, so to persist it save the same descriptors that were passed to `add()`.
+	#[inline]
+	fn load_dataset(&mut self, path: &str) -> Result<()> {
+		super::dataset::load_dataset(self, path)
+	}
+}
+
+impl<T: BinaryDescriptorMatcherTrait> BinaryDescriptorMatcherTraitManual for T {}
+
+/// `BinaryDescriptorMatcher` that can be queried from multiple threads at once
+///
+/// `BinaryDescriptorMatcher` is `Send`, but not `Sync`. The methods taking `&self` (`match_()`, `knn_match()`,
+/// `radius_match()`) only read the descriptors passed in, but the ones working with the internal dataset
+/// (`match_query()`, `knn_match_query()`, `radius_match_1()`, `add()`, `train()` and `clear()`) take `&mut self`
+/// because OpenCV updates the matcher state during the call. This wrapper serializes access to the matcher
+/// behind a `Mutex`, so the dataset queries are executed one at a time.
+pub struct SyncMatcher {
+	inner: Mutex<BinaryDescriptorMatcher>,
+}
+
+impl SyncMatcher {
+	#[inline]
+	pub fn new(matcher: BinaryDescriptorMatcher) -> Self {
+		Self { inner: Mutex::new(matcher) }
+	}
+
+	/// Create a new matcher with the internal dataset built from the `descriptors` of the training images
+	pub fn from_dataset(descriptors: &[Mat]) -> Result<Self> {
+		let mut matcher = BinaryDescriptorMatcher::default()?;
+		let dataset = descriptors.iter()
+			.map(|d| d.try_clone())
+			.collect::<Result<VectorOfMat>>()?;
+		matcher.add(&dataset)?;
+		matcher.train()?;
+		Ok(Self::new(matcher))
+	}
+
+	/// For each query descriptor find the best match in the internal dataset
+	pub fn match_query(&self, query: &Mat) -> Result<Vec<DMatch>> {
+		let mut out = VectorOfDMatch::new();
+		self.lock()?.match_query(query, &mut out, &VectorOfMat::new())?;
+		Ok(out.to_vec())
+	}
+
+	/// For each query descriptor find `k` best matches in the internal dataset, see
+	/// `BinaryDescriptorMatcherTraitManual::knn_match_vec()` for the result layout
+	pub fn knn_match_query(&self, query: &Mat, k: i32) -> Result<Vec<Vec<DMatch>>> {
+		let mut out = VectorOfVectorOfDMatch::new();
+		self.lock()?.knn_match_query(query, &mut out, k, &VectorOfMat::new(), false)?;
+		Ok(out.into_iter().map(|m| m.to_vec()).collect())
+	}
+
+	/// Exclusive access to the underlying matcher, e.g. to extend the dataset
+	pub fn lock(&self) -> Result<MutexGuard<'_, BinaryDescriptorMatcher>> {
+		self.inner.lock()
+			.map_err(|_| Error::new(core::StsError, "SyncMatcher mutex is poisoned".to_string()))
+	}
+
+	/// Consume the wrapper returning the underlying matcher
+	pub fn into_inner(self) -> Result<BinaryDescriptorMatcher> {
+		self.inner.into_inner()
+			.map_err(|_| Error::new(core::StsError, "SyncMatcher mutex is poisoned".to_string()))
+	}
+}
@@ -0,0 +1,139 @@
+use std::f32::consts::PI;
+
+use crate::{
+	core::Point2f,
+	line_descriptor::KeyLine,
+	types::VectorOfKeyLine,
+};
+
+/// Merge fragments of the same straight edge into single keylines
+///
+/// Two keylines are merged when their directions differ by at most `angle_tol_deg` (direction of the line is
+/// ignored, so e.g. 89.5° and -90° are considered close), the gap between their nearest endpoints is at most
+/// `gap_tol` and they lie on the same line within `gap_tol`. Merging is transitive. Each group is replaced by a
+/// single keyline spanning all of its fragments along the direction of the longest one, which also provides the
+/// `class_id`, `octave` and `response`. Keylines that aren't merged are returned unchanged.
+pub fn merge_collinear(keylines: &VectorOfKeyLine, angle_tol_deg: f32, gap_tol: f32) -> VectorOfKeyLine {
+	let keylines = keylines.to_vec();
+	let angle_tol = angle_tol_deg.to_radians();
+	let mut parents = (0..keylines.len()).collect::<Vec<_>>();
+	for i in 0..keylines.len() {
+		for j in i + 1..keylines.len() {
+			if are_collinear(&keylines[i], &keylines[j], angle_tol, gap_tol) {
+				let (root_i, root_j) = (find_root(&mut parents, i), find_root(&mut parents, j));
+				parents[root_i.max(root_j)] = root_i.min(root_j);
+			}
+		}
+	}
+	let mut groups: Vec<Vec<usize>> = vec![];
+	let mut group_of_root = vec![None::<usize>; keylines.len()];
+	for i in 0..keylines.len() {
+		let root = find_root(&mut parents, i);
+		match group_of_root[root] {
+			Some(group) => groups[group].push(i),
+			None => {
+				group_of_root[root] = Some(groups.len());
+				groups.push(vec![i]);
+			}
+		}
+	}
+	groups.into_iter()
+		.map(|group| if group.len() == 1 {
+			keylines[group[0]]
+		} else {
+			merge_group(group.iter().map(|&i| &keylines[i]))
+		})
+		.collect()
+}
+
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+	while parents[i] != i {
+		parents[i] = parents[parents[i]];
+		i = parents[i];
+	}
+	i
+}
+
+#[inline]
+fn endpoints(keyline: &KeyLine) -> [Point2f; 2] {
+	[
+		Point2f::new(keyline.start_point_x, keyline.start_point_y),
+		Point2f::new(keyline.end_point_x, keyline.end_point_y),
+	]
+}
+
+/// Unit direction vector of the keyline, `None` for zero length
+#[inline]
+fn direction(keyline: &KeyLine) -> Option<Point2f> {
+	let [start, end] = endpoints(keyline);
+	let len = keyline.length();
+	if len > 0. {
+		Some((end - start) / len)
+	} else {
+		None
+	}
+}
+
+/// Difference between the undirected angles of the keylines, in range `[0, PI/2]`
+fn angle_diff(a: &KeyLine, b: &KeyLine) -> f32 {
+	let [a_start, a_end] = endpoints(a);
+	let [b_start, b_end] = endpoints(b);
+	let a_angle = (a_end.y - a_start.y).atan2(a_end.x - a_start.x);
+	let b_angle = (b_end.y - b_start.y).atan2(b_end.x - b_start.x);
+	let diff = (a_angle - b_angle).abs() % PI;
+	diff.min(PI - diff)
+}
+
+/// Distance from `pt` to the infinite line through `origin` with the unit direction `dir`
+#[inline]
+fn line_distance(pt: Point2f, origin: Point2f, dir: Point2f) -> f32 {
+	(pt - origin).cross(dir).abs() as f32
+}
+
+fn are_collinear(a: &KeyLine, b: &KeyLine, angle_tol: f32, gap_tol: f32) -> bool {
+	if angle_diff(a, b) > angle_tol {
+		return false;
+	}
+	let (a_pts, b_pts) = (endpoints(a), endpoints(b));
+	let gap = a_pts.iter()
+		.flat_map(|&a_pt| b_pts.iter().map(move |&b_pt| (a_pt - b_pt).norm() as f32))
+		.fold(f32::INFINITY, f32::min);
+	if gap > gap_tol {
+		return false;
+	}
+	let (long, short) = if a.length() >= b.length() { (a, b) } else { (b, a) };
+	match direction(long) {
+		Some(dir) => {
+			let origin = endpoints(long)[0];
+			endpoints(short).iter().all(|&pt| line_distance(pt, origin, dir) <= gap_tol)
+		}
+		None => true,
+	}
+}
+
+fn merge_group<'k>(group: impl Iterator<Item=&'k KeyLine> + Clone) -> KeyLine {
+	let longest = group.clone()
+		.fold(None, |longest: Option<&KeyLine>, keyline| match longest {
+			Some(longest) if longest.length() >= keyline.length() => Some(longest),
+			_ => Some(keyline),
+		})
+		.expect("Group can't be empty");
+	let origin = endpoints(longest)[0];
+	let dir = direction(longest).unwrap_or_else(|| Point2f::new(1., 0.));
+	let (min_t, max_t) = group
+		.flat_map(|keyline| endpoints(keyline).to_vec())
+		.map(|pt| (pt - origin).dot(dir))
+		.fold((f32::INFINITY, f32::NEG_INFINITY), |(min_t, max_t), t| (min_t.min(t), max_t.max(t)));
+	let mut out = KeyLine::from_points(origin + dir * min_t, origin + dir * max_t);
+	// octave coordinates keep the scale of the longest fragment
+	let octave_scale = if longest.length() > 0. { longest.line_length / longest.length() } else { 1. };
+	out.s_point_in_octave_x *= octave_scale;
+	out.s_point_in_octave_y *= octave_scale;
+	out.e_point_in_octave_x *= octave_scale;
+	out.e_point_in_octave_y *= octave_scale;
+	out.line_length *= octave_scale;
+	out.class_id = longest.class_id;
+	out.octave = longest.octave;
+	out.response = longest.response;
+	out
+}
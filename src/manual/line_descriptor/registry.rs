@@ -0,0 +1,124 @@
+//! Registry of [LineDetector] implementations selectable by name at runtime
+//!
+//! Built-in detectors are `"binary"` (`BinaryDescriptor`, configured by `num_of_octaves`, `width_of_band` and
+//! `reduction_ratio`) and `"lsd"` (`ConfiguredLsdDetector`, configured by `octaves` and `octave_scale`).
+
+use std::{
+	collections::BTreeMap,
+	sync::{Arc, RwLock},
+};
+
+use once_cell::sync::Lazy;
+
+use crate::{
+	core,
+	Error,
+	line_descriptor::{BinaryDescriptor, BinaryDescriptorTrait, ConfiguredLsdDetector, LineDetector},
+	Result,
+};
+
+/// Factory creating a detector from the configuration
+///
+/// Factories are called without holding the registry lock, so they can use the registry themselves.
+pub type DetectorFactory = Arc<dyn Fn(&PipelineConfig) -> Result<Box<dyn LineDetector>> + Send + Sync>;
+
+static REGISTRY: Lazy<RwLock<BTreeMap<String, DetectorFactory>>> = Lazy::new(|| {
+	let mut out = BTreeMap::<String, DetectorFactory>::new();
+	out.insert("binary".to_string(), Arc::new(|config| {
+		let mut detector = BinaryDescriptor::create_binary_descriptor()?;
+		if let Some(num_of_octaves) = config.get_i32("num_of_octaves")? {
+			detector.set_num_of_octaves(num_of_octaves)?;
+		}
+		if let Some(width_of_band) = config.get_i32("width_of_band")? {
+			detector.set_width_of_band(width_of_band)?;
+		}
+		if let Some(reduction_ratio) = config.get_i32("reduction_ratio")? {
+			detector.set_reduction_ratio(reduction_ratio)?;
+		}
+		Ok(Box::new(detector))
+	}));
+	out.insert("lsd".to_string(), Arc::new(|config| {
+		let mut builder = ConfiguredLsdDetector::builder();
+		if let Some(octaves) = config.get_i32("octaves")? {
+			builder = builder.octaves(octaves);
+		}
+		if let Some(octave_scale) = config.get_i32("octave_scale")? {
+			builder = builder.octave_scale(octave_scale);
+		}
+		Ok(Box::new(builder.build()?))
+	}));
+	RwLock::new(out)
+});
+
+/// Register a new detector `factory` under `name`, fails if the name is already taken
+pub fn register_detector(name: &str, factory: DetectorFactory) -> Result<()> {
+	let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+	if registry.contains_key(name) {
+		return Err(Error::new(core::StsBadArg, format!("Detector: {} is already registered", name)));
+	}
+	registry.insert(name.to_string(), factory);
+	Ok(())
+}
+
+/// Create the detector registered under `name` using `config`
+pub fn create_detector(name: &str, config: &PipelineConfig) -> Result<Box<dyn LineDetector>> {
+	let factory = {
+		let registry = REGISTRY.read().unwrap_or_else(|e| e.into_inner());
+		match registry.get(name) {
+			Some(factory) => Arc::clone(factory),
+			None => return Err(Error::new(
+				core::StsObjectNotFound,
+				format!("Unknown detector: {}, available detectors: {}", name, registry.keys().map(String::as_str).collect::<Vec<_>>().join(", ")),
+			)),
+		}
+	};
+	factory(config)
+}
+
+/// Names of all registered detectors in alphabetical order
+pub fn list_detectors() -> Vec<String> {
+	REGISTRY.read().unwrap_or_else(|e| e.into_inner()).keys().cloned().collect()
+}
+
+/// String key-value configuration passed to the detector factories
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PipelineConfig {
+	values: BTreeMap<String, String>,
+}
+
+impl PipelineConfig {
+	#[inline]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Parse the configuration from a string like `"octaves=2, octave_scale=2"`
+	pub fn parse(s: &str) -> Result<Self> {
+		let mut out = Self::new();
+		for pair in s.split(|c| c == ',' || c == ';').map(str::trim).filter(|p| !p.is_empty()) {
+			let mut parts = pair.splitn(2, '=');
+			match (parts.next().map(str::trim), parts.next().map(str::trim)) {
+				(Some(key), Some(value)) if !key.is_empty() => out.set(key, value),
+				_ => return Err(Error::new(core::StsParseError, format!("Invalid configuration entry: {}, expected key=value", pair))),
+			}
+		}
+		Ok(out)
+	}
+
+	#[inline]
+	pub fn set(&mut self, key: &str, value: &str) {
+		self.values.insert(key.to_string(), value.to_string());
+	}
+
+	#[inline]
+	pub fn get(&self, key: &str) -> Option<&str> {
+		self.values.get(key).map(String::as_str)
+	}
+
+	/// Value of `key` parsed as `i32`, `None` if it's not set
+	pub fn get_i32(&self, key: &str) -> Result<Option<i32>> {
+		self.get(key)
+			.map(|v| v.parse().map_err(|_| Error::new(core::StsParseError, format!("Configuration value: {} of: {} is not an integer", v, key))))
+			.transpose()
+	}
+}
@@ -0,0 +1,124 @@
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+	core::{self, Mat, Size},
+	Error,
+	prelude::*,
+	Result,
+	types::VectorOfKeyLine,
+};
+
+/// Intensity profile across the rendered line used by [render_lines_f32]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Falloff {
+	/// Exact area coverage of a pixel by a line of the given thickness, 1.0 inside the line with linear edges
+	Linear,
+	/// Gaussian profile with sigma of half the thickness (but at least 0.5 px), peak is scaled down for lines
+	/// thinner than 1 px to keep the total intensity proportional to the thickness
+	Gaussian,
+}
+
+/// How intensities of overlapping lines are combined by [render_lines_f32_blend]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Blend {
+	Max,
+	Sum,
+}
+
+/// Render `keylines` with sub-pixel precision into a new `CV_32FC1` `Mat` of the specified `size`
+///
+/// Pixel centers are at integer coordinates, overlapping lines are combined with `Blend::Max`.
+#[inline]
+pub fn render_lines_f32(keylines: &VectorOfKeyLine, size: Size, thickness: f32, falloff: Falloff) -> Result<Mat> {
+	render_lines_f32_blend(keylines, size, thickness, falloff, Blend::Max)
+}
+
+/// Like [render_lines_f32], but with the explicit `blend` mode
+///
+/// With the `rayon` feature enabled the rows are rendered in parallel, the result is the same.
+pub fn render_lines_f32_blend(keylines: &VectorOfKeyLine, size: Size, thickness: f32, falloff: Falloff, blend: Blend) -> Result<Mat> {
+	if !(thickness.is_finite() && thickness > 0.) {
+		return Err(Error::new(core::StsBadArg, format!("Line thickness must be positive, but it's: {}", thickness)));
+	}
+	if size.width <= 0 || size.height <= 0 {
+		return Err(Error::new(core::StsBadArg, format!("Invalid image size: {:?}", size)));
+	}
+	let mut out = Mat::new_rows_cols_with_default(size.height, size.width, core::CV_32FC1, Default::default())?;
+	let half = thickness / 2.;
+	let (sigma, peak) = (thickness.max(1.) / 2., thickness.min(1.));
+	let reach = match falloff {
+		Falloff::Linear => half + 0.5,
+		Falloff::Gaussian => 3. * sigma,
+	};
+	let segments = keylines.iter()
+		.filter_map(|keyline| {
+			let (x0, y0, x1, y1) = (keyline.start_point_x, keyline.start_point_y, keyline.end_point_x, keyline.end_point_y);
+			let out = Segment {
+				x0, y0, x1, y1,
+				min_x: ((x0.min(x1) - reach).floor() as i32).max(0),
+				max_x: ((x0.max(x1) + reach).ceil() as i32).min(size.width - 1),
+				min_y: ((y0.min(y1) - reach).floor() as i32).max(0),
+				max_y: ((y0.max(y1) + reach).ceil() as i32).min(size.height - 1),
+			};
+			if out.min_x > out.max_x || out.min_y > out.max_y {
+				None
+			} else {
+				Some(out)
+			}
+		})
+		.collect::<Vec<_>>();
+	let render_row = |(y, row): (usize, &mut [f32])| {
+		let y = y as i32;
+		for seg in segments.iter().filter(|seg| seg.min_y <= y && y <= seg.max_y) {
+			for x in seg.min_x..=seg.max_x {
+				let dist = segment_distance(x as f32, y as f32, seg.x0, seg.y0, seg.x1, seg.y1);
+				let val = match falloff {
+					Falloff::Linear => ((dist + half).min(0.5) - (dist - half).max(-0.5)).max(0.),
+					Falloff::Gaussian => if dist <= reach {
+						peak * (-dist * dist / (2. * sigma * sigma)).exp()
+					} else {
+						0.
+					},
+				};
+				let px = &mut row[x as usize];
+				match blend {
+					Blend::Max => *px = px.max(val),
+					Blend::Sum => *px += val,
+				}
+			}
+		}
+	};
+	// freshly allocated Mat is continuous
+	let data = out.data_typed_mut::<f32>()?;
+	#[cfg(feature = "rayon")]
+	data.par_chunks_mut(size.width as usize).enumerate().for_each(render_row);
+	#[cfg(not(feature = "rayon"))]
+	data.chunks_mut(size.width as usize).enumerate().for_each(render_row);
+	Ok(out)
+}
+
+/// Segment endpoints together with the clamped bounding box of the pixels it affects
+struct Segment {
+	x0: f32,
+	y0: f32,
+	x1: f32,
+	y1: f32,
+	min_x: i32,
+	max_x: i32,
+	min_y: i32,
+	max_y: i32,
+}
+
+/// Distance from the point (`px`, `py`) to the segment (`x0`, `y0`)-(`x1`, `y1`)
+#[inline]
+fn segment_distance(px: f32, py: f32, x0: f32, y0: f32, x1: f32, y1: f32) -> f32 {
+	let (dx, dy) = (x1 - x0, y1 - y0);
+	let len_sq = dx * dx + dy * dy;
+	let t = if len_sq > 0. {
+		(((px - x0) * dx + (py - y0) * dy) / len_sq).max(0.).min(1.)
+	} else {
+		0.
+	};
+	(px - (x0 + t * dx)).hypot(py - (y0 + t * dy))
+}
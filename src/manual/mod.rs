@@ -4,12 +4,22 @@ pub mod core;
 pub mod dnn;
 #[cfg(ocvrs_has_module_features2d)]
 pub mod features2d;
+#[cfg(ocvrs_has_module_imgcodecs)]
+pub mod imgcodecs;
+#[cfg(ocvrs_has_module_line_descriptor)]
+pub mod line_descriptor;
+#[cfg(ocvrs_has_module_photo)]
+pub mod photo;
 pub mod sys;
 pub mod types;
+#[cfg(ocvrs_has_module_videoio)]
+pub mod videoio;
 
 pub mod prelude {
 	#[cfg(ocvrs_has_module_core)]
-	pub use super::core::{MatConstIteratorTraitManual, MatTraitManual, MatxTrait, UMatTraitManual};
+	pub use super::core::{FileNodeTraitManual, MatConstIteratorTraitManual, MatTraitManual, MatxTrait, RNGTraitManual, RotatedRectTraitManual, SparseMatTraitManual, UMatTraitManual};
 	#[cfg(all(ocvrs_has_module_core, ocvrs_opencv_branch_32))]
 	pub use super::core::MatSizeTraitManual;
+	#[cfg(ocvrs_has_module_line_descriptor)]
+	pub use super::line_descriptor::{BinaryDescriptorMatcherTraitManual, BinaryDescriptorTraitManual, LSDDetectorTraitManual};
 }
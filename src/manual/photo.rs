@@ -0,0 +1,82 @@
+use crate::{
+	core::{self, Mat, Point, Rect, Size, Vec3b},
+	Error,
+	photo,
+	prelude::*,
+	Result,
+};
+
+/// Like `seamless_clone()`, but validates the arguments beforehand to report the problems with readable errors
+///
+/// `src` and `dst` must be `CV_8UC3`, `mask` must be `CV_8UC1` or `CV_8UC3` of the same size as `src` with at
+/// least one non-zero pixel, `flags` must be one of `NORMAL_CLONE`, `MIXED_CLONE` or `MONOCHROME_TRANSFER`. The
+/// masked region centered at `center` must fit inside `dst`.
+pub fn seamless_clone_checked(src: &Mat, dst: &Mat, mask: &Mat, center: Point, blend: &mut Mat, flags: i32) -> Result<()> {
+	check_8uc3("src", src)?;
+	check_8uc3("dst", dst)?;
+	check_mask(mask, src.size()?)?;
+	if ![photo::NORMAL_CLONE, photo::MIXED_CLONE, photo::MONOCHROME_TRANSFER].contains(&flags) {
+		return Err(Error::new(core::StsBadFlag, format!("Invalid seamless clone flags: {}", flags)));
+	}
+	let region = mask_bounds(mask)?
+		.ok_or_else(|| Error::new(core::StsBadArg, "Mask doesn't have any non-zero pixels".to_string()))?;
+	let dst_size = dst.size()?;
+	let target = Rect::new(center.x - region.width / 2, center.y - region.height / 2, region.width, region.height);
+	if target.x < 0 || target.y < 0 || target.x + target.width > dst_size.width || target.y + target.height > dst_size.height {
+		return Err(Error::new(
+			core::StsOutOfRange,
+			format!("Masked region of size: {:?} centered at: {:?} doesn't fit into dst of size: {:?}", region.size(), center, dst_size),
+		));
+	}
+	photo::seamless_clone(src, dst, mask, center, blend, flags)
+}
+
+/// Like `illumination_change()`, but validates the arguments beforehand to report the problems with readable errors
+///
+/// `src` must be `CV_8UC3`, `mask` must be `CV_8UC1` or `CV_8UC3` of the same size as `src`.
+pub fn illumination_change_checked(src: &Mat, mask: &Mat, dst: &mut Mat, alpha: f32, beta: f32) -> Result<()> {
+	check_8uc3("src", src)?;
+	check_mask(mask, src.size()?)?;
+	photo::illumination_change(src, mask, dst, alpha, beta)
+}
+
+fn check_8uc3(name: &str, mat: &Mat) -> Result<()> {
+	if mat.empty()? {
+		return Err(Error::new(core::StsBadArg, format!("{} image is empty", name)));
+	}
+	if mat.typ()? != core::CV_8UC3 {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("{} image must be CV_8UC3 ({}), but its type is: {}", name, core::CV_8UC3, mat.typ()?)));
+	}
+	Ok(())
+}
+
+fn check_mask(mask: &Mat, size: Size) -> Result<()> {
+	let typ = mask.typ()?;
+	if typ != core::CV_8UC1 && typ != core::CV_8UC3 {
+		return Err(Error::new(core::StsUnsupportedFormat, format!("mask must be CV_8UC1 ({}) or CV_8UC3 ({}), but its type is: {}", core::CV_8UC1, core::CV_8UC3, typ)));
+	}
+	if mask.size()? != size {
+		return Err(Error::new(core::StsUnmatchedSizes, format!("mask size: {:?} doesn't match src size: {:?}", mask.size()?, size)));
+	}
+	Ok(())
+}
+
+/// Bounding rectangle of the non-zero mask pixels
+fn mask_bounds(mask: &Mat) -> Result<Option<Rect>> {
+	let mut bounds: Option<(i32, i32, i32, i32)> = None;
+	for y in 0..mask.rows() {
+		let row_nonzero: Vec<bool> = if mask.typ()? == core::CV_8UC1 {
+			mask.at_row::<u8>(y)?.iter().map(|&x| x != 0).collect()
+		} else {
+			mask.at_row::<Vec3b>(y)?.iter().map(|x| x.iter().any(|&c| c != 0)).collect()
+		};
+		if let (Some(first), Some(last)) = (row_nonzero.iter().position(|&x| x), row_nonzero.iter().rposition(|&x| x)) {
+			let (first, last) = (first as i32, last as i32);
+			bounds = Some(match bounds {
+				Some((min_x, min_y, max_x, _)) => (min_x.min(first), min_y, max_x.max(last), y),
+				None => (first, y, last, y),
+			});
+		}
+	}
+	Ok(bounds.map(|(min_x, min_y, max_x, max_y)| Rect::new(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1)))
+}
@@ -0,0 +1,101 @@
+use std::{iter::FusedIterator, time::Duration};
+
+use crate::{
+	core::{self, Mat},
+	Error,
+	prelude::*,
+	Result,
+	videoio::{self, VideoCapture},
+};
+
+/// Open the video file at `path` and iterate over its frames, see [FrameIter]
+pub fn frames(path: &str) -> Result<FrameIter> {
+	let cap = VideoCapture::from_file(path, videoio::CAP_ANY)?;
+	if !cap.is_opened()? {
+		return Err(Error::new(core::StsError, format!("Can't open video: {}", path)));
+	}
+	Ok(FrameIter::new(cap))
+}
+
+/// Iterator over the frames of an owned `VideoCapture`
+///
+/// When reading fails the iterator yields a single `Err` and then stops.
+pub struct FrameIter {
+	cap: VideoCapture,
+	stride: usize,
+	limit: Option<usize>,
+	pos: usize,
+	done: bool,
+}
+
+impl FrameIter {
+	#[inline]
+	pub fn new(cap: VideoCapture) -> Self {
+		Self { cap, stride: 1, limit: None, pos: 0, done: false }
+	}
+
+	/// Yield only every `stride`-th frame starting with the first one, `stride` of 0 is treated as 1
+	#[inline]
+	pub fn with_stride(mut self, stride: usize) -> Self {
+		self.stride = stride.max(1);
+		self
+	}
+
+	/// Stop after `duration` of the video has been consumed, uses the `CAP_PROP_FPS` of the capture
+	pub fn take_duration(mut self, duration: Duration) -> Result<Self> {
+		let fps = self.cap.get(videoio::CAP_PROP_FPS)?;
+		if !fps.is_finite() || fps <= 0. {
+			return Err(Error::new(core::StsError, format!("Video FPS is not available: {}", fps)));
+		}
+		self.limit = Some(self.pos + (duration.as_secs_f64() * fps).ceil() as usize);
+		Ok(self)
+	}
+
+	/// Consume the iterator returning the underlying capture
+	#[inline]
+	pub fn into_inner(self) -> VideoCapture {
+		self.cap
+	}
+
+	#[inline]
+	fn within_limit(&self) -> bool {
+		self.limit.map_or(true, |limit| self.pos < limit)
+	}
+
+	fn read_frame(&mut self) -> Result<Option<Mat>> {
+		if self.pos > 0 {
+			for _ in 1..self.stride {
+				if !self.within_limit() || !self.cap.grab()? {
+					return Ok(None);
+				}
+				self.pos += 1;
+			}
+		}
+		if !self.within_limit() {
+			return Ok(None);
+		}
+		let mut frame = Mat::default();
+		if !self.cap.read(&mut frame)? {
+			return Ok(None);
+		}
+		self.pos += 1;
+		Ok(Some(frame))
+	}
+}
+
+impl Iterator for FrameIter {
+	type Item = Result<Mat>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		let out = self.read_frame().transpose();
+		if !matches!(out, Some(Ok(_))) {
+			self.done = true;
+		}
+		out
+	}
+}
+
+impl FusedIterator for FrameIter {}
@@ -127,7 +127,7 @@ pub mod xobjdetect;
 #[cfg(ocvrs_has_module_xphoto)]
 pub mod xphoto;
 pub mod types;
-#[doc(hidden)]
+/// Raw FFI functions used by the generated bindings, the API of this module is unstable and can change between releases
 pub mod sys;
 pub mod hub_prelude {
 	#[cfg(ocvrs_has_module_alphamat)]
@@ -824,7 +824,9 @@ opencv_type_boxed! { Board }
 impl Drop for Board {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Board_delete(instance: *mut c_void); }
-		unsafe { cv_Board_delete(self.as_raw_mut_Board()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Board_delete(self.as_raw_mut_Board()) };
+		}
 	}
 }
 
@@ -937,7 +939,9 @@ opencv_type_boxed! { CharucoBoard }
 impl Drop for CharucoBoard {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CharucoBoard_delete(instance: *mut c_void); }
-		unsafe { cv_CharucoBoard_delete(self.as_raw_mut_CharucoBoard()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CharucoBoard_delete(self.as_raw_mut_CharucoBoard()) };
+		}
 	}
 }
 
@@ -1346,7 +1350,9 @@ opencv_type_boxed! { DetectorParameters }
 impl Drop for DetectorParameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectorParameters_delete(instance: *mut c_void); }
-		unsafe { cv_DetectorParameters_delete(self.as_raw_mut_DetectorParameters()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectorParameters_delete(self.as_raw_mut_DetectorParameters()) };
+		}
 	}
 }
 
@@ -1453,7 +1459,9 @@ opencv_type_boxed! { Dictionary }
 impl Drop for Dictionary {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Dictionary_delete(instance: *mut c_void); }
-		unsafe { cv_Dictionary_delete(self.as_raw_mut_Dictionary()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Dictionary_delete(self.as_raw_mut_Dictionary()) };
+		}
 	}
 }
 
@@ -1570,7 +1578,9 @@ opencv_type_boxed! { GridBoard }
 impl Drop for GridBoard {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GridBoard_delete(instance: *mut c_void); }
-		unsafe { cv_GridBoard_delete(self.as_raw_mut_GridBoard()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GridBoard_delete(self.as_raw_mut_GridBoard()) };
+		}
 	}
 }
 
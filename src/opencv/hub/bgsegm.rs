@@ -407,7 +407,9 @@ opencv_type_boxed! { BackgroundSubtractorLSBPDesc }
 impl Drop for BackgroundSubtractorLSBPDesc {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BackgroundSubtractorLSBPDesc_delete(instance: *mut c_void); }
-		unsafe { cv_BackgroundSubtractorLSBPDesc_delete(self.as_raw_mut_BackgroundSubtractorLSBPDesc()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BackgroundSubtractorLSBPDesc_delete(self.as_raw_mut_BackgroundSubtractorLSBPDesc()) };
+		}
 	}
 }
 
@@ -516,7 +518,9 @@ opencv_type_boxed! { SyntheticSequenceGenerator }
 impl Drop for SyntheticSequenceGenerator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SyntheticSequenceGenerator_delete(instance: *mut c_void); }
-		unsafe { cv_SyntheticSequenceGenerator_delete(self.as_raw_mut_SyntheticSequenceGenerator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SyntheticSequenceGenerator_delete(self.as_raw_mut_SyntheticSequenceGenerator()) };
+		}
 	}
 }
 
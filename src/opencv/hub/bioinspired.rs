@@ -654,7 +654,9 @@ opencv_type_boxed! { RetinaParameters }
 impl Drop for RetinaParameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RetinaParameters_delete(instance: *mut c_void); }
-		unsafe { cv_RetinaParameters_delete(self.as_raw_mut_RetinaParameters()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RetinaParameters_delete(self.as_raw_mut_RetinaParameters()) };
+		}
 	}
 }
 
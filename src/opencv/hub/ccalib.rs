@@ -480,7 +480,9 @@ opencv_type_boxed! { CustomPattern }
 impl Drop for CustomPattern {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CustomPattern_delete(instance: *mut c_void); }
-		unsafe { cv_CustomPattern_delete(self.as_raw_mut_CustomPattern()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CustomPattern_delete(self.as_raw_mut_CustomPattern()) };
+		}
 	}
 }
 
@@ -568,7 +570,9 @@ opencv_type_boxed! { MultiCameraCalibration }
 impl Drop for MultiCameraCalibration {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MultiCameraCalibration_delete(instance: *mut c_void); }
-		unsafe { cv_MultiCameraCalibration_delete(self.as_raw_mut_MultiCameraCalibration()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MultiCameraCalibration_delete(self.as_raw_mut_MultiCameraCalibration()) };
+		}
 	}
 }
 
@@ -648,7 +652,9 @@ opencv_type_boxed! { MultiCameraCalibration_edge }
 impl Drop for MultiCameraCalibration_edge {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MultiCameraCalibration_edge_delete(instance: *mut c_void); }
-		unsafe { cv_MultiCameraCalibration_edge_delete(self.as_raw_mut_MultiCameraCalibration_edge()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MultiCameraCalibration_edge_delete(self.as_raw_mut_MultiCameraCalibration_edge()) };
+		}
 	}
 }
 
@@ -702,7 +708,9 @@ opencv_type_boxed! { MultiCameraCalibration_vertex }
 impl Drop for MultiCameraCalibration_vertex {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MultiCameraCalibration_vertex_delete(instance: *mut c_void); }
-		unsafe { cv_MultiCameraCalibration_vertex_delete(self.as_raw_mut_MultiCameraCalibration_vertex()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MultiCameraCalibration_vertex_delete(self.as_raw_mut_MultiCameraCalibration_vertex()) };
+		}
 	}
 }
 
@@ -786,7 +794,9 @@ opencv_type_boxed! { RandomPatternCornerFinder }
 impl Drop for RandomPatternCornerFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RandomPatternCornerFinder_delete(instance: *mut c_void); }
-		unsafe { cv_RandomPatternCornerFinder_delete(self.as_raw_mut_RandomPatternCornerFinder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RandomPatternCornerFinder_delete(self.as_raw_mut_RandomPatternCornerFinder()) };
+		}
 	}
 }
 
@@ -840,7 +850,9 @@ opencv_type_boxed! { RandomPatternGenerator }
 impl Drop for RandomPatternGenerator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RandomPatternGenerator_delete(instance: *mut c_void); }
-		unsafe { cv_RandomPatternGenerator_delete(self.as_raw_mut_RandomPatternGenerator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RandomPatternGenerator_delete(self.as_raw_mut_RandomPatternGenerator()) };
+		}
 	}
 }
 
@@ -6410,7 +6410,9 @@ opencv_type_boxed! { Algorithm }
 impl Drop for Algorithm {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Algorithm_delete(instance: *mut c_void); }
-		unsafe { cv_Algorithm_delete(self.as_raw_mut_Algorithm()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Algorithm_delete(self.as_raw_mut_Algorithm()) };
+		}
 	}
 }
 
@@ -6512,7 +6514,9 @@ opencv_type_boxed! { AsyncArray }
 impl Drop for AsyncArray {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AsyncArray_delete(instance: *mut c_void); }
-		unsafe { cv_AsyncArray_delete(self.as_raw_mut_AsyncArray()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_AsyncArray_delete(self.as_raw_mut_AsyncArray()) };
+		}
 	}
 }
 
@@ -6590,7 +6594,9 @@ opencv_type_boxed! { AsyncPromise }
 impl Drop for AsyncPromise {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AsyncPromise_delete(instance: *mut c_void); }
-		unsafe { cv_AsyncPromise_delete(self.as_raw_mut_AsyncPromise()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_AsyncPromise_delete(self.as_raw_mut_AsyncPromise()) };
+		}
 	}
 }
 
@@ -6849,7 +6855,9 @@ opencv_type_boxed! { CommandLineParser }
 impl Drop for CommandLineParser {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CommandLineParser_delete(instance: *mut c_void); }
-		unsafe { cv_CommandLineParser_delete(self.as_raw_mut_CommandLineParser()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CommandLineParser_delete(self.as_raw_mut_CommandLineParser()) };
+		}
 	}
 }
 
@@ -7185,7 +7193,9 @@ opencv_type_boxed! { Exception }
 impl Drop for Exception {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Exception_delete(instance: *mut c_void); }
-		unsafe { cv_Exception_delete(self.as_raw_mut_Exception()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Exception_delete(self.as_raw_mut_Exception()) };
+		}
 	}
 }
 
@@ -7454,7 +7464,9 @@ opencv_type_boxed! { FileNode }
 impl Drop for FileNode {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FileNode_delete(instance: *mut c_void); }
-		unsafe { cv_FileNode_delete(self.as_raw_mut_FileNode()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FileNode_delete(self.as_raw_mut_FileNode()) };
+		}
 	}
 }
 
@@ -7584,7 +7596,9 @@ opencv_type_boxed! { FileNodeIterator }
 impl Drop for FileNodeIterator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FileNodeIterator_delete(instance: *mut c_void); }
-		unsafe { cv_FileNodeIterator_delete(self.as_raw_mut_FileNodeIterator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FileNodeIterator_delete(self.as_raw_mut_FileNodeIterator()) };
+		}
 	}
 }
 
@@ -7878,7 +7892,9 @@ opencv_type_boxed! { FileStorage }
 impl Drop for FileStorage {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FileStorage_delete(instance: *mut c_void); }
-		unsafe { cv_FileStorage_delete(self.as_raw_mut_FileStorage()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FileStorage_delete(self.as_raw_mut_FileStorage()) };
+		}
 	}
 }
 
@@ -8005,7 +8021,9 @@ opencv_type_boxed! { Hamming }
 impl Drop for Hamming {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Hamming_delete(instance: *mut c_void); }
-		unsafe { cv_Hamming_delete(self.as_raw_mut_Hamming()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Hamming_delete(self.as_raw_mut_Hamming()) };
+		}
 	}
 }
 
@@ -8097,7 +8115,7 @@ impl KeyPoint {
 		unsafe { sys::cv_KeyPoint_KeyPoint_float_float_float_float_float_int_int(x, y, _size, _angle, _response, _octave, _class_id) }.into_result()
 	}
 	
-	pub fn hash(self) -> Result<size_t> {
+	pub fn hash(&self) -> Result<size_t> {
 		unsafe { sys::cv_KeyPoint_hash_const(self.opencv_as_extern()) }.into_result()
 	}
 	
@@ -8227,7 +8245,9 @@ opencv_type_boxed! { LDA }
 impl Drop for LDA {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LDA_delete(instance: *mut c_void); }
-		unsafe { cv_LDA_delete(self.as_raw_mut_LDA()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LDA_delete(self.as_raw_mut_LDA()) };
+		}
 	}
 }
 
@@ -10241,7 +10261,9 @@ opencv_type_boxed! { Mat }
 impl Drop for Mat {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Mat_delete(instance: *mut c_void); }
-		unsafe { cv_Mat_delete(self.as_raw_mut_Mat()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Mat_delete(self.as_raw_mut_Mat()) };
+		}
 	}
 }
 
@@ -10832,7 +10854,9 @@ opencv_type_boxed! { MatConstIterator }
 impl Drop for MatConstIterator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MatConstIterator_delete(instance: *mut c_void); }
-		unsafe { cv_MatConstIterator_delete(self.as_raw_mut_MatConstIterator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MatConstIterator_delete(self.as_raw_mut_MatConstIterator()) };
+		}
 	}
 }
 
@@ -11104,7 +11128,9 @@ opencv_type_boxed! { MatExpr }
 impl Drop for MatExpr {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MatExpr_delete(instance: *mut c_void); }
-		unsafe { cv_MatExpr_delete(self.as_raw_mut_MatExpr()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MatExpr_delete(self.as_raw_mut_MatExpr()) };
+		}
 	}
 }
 
@@ -11294,7 +11320,9 @@ opencv_type_boxed! { MatSize }
 impl Drop for MatSize {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MatSize_delete(instance: *mut c_void); }
-		unsafe { cv_MatSize_delete(self.as_raw_mut_MatSize()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MatSize_delete(self.as_raw_mut_MatSize()) };
+		}
 	}
 }
 
@@ -11357,7 +11385,9 @@ opencv_type_boxed! { MatStep }
 impl Drop for MatStep {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MatStep_delete(instance: *mut c_void); }
-		unsafe { cv_MatStep_delete(self.as_raw_mut_MatStep()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MatStep_delete(self.as_raw_mut_MatStep()) };
+		}
 	}
 }
 
@@ -11402,7 +11432,9 @@ opencv_type_boxed! { Matx_AddOp }
 impl Drop for Matx_AddOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_AddOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_AddOp_delete(self.as_raw_mut_Matx_AddOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_AddOp_delete(self.as_raw_mut_Matx_AddOp()) };
+		}
 	}
 }
 
@@ -11444,7 +11476,9 @@ opencv_type_boxed! { Matx_DivOp }
 impl Drop for Matx_DivOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_DivOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_DivOp_delete(self.as_raw_mut_Matx_DivOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_DivOp_delete(self.as_raw_mut_Matx_DivOp()) };
+		}
 	}
 }
 
@@ -11486,7 +11520,9 @@ opencv_type_boxed! { Matx_MatMulOp }
 impl Drop for Matx_MatMulOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_MatMulOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_MatMulOp_delete(self.as_raw_mut_Matx_MatMulOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_MatMulOp_delete(self.as_raw_mut_Matx_MatMulOp()) };
+		}
 	}
 }
 
@@ -11528,7 +11564,9 @@ opencv_type_boxed! { Matx_MulOp }
 impl Drop for Matx_MulOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_MulOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_MulOp_delete(self.as_raw_mut_Matx_MulOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_MulOp_delete(self.as_raw_mut_Matx_MulOp()) };
+		}
 	}
 }
 
@@ -11570,7 +11608,9 @@ opencv_type_boxed! { Matx_ScaleOp }
 impl Drop for Matx_ScaleOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_ScaleOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_ScaleOp_delete(self.as_raw_mut_Matx_ScaleOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_ScaleOp_delete(self.as_raw_mut_Matx_ScaleOp()) };
+		}
 	}
 }
 
@@ -11612,7 +11652,9 @@ opencv_type_boxed! { Matx_SubOp }
 impl Drop for Matx_SubOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_SubOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_SubOp_delete(self.as_raw_mut_Matx_SubOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_SubOp_delete(self.as_raw_mut_Matx_SubOp()) };
+		}
 	}
 }
 
@@ -11654,7 +11696,9 @@ opencv_type_boxed! { Matx_TOp }
 impl Drop for Matx_TOp {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Matx_TOp_delete(instance: *mut c_void); }
-		unsafe { cv_Matx_TOp_delete(self.as_raw_mut_Matx_TOp()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Matx_TOp_delete(self.as_raw_mut_Matx_TOp()) };
+		}
 	}
 }
 
@@ -12161,7 +12205,9 @@ opencv_type_boxed! { PCA }
 impl Drop for PCA {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PCA_delete(instance: *mut c_void); }
-		unsafe { cv_PCA_delete(self.as_raw_mut_PCA()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PCA_delete(self.as_raw_mut_PCA()) };
+		}
 	}
 }
 
@@ -12514,7 +12560,9 @@ opencv_type_boxed! { RNG }
 impl Drop for RNG {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RNG_delete(instance: *mut c_void); }
-		unsafe { cv_RNG_delete(self.as_raw_mut_RNG()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RNG_delete(self.as_raw_mut_RNG()) };
+		}
 	}
 }
 
@@ -12622,7 +12670,9 @@ opencv_type_boxed! { RNG_MT19937 }
 impl Drop for RNG_MT19937 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RNG_MT19937_delete(instance: *mut c_void); }
-		unsafe { cv_RNG_MT19937_delete(self.as_raw_mut_RNG_MT19937()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RNG_MT19937_delete(self.as_raw_mut_RNG_MT19937()) };
+		}
 	}
 }
 
@@ -12734,7 +12784,9 @@ opencv_type_boxed! { Range }
 impl Drop for Range {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Range_delete(instance: *mut c_void); }
-		unsafe { cv_Range_delete(self.as_raw_mut_Range()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Range_delete(self.as_raw_mut_Range()) };
+		}
 	}
 }
 
@@ -12848,7 +12900,9 @@ opencv_type_boxed! { RotatedRect }
 impl Drop for RotatedRect {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RotatedRect_delete(instance: *mut c_void); }
-		unsafe { cv_RotatedRect_delete(self.as_raw_mut_RotatedRect()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RotatedRect_delete(self.as_raw_mut_RotatedRect()) };
+		}
 	}
 }
 
@@ -12982,7 +13036,9 @@ opencv_type_boxed! { SVD }
 impl Drop for SVD {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SVD_delete(instance: *mut c_void); }
-		unsafe { cv_SVD_delete(self.as_raw_mut_SVD()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SVD_delete(self.as_raw_mut_SVD()) };
+		}
 	}
 }
 
@@ -13537,7 +13593,9 @@ opencv_type_boxed! { SparseMat }
 impl Drop for SparseMat {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparseMat_delete(instance: *mut c_void); }
-		unsafe { cv_SparseMat_delete(self.as_raw_mut_SparseMat()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparseMat_delete(self.as_raw_mut_SparseMat()) };
+		}
 	}
 }
 
@@ -13692,7 +13750,9 @@ opencv_type_boxed! { SparseMat_Hdr }
 impl Drop for SparseMat_Hdr {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparseMat_Hdr_delete(instance: *mut c_void); }
-		unsafe { cv_SparseMat_Hdr_delete(self.as_raw_mut_SparseMat_Hdr()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparseMat_Hdr_delete(self.as_raw_mut_SparseMat_Hdr()) };
+		}
 	}
 }
 
@@ -13757,7 +13817,9 @@ opencv_type_boxed! { SparseMat_Node }
 impl Drop for SparseMat_Node {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparseMat_Node_delete(instance: *mut c_void); }
-		unsafe { cv_SparseMat_Node_delete(self.as_raw_mut_SparseMat_Node()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparseMat_Node_delete(self.as_raw_mut_SparseMat_Node()) };
+		}
 	}
 }
 
@@ -13843,7 +13905,9 @@ opencv_type_boxed! { SparseMatConstIterator }
 impl Drop for SparseMatConstIterator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparseMatConstIterator_delete(instance: *mut c_void); }
-		unsafe { cv_SparseMatConstIterator_delete(self.as_raw_mut_SparseMatConstIterator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparseMatConstIterator_delete(self.as_raw_mut_SparseMatConstIterator()) };
+		}
 	}
 }
 
@@ -13905,7 +13969,9 @@ opencv_type_boxed! { SparseMatIterator }
 impl Drop for SparseMatIterator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparseMatIterator_delete(instance: *mut c_void); }
-		unsafe { cv_SparseMatIterator_delete(self.as_raw_mut_SparseMatIterator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparseMatIterator_delete(self.as_raw_mut_SparseMatIterator()) };
+		}
 	}
 }
 
@@ -13990,7 +14056,7 @@ impl TermCriteria {
 		unsafe { sys::cv_TermCriteria_TermCriteria_int_int_double(typ, max_count, epsilon) }.into_result()
 	}
 	
-	pub fn is_valid(self) -> Result<bool> {
+	pub fn is_valid(&self) -> Result<bool> {
 		unsafe { sys::cv_TermCriteria_isValid_const(self.opencv_as_extern()) }.into_result()
 	}
 	
@@ -14086,7 +14152,9 @@ opencv_type_boxed! { TickMeter }
 impl Drop for TickMeter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TickMeter_delete(instance: *mut c_void); }
-		unsafe { cv_TickMeter_delete(self.as_raw_mut_TickMeter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TickMeter_delete(self.as_raw_mut_TickMeter()) };
+		}
 	}
 }
 
@@ -14463,7 +14531,9 @@ opencv_type_boxed! { UMat }
 impl Drop for UMat {
 	fn drop(&mut self) {
 		extern "C" { fn cv_UMat_delete(instance: *mut c_void); }
-		unsafe { cv_UMat_delete(self.as_raw_mut_UMat()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_UMat_delete(self.as_raw_mut_UMat()) };
+		}
 	}
 }
 
@@ -14750,7 +14820,9 @@ opencv_type_boxed! { UMatData }
 impl Drop for UMatData {
 	fn drop(&mut self) {
 		extern "C" { fn cv_UMatData_delete(instance: *mut c_void); }
-		unsafe { cv_UMatData_delete(self.as_raw_mut_UMatData()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_UMatData_delete(self.as_raw_mut_UMatData()) };
+		}
 	}
 }
 
@@ -15140,7 +15212,9 @@ opencv_type_boxed! { _InputArray }
 impl Drop for _InputArray {
 	fn drop(&mut self) {
 		extern "C" { fn cv__InputArray_delete(instance: *mut c_void); }
-		unsafe { cv__InputArray_delete(self.as_raw_mut__InputArray()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv__InputArray_delete(self.as_raw_mut__InputArray()) };
+		}
 	}
 }
 
@@ -15226,7 +15300,9 @@ opencv_type_boxed! { _InputOutputArray }
 impl Drop for _InputOutputArray {
 	fn drop(&mut self) {
 		extern "C" { fn cv__InputOutputArray_delete(instance: *mut c_void); }
-		unsafe { cv__InputOutputArray_delete(self.as_raw_mut__InputOutputArray()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv__InputOutputArray_delete(self.as_raw_mut__InputOutputArray()) };
+		}
 	}
 }
 
@@ -15498,7 +15574,9 @@ opencv_type_boxed! { _OutputArray }
 impl Drop for _OutputArray {
 	fn drop(&mut self) {
 		extern "C" { fn cv__OutputArray_delete(instance: *mut c_void); }
-		unsafe { cv__OutputArray_delete(self.as_raw_mut__OutputArray()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv__OutputArray_delete(self.as_raw_mut__OutputArray()) };
+		}
 	}
 }
 
@@ -15867,7 +15945,9 @@ opencv_type_boxed! { BufferPool }
 impl Drop for BufferPool {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BufferPool_delete(instance: *mut c_void); }
-		unsafe { cv_BufferPool_delete(self.as_raw_mut_BufferPool()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BufferPool_delete(self.as_raw_mut_BufferPool()) };
+		}
 	}
 }
 
@@ -16204,7 +16284,9 @@ opencv_type_boxed! { DeviceInfo }
 impl Drop for DeviceInfo {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DeviceInfo_delete(instance: *mut c_void); }
-		unsafe { cv_DeviceInfo_delete(self.as_raw_mut_DeviceInfo()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DeviceInfo_delete(self.as_raw_mut_DeviceInfo()) };
+		}
 	}
 }
 
@@ -16272,7 +16354,9 @@ opencv_type_boxed! { Event }
 impl Drop for Event {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Event_delete(instance: *mut c_void); }
-		unsafe { cv_Event_delete(self.as_raw_mut_Event()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Event_delete(self.as_raw_mut_Event()) };
+		}
 	}
 }
 
@@ -16333,7 +16417,9 @@ opencv_type_boxed! { GpuData }
 impl Drop for GpuData {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GpuData_delete(instance: *mut c_void); }
-		unsafe { cv_GpuData_delete(self.as_raw_mut_GpuData()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GpuData_delete(self.as_raw_mut_GpuData()) };
+		}
 	}
 }
 
@@ -16798,7 +16884,9 @@ opencv_type_boxed! { GpuMat }
 impl Drop for GpuMat {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GpuMat_delete(instance: *mut c_void); }
-		unsafe { cv_GpuMat_delete(self.as_raw_mut_GpuMat()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GpuMat_delete(self.as_raw_mut_GpuMat()) };
+		}
 	}
 }
 
@@ -17132,7 +17220,9 @@ opencv_type_boxed! { GpuMatND }
 impl Drop for GpuMatND {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GpuMatND_delete(instance: *mut c_void); }
-		unsafe { cv_GpuMatND_delete(self.as_raw_mut_GpuMatND()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GpuMatND_delete(self.as_raw_mut_GpuMatND()) };
+		}
 	}
 }
 
@@ -17403,7 +17493,9 @@ opencv_type_boxed! { HostMem }
 impl Drop for HostMem {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HostMem_delete(instance: *mut c_void); }
-		unsafe { cv_HostMem_delete(self.as_raw_mut_HostMem()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_HostMem_delete(self.as_raw_mut_HostMem()) };
+		}
 	}
 }
 
@@ -17570,7 +17662,9 @@ opencv_type_boxed! { Stream }
 impl Drop for Stream {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Stream_delete(instance: *mut c_void); }
-		unsafe { cv_Stream_delete(self.as_raw_mut_Stream()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Stream_delete(self.as_raw_mut_Stream()) };
+		}
 	}
 }
 
@@ -17644,7 +17738,9 @@ opencv_type_boxed! { TargetArchs }
 impl Drop for TargetArchs {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TargetArchs_delete(instance: *mut c_void); }
-		unsafe { cv_TargetArchs_delete(self.as_raw_mut_TargetArchs()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TargetArchs_delete(self.as_raw_mut_TargetArchs()) };
+		}
 	}
 }
 
@@ -17756,7 +17852,9 @@ opencv_type_boxed! { Detail_CheckContext }
 impl Drop for Detail_CheckContext {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CheckContext_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CheckContext_delete(self.as_raw_mut_Detail_CheckContext()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CheckContext_delete(self.as_raw_mut_Detail_CheckContext()) };
+		}
 	}
 }
 
@@ -17883,7 +17981,9 @@ opencv_type_boxed! { NodeData }
 impl Drop for NodeData {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NodeData_delete(instance: *mut c_void); }
-		unsafe { cv_NodeData_delete(self.as_raw_mut_NodeData()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NodeData_delete(self.as_raw_mut_NodeData()) };
+		}
 	}
 }
 
@@ -17935,7 +18035,9 @@ opencv_type_boxed! { WriteStructContext }
 impl Drop for WriteStructContext {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WriteStructContext_delete(instance: *mut c_void); }
-		unsafe { cv_WriteStructContext_delete(self.as_raw_mut_WriteStructContext()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WriteStructContext_delete(self.as_raw_mut_WriteStructContext()) };
+		}
 	}
 }
 
@@ -18029,7 +18131,9 @@ opencv_type_boxed! { Context }
 impl Drop for Context {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Context_delete(instance: *mut c_void); }
-		unsafe { cv_Context_delete(self.as_raw_mut_Context()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Context_delete(self.as_raw_mut_Context()) };
+		}
 	}
 }
 
@@ -18406,7 +18510,9 @@ opencv_type_boxed! { Device }
 impl Drop for Device {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Device_delete(instance: *mut c_void); }
-		unsafe { cv_Device_delete(self.as_raw_mut_Device()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Device_delete(self.as_raw_mut_Device()) };
+		}
 	}
 }
 
@@ -18470,7 +18576,9 @@ opencv_type_boxed! { Image2D }
 impl Drop for Image2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Image2D_delete(instance: *mut c_void); }
-		unsafe { cv_Image2D_delete(self.as_raw_mut_Image2D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Image2D_delete(self.as_raw_mut_Image2D()) };
+		}
 	}
 }
 
@@ -18630,7 +18738,9 @@ opencv_type_boxed! { Kernel }
 impl Drop for Kernel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Kernel_delete(instance: *mut c_void); }
-		unsafe { cv_Kernel_delete(self.as_raw_mut_Kernel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Kernel_delete(self.as_raw_mut_Kernel()) };
+		}
 	}
 }
 
@@ -18737,7 +18847,9 @@ opencv_type_boxed! { KernelArg }
 impl Drop for KernelArg {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KernelArg_delete(instance: *mut c_void); }
-		unsafe { cv_KernelArg_delete(self.as_raw_mut_KernelArg()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KernelArg_delete(self.as_raw_mut_KernelArg()) };
+		}
 	}
 }
 
@@ -18906,7 +19018,9 @@ opencv_type_boxed! { OpenCLExecutionContext }
 impl Drop for OpenCLExecutionContext {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OpenCLExecutionContext_delete(instance: *mut c_void); }
-		unsafe { cv_OpenCLExecutionContext_delete(self.as_raw_mut_OpenCLExecutionContext()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OpenCLExecutionContext_delete(self.as_raw_mut_OpenCLExecutionContext()) };
+		}
 	}
 }
 
@@ -19016,7 +19130,9 @@ opencv_type_boxed! { Platform }
 impl Drop for Platform {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Platform_delete(instance: *mut c_void); }
-		unsafe { cv_Platform_delete(self.as_raw_mut_Platform()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Platform_delete(self.as_raw_mut_Platform()) };
+		}
 	}
 }
 
@@ -19100,7 +19216,9 @@ opencv_type_boxed! { PlatformInfo }
 impl Drop for PlatformInfo {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PlatformInfo_delete(instance: *mut c_void); }
-		unsafe { cv_PlatformInfo_delete(self.as_raw_mut_PlatformInfo()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PlatformInfo_delete(self.as_raw_mut_PlatformInfo()) };
+		}
 	}
 }
 
@@ -19201,7 +19319,9 @@ opencv_type_boxed! { Program }
 impl Drop for Program {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Program_delete(instance: *mut c_void); }
-		unsafe { cv_Program_delete(self.as_raw_mut_Program()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Program_delete(self.as_raw_mut_Program()) };
+		}
 	}
 }
 
@@ -19272,7 +19392,9 @@ opencv_type_boxed! { ProgramSource }
 impl Drop for ProgramSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ProgramSource_delete(instance: *mut c_void); }
-		unsafe { cv_ProgramSource_delete(self.as_raw_mut_ProgramSource()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ProgramSource_delete(self.as_raw_mut_ProgramSource()) };
+		}
 	}
 }
 
@@ -19412,7 +19534,9 @@ opencv_type_boxed! { Queue }
 impl Drop for Queue {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Queue_delete(instance: *mut c_void); }
-		unsafe { cv_Queue_delete(self.as_raw_mut_Queue()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Queue_delete(self.as_raw_mut_Queue()) };
+		}
 	}
 }
 
@@ -19480,7 +19604,9 @@ opencv_type_boxed! { Timer }
 impl Drop for Timer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Timer_delete(instance: *mut c_void); }
-		unsafe { cv_Timer_delete(self.as_raw_mut_Timer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Timer_delete(self.as_raw_mut_Timer()) };
+		}
 	}
 }
 
@@ -19602,7 +19728,9 @@ opencv_type_boxed! { Arrays }
 impl Drop for Arrays {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Arrays_delete(instance: *mut c_void); }
-		unsafe { cv_Arrays_delete(self.as_raw_mut_Arrays()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Arrays_delete(self.as_raw_mut_Arrays()) };
+		}
 	}
 }
 
@@ -19879,7 +20007,9 @@ opencv_type_boxed! { Buffer }
 impl Drop for Buffer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Buffer_delete(instance: *mut c_void); }
-		unsafe { cv_Buffer_delete(self.as_raw_mut_Buffer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Buffer_delete(self.as_raw_mut_Buffer()) };
+		}
 	}
 }
 
@@ -20145,7 +20275,9 @@ opencv_type_boxed! { Texture2D }
 impl Drop for Texture2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Texture2D_delete(instance: *mut c_void); }
-		unsafe { cv_Texture2D_delete(self.as_raw_mut_Texture2D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Texture2D_delete(self.as_raw_mut_Texture2D()) };
+		}
 	}
 }
 
@@ -20280,7 +20412,9 @@ opencv_type_boxed! { LogTag }
 impl Drop for LogTag {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LogTag_delete(instance: *mut c_void); }
-		unsafe { cv_LogTag_delete(self.as_raw_mut_LogTag()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LogTag_delete(self.as_raw_mut_LogTag()) };
+		}
 	}
 }
 
@@ -513,7 +513,9 @@ opencv_type_boxed! { EncoderParams }
 impl Drop for EncoderParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_EncoderParams_delete(instance: *mut c_void); }
-		unsafe { cv_EncoderParams_delete(self.as_raw_mut_EncoderParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_EncoderParams_delete(self.as_raw_mut_EncoderParams()) };
+		}
 	}
 }
 
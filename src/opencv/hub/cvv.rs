@@ -88,7 +88,9 @@ opencv_type_boxed! { CallMetaData }
 impl Drop for CallMetaData {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CallMetaData_delete(instance: *mut c_void); }
-		unsafe { cv_CallMetaData_delete(self.as_raw_mut_CallMetaData()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CallMetaData_delete(self.as_raw_mut_CallMetaData()) };
+		}
 	}
 }
 
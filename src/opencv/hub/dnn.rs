@@ -767,7 +767,9 @@ opencv_type_boxed! { AccumLayer }
 impl Drop for AccumLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AccumLayer_delete(instance: *mut c_void); }
-		unsafe { cv_AccumLayer_delete(self.as_raw_mut_AccumLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_AccumLayer_delete(self.as_raw_mut_AccumLayer()) };
+		}
 	}
 }
 
@@ -849,7 +851,9 @@ opencv_type_boxed! { BackendNode }
 impl Drop for BackendNode {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BackendNode_delete(instance: *mut c_void); }
-		unsafe { cv_BackendNode_delete(self.as_raw_mut_BackendNode()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BackendNode_delete(self.as_raw_mut_BackendNode()) };
+		}
 	}
 }
 
@@ -1025,7 +1029,9 @@ opencv_type_boxed! { BaseConvolutionLayer }
 impl Drop for BaseConvolutionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BaseConvolutionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_BaseConvolutionLayer_delete(self.as_raw_mut_BaseConvolutionLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BaseConvolutionLayer_delete(self.as_raw_mut_BaseConvolutionLayer()) };
+		}
 	}
 }
 
@@ -1143,7 +1149,9 @@ opencv_type_boxed! { BlankLayer }
 impl Drop for BlankLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BlankLayer_delete(instance: *mut c_void); }
-		unsafe { cv_BlankLayer_delete(self.as_raw_mut_BlankLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BlankLayer_delete(self.as_raw_mut_BlankLayer()) };
+		}
 	}
 }
 
@@ -1223,7 +1231,9 @@ opencv_type_boxed! { ClassificationModel }
 impl Drop for ClassificationModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ClassificationModel_delete(instance: *mut c_void); }
-		unsafe { cv_ClassificationModel_delete(self.as_raw_mut_ClassificationModel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ClassificationModel_delete(self.as_raw_mut_ClassificationModel()) };
+		}
 	}
 }
 
@@ -1307,7 +1317,9 @@ opencv_type_boxed! { ConcatLayer }
 impl Drop for ConcatLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConcatLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ConcatLayer_delete(self.as_raw_mut_ConcatLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ConcatLayer_delete(self.as_raw_mut_ConcatLayer()) };
+		}
 	}
 }
 
@@ -1357,7 +1369,9 @@ opencv_type_boxed! { ConstLayer }
 impl Drop for ConstLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConstLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ConstLayer_delete(self.as_raw_mut_ConstLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ConstLayer_delete(self.as_raw_mut_ConstLayer()) };
+		}
 	}
 }
 
@@ -1405,7 +1419,9 @@ opencv_type_boxed! { ConvolutionLayer }
 impl Drop for ConvolutionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConvolutionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ConvolutionLayer_delete(self.as_raw_mut_ConvolutionLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ConvolutionLayer_delete(self.as_raw_mut_ConvolutionLayer()) };
+		}
 	}
 }
 
@@ -1458,7 +1474,9 @@ opencv_type_boxed! { CorrelationLayer }
 impl Drop for CorrelationLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CorrelationLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CorrelationLayer_delete(self.as_raw_mut_CorrelationLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CorrelationLayer_delete(self.as_raw_mut_CorrelationLayer()) };
+		}
 	}
 }
 
@@ -1506,7 +1524,9 @@ opencv_type_boxed! { CropAndResizeLayer }
 impl Drop for CropAndResizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CropAndResizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CropAndResizeLayer_delete(self.as_raw_mut_CropAndResizeLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CropAndResizeLayer_delete(self.as_raw_mut_CropAndResizeLayer()) };
+		}
 	}
 }
 
@@ -1554,7 +1574,9 @@ opencv_type_boxed! { CropLayer }
 impl Drop for CropLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CropLayer_delete(instance: *mut c_void); }
-		unsafe { cv_CropLayer_delete(self.as_raw_mut_CropLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CropLayer_delete(self.as_raw_mut_CropLayer()) };
+		}
 	}
 }
 
@@ -1602,7 +1624,9 @@ opencv_type_boxed! { DataAugmentationLayer }
 impl Drop for DataAugmentationLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DataAugmentationLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DataAugmentationLayer_delete(self.as_raw_mut_DataAugmentationLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DataAugmentationLayer_delete(self.as_raw_mut_DataAugmentationLayer()) };
+		}
 	}
 }
 
@@ -1650,7 +1674,9 @@ opencv_type_boxed! { DeconvolutionLayer }
 impl Drop for DeconvolutionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DeconvolutionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DeconvolutionLayer_delete(self.as_raw_mut_DeconvolutionLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DeconvolutionLayer_delete(self.as_raw_mut_DeconvolutionLayer()) };
+		}
 	}
 }
 
@@ -1747,7 +1773,9 @@ opencv_type_boxed! { DetectionModel }
 impl Drop for DetectionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionModel_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionModel_delete(self.as_raw_mut_DetectionModel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectionModel_delete(self.as_raw_mut_DetectionModel()) };
+		}
 	}
 }
 
@@ -1823,7 +1851,9 @@ opencv_type_boxed! { DetectionOutputLayer }
 impl Drop for DetectionOutputLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionOutputLayer_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionOutputLayer_delete(self.as_raw_mut_DetectionOutputLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectionOutputLayer_delete(self.as_raw_mut_DetectionOutputLayer()) };
+		}
 	}
 }
 
@@ -1930,7 +1960,9 @@ opencv_type_boxed! { Dict }
 impl Drop for Dict {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Dict_delete(instance: *mut c_void); }
-		unsafe { cv_Dict_delete(self.as_raw_mut_Dict()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Dict_delete(self.as_raw_mut_Dict()) };
+		}
 	}
 }
 
@@ -2026,7 +2058,9 @@ opencv_type_boxed! { DictValue }
 impl Drop for DictValue {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DictValue_delete(instance: *mut c_void); }
-		unsafe { cv_DictValue_delete(self.as_raw_mut_DictValue()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DictValue_delete(self.as_raw_mut_DictValue()) };
+		}
 	}
 }
 
@@ -2115,7 +2149,9 @@ opencv_type_boxed! { EltwiseLayer }
 impl Drop for EltwiseLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_EltwiseLayer_delete(instance: *mut c_void); }
-		unsafe { cv_EltwiseLayer_delete(self.as_raw_mut_EltwiseLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_EltwiseLayer_delete(self.as_raw_mut_EltwiseLayer()) };
+		}
 	}
 }
 
@@ -2199,7 +2235,9 @@ opencv_type_boxed! { FlattenLayer }
 impl Drop for FlattenLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FlattenLayer_delete(instance: *mut c_void); }
-		unsafe { cv_FlattenLayer_delete(self.as_raw_mut_FlattenLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FlattenLayer_delete(self.as_raw_mut_FlattenLayer()) };
+		}
 	}
 }
 
@@ -2247,7 +2285,9 @@ opencv_type_boxed! { FlowWarpLayer }
 impl Drop for FlowWarpLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FlowWarpLayer_delete(instance: *mut c_void); }
-		unsafe { cv_FlowWarpLayer_delete(self.as_raw_mut_FlowWarpLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FlowWarpLayer_delete(self.as_raw_mut_FlowWarpLayer()) };
+		}
 	}
 }
 
@@ -2303,7 +2343,9 @@ opencv_type_boxed! { InnerProductLayer }
 impl Drop for InnerProductLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InnerProductLayer_delete(instance: *mut c_void); }
-		unsafe { cv_InnerProductLayer_delete(self.as_raw_mut_InnerProductLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_InnerProductLayer_delete(self.as_raw_mut_InnerProductLayer()) };
+		}
 	}
 }
 
@@ -2357,7 +2399,9 @@ opencv_type_boxed! { InterpLayer }
 impl Drop for InterpLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InterpLayer_delete(instance: *mut c_void); }
-		unsafe { cv_InterpLayer_delete(self.as_raw_mut_InterpLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_InterpLayer_delete(self.as_raw_mut_InterpLayer()) };
+		}
 	}
 }
 
@@ -2429,7 +2473,9 @@ opencv_type_boxed! { KeypointsModel }
 impl Drop for KeypointsModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeypointsModel_delete(instance: *mut c_void); }
-		unsafe { cv_KeypointsModel_delete(self.as_raw_mut_KeypointsModel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KeypointsModel_delete(self.as_raw_mut_KeypointsModel()) };
+		}
 	}
 }
 
@@ -2537,7 +2583,9 @@ opencv_type_boxed! { LRNLayer }
 impl Drop for LRNLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LRNLayer_delete(instance: *mut c_void); }
-		unsafe { cv_LRNLayer_delete(self.as_raw_mut_LRNLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LRNLayer_delete(self.as_raw_mut_LRNLayer()) };
+		}
 	}
 }
 
@@ -2976,7 +3024,9 @@ opencv_type_boxed! { Layer }
 impl Drop for Layer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Layer_delete(instance: *mut c_void); }
-		unsafe { cv_Layer_delete(self.as_raw_mut_Layer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Layer_delete(self.as_raw_mut_Layer()) };
+		}
 	}
 }
 
@@ -3025,7 +3075,9 @@ opencv_type_boxed! { LayerFactory }
 impl Drop for LayerFactory {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LayerFactory_delete(instance: *mut c_void); }
-		unsafe { cv_LayerFactory_delete(self.as_raw_mut_LayerFactory()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LayerFactory_delete(self.as_raw_mut_LayerFactory()) };
+		}
 	}
 }
 
@@ -3122,7 +3174,9 @@ opencv_type_boxed! { LayerParams }
 impl Drop for LayerParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LayerParams_delete(instance: *mut c_void); }
-		unsafe { cv_LayerParams_delete(self.as_raw_mut_LayerParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LayerParams_delete(self.as_raw_mut_LayerParams()) };
+		}
 	}
 }
 
@@ -3185,7 +3239,9 @@ opencv_type_boxed! { MVNLayer }
 impl Drop for MVNLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MVNLayer_delete(instance: *mut c_void); }
-		unsafe { cv_MVNLayer_delete(self.as_raw_mut_MVNLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MVNLayer_delete(self.as_raw_mut_MVNLayer()) };
+		}
 	}
 }
 
@@ -3257,7 +3313,9 @@ opencv_type_boxed! { MaxUnpoolLayer }
 impl Drop for MaxUnpoolLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MaxUnpoolLayer_delete(instance: *mut c_void); }
-		unsafe { cv_MaxUnpoolLayer_delete(self.as_raw_mut_MaxUnpoolLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MaxUnpoolLayer_delete(self.as_raw_mut_MaxUnpoolLayer()) };
+		}
 	}
 }
 
@@ -3427,7 +3485,9 @@ opencv_type_boxed! { Model }
 impl Drop for Model {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Model_delete(instance: *mut c_void); }
-		unsafe { cv_Model_delete(self.as_raw_mut_Model()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Model_delete(self.as_raw_mut_Model()) };
+		}
 	}
 }
 
@@ -3997,7 +4057,9 @@ opencv_type_boxed! { Net }
 impl Drop for Net {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Net_delete(instance: *mut c_void); }
-		unsafe { cv_Net_delete(self.as_raw_mut_Net()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Net_delete(self.as_raw_mut_Net()) };
+		}
 	}
 }
 
@@ -4141,7 +4203,9 @@ opencv_type_boxed! { NormalizeBBoxLayer }
 impl Drop for NormalizeBBoxLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NormalizeBBoxLayer_delete(instance: *mut c_void); }
-		unsafe { cv_NormalizeBBoxLayer_delete(self.as_raw_mut_NormalizeBBoxLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NormalizeBBoxLayer_delete(self.as_raw_mut_NormalizeBBoxLayer()) };
+		}
 	}
 }
 
@@ -4229,7 +4293,9 @@ opencv_type_boxed! { PaddingLayer }
 impl Drop for PaddingLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PaddingLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PaddingLayer_delete(self.as_raw_mut_PaddingLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PaddingLayer_delete(self.as_raw_mut_PaddingLayer()) };
+		}
 	}
 }
 
@@ -4277,7 +4343,9 @@ opencv_type_boxed! { PermuteLayer }
 impl Drop for PermuteLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PermuteLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PermuteLayer_delete(self.as_raw_mut_PermuteLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PermuteLayer_delete(self.as_raw_mut_PermuteLayer()) };
+		}
 	}
 }
 
@@ -4440,7 +4508,9 @@ opencv_type_boxed! { PoolingLayer }
 impl Drop for PoolingLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PoolingLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PoolingLayer_delete(self.as_raw_mut_PoolingLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PoolingLayer_delete(self.as_raw_mut_PoolingLayer()) };
+		}
 	}
 }
 
@@ -4524,7 +4594,9 @@ opencv_type_boxed! { PriorBoxLayer }
 impl Drop for PriorBoxLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PriorBoxLayer_delete(instance: *mut c_void); }
-		unsafe { cv_PriorBoxLayer_delete(self.as_raw_mut_PriorBoxLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PriorBoxLayer_delete(self.as_raw_mut_PriorBoxLayer()) };
+		}
 	}
 }
 
@@ -4572,7 +4644,9 @@ opencv_type_boxed! { ProposalLayer }
 impl Drop for ProposalLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ProposalLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ProposalLayer_delete(self.as_raw_mut_ProposalLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ProposalLayer_delete(self.as_raw_mut_ProposalLayer()) };
+		}
 	}
 }
 
@@ -4728,7 +4802,9 @@ opencv_type_boxed! { RegionLayer }
 impl Drop for RegionLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RegionLayer_delete(instance: *mut c_void); }
-		unsafe { cv_RegionLayer_delete(self.as_raw_mut_RegionLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RegionLayer_delete(self.as_raw_mut_RegionLayer()) };
+		}
 	}
 }
 
@@ -4776,7 +4852,9 @@ opencv_type_boxed! { ReorgLayer }
 impl Drop for ReorgLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ReorgLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ReorgLayer_delete(self.as_raw_mut_ReorgLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ReorgLayer_delete(self.as_raw_mut_ReorgLayer()) };
+		}
 	}
 }
 
@@ -4840,7 +4918,9 @@ opencv_type_boxed! { ReshapeLayer }
 impl Drop for ReshapeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ReshapeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ReshapeLayer_delete(self.as_raw_mut_ReshapeLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ReshapeLayer_delete(self.as_raw_mut_ReshapeLayer()) };
+		}
 	}
 }
 
@@ -4894,7 +4974,9 @@ opencv_type_boxed! { ResizeLayer }
 impl Drop for ResizeLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ResizeLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ResizeLayer_delete(self.as_raw_mut_ResizeLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ResizeLayer_delete(self.as_raw_mut_ResizeLayer()) };
+		}
 	}
 }
 
@@ -4958,7 +5040,9 @@ opencv_type_boxed! { ScaleLayer }
 impl Drop for ScaleLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ScaleLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ScaleLayer_delete(self.as_raw_mut_ScaleLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ScaleLayer_delete(self.as_raw_mut_ScaleLayer()) };
+		}
 	}
 }
 
@@ -5026,7 +5110,9 @@ opencv_type_boxed! { SegmentationModel }
 impl Drop for SegmentationModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SegmentationModel_delete(instance: *mut c_void); }
-		unsafe { cv_SegmentationModel_delete(self.as_raw_mut_SegmentationModel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SegmentationModel_delete(self.as_raw_mut_SegmentationModel()) };
+		}
 	}
 }
 
@@ -5086,7 +5172,9 @@ opencv_type_boxed! { ShiftLayer }
 impl Drop for ShiftLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ShiftLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ShiftLayer_delete(self.as_raw_mut_ShiftLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ShiftLayer_delete(self.as_raw_mut_ShiftLayer()) };
+		}
 	}
 }
 
@@ -5158,7 +5246,9 @@ opencv_type_boxed! { ShuffleChannelLayer }
 impl Drop for ShuffleChannelLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ShuffleChannelLayer_delete(instance: *mut c_void); }
-		unsafe { cv_ShuffleChannelLayer_delete(self.as_raw_mut_ShuffleChannelLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ShuffleChannelLayer_delete(self.as_raw_mut_ShuffleChannelLayer()) };
+		}
 	}
 }
 
@@ -5304,7 +5394,9 @@ opencv_type_boxed! { SliceLayer }
 impl Drop for SliceLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SliceLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SliceLayer_delete(self.as_raw_mut_SliceLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SliceLayer_delete(self.as_raw_mut_SliceLayer()) };
+		}
 	}
 }
 
@@ -5360,7 +5452,9 @@ opencv_type_boxed! { SoftmaxLayer }
 impl Drop for SoftmaxLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SoftmaxLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SoftmaxLayer_delete(self.as_raw_mut_SoftmaxLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SoftmaxLayer_delete(self.as_raw_mut_SoftmaxLayer()) };
+		}
 	}
 }
 
@@ -5418,7 +5512,9 @@ opencv_type_boxed! { SplitLayer }
 impl Drop for SplitLayer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SplitLayer_delete(instance: *mut c_void); }
-		unsafe { cv_SplitLayer_delete(self.as_raw_mut_SplitLayer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SplitLayer_delete(self.as_raw_mut_SplitLayer()) };
+		}
 	}
 }
 
@@ -5504,7 +5600,7 @@ impl TextDetectionModel {
 	/// * frame: The input image
 	/// * detections:[out] array with detections' quadrangles (4 points per result)
 	/// * confidences:[out] array with detection confidences
-	pub fn detect_with_confidences(self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::Vector::<core::Point>>, confidences: &mut core::Vector::<f32>) -> Result<()> {
+	pub fn detect_with_confidences(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::Vector::<core::Point>>, confidences: &mut core::Vector::<f32>) -> Result<()> {
 		input_array_arg!(frame);
 		unsafe { sys::cv_dnn_TextDetectionModel_detect_const_const__InputArrayR_vector_vector_Point__R_vector_float_R(self.opencv_as_extern(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfVectorOfPoint(), confidences.as_raw_mut_VectorOff32()) }.into_result()
 	}
@@ -5530,7 +5626,7 @@ impl TextDetectionModel {
 	/// * confidences:[out] array with detection confidences
 	/// 
 	/// ## Overloaded parameters
-	pub fn detect(self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::Vector::<core::Point>>) -> Result<()> {
+	pub fn detect(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::Vector::<core::Point>>) -> Result<()> {
 		input_array_arg!(frame);
 		unsafe { sys::cv_dnn_TextDetectionModel_detect_const_const__InputArrayR_vector_vector_Point__R(self.opencv_as_extern(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfVectorOfPoint()) }.into_result()
 	}
@@ -5548,7 +5644,7 @@ impl TextDetectionModel {
 	/// * frame: the input image
 	/// * detections:[out] array with detections' RotationRect results
 	/// * confidences:[out] array with detection confidences
-	pub fn detect_text_rectangles(self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::RotatedRect>, confidences: &mut core::Vector::<f32>) -> Result<()> {
+	pub fn detect_text_rectangles(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::RotatedRect>, confidences: &mut core::Vector::<f32>) -> Result<()> {
 		input_array_arg!(frame);
 		unsafe { sys::cv_dnn_TextDetectionModel_detectTextRectangles_const_const__InputArrayR_vector_RotatedRect_R_vector_float_R(self.opencv_as_extern(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfRotatedRect(), confidences.as_raw_mut_VectorOff32()) }.into_result()
 	}
@@ -5568,7 +5664,7 @@ impl TextDetectionModel {
 	/// * confidences:[out] array with detection confidences
 	/// 
 	/// ## Overloaded parameters
-	pub fn detect_text_rectangles_1(self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::RotatedRect>) -> Result<()> {
+	pub fn detect_text_rectangles_1(&self, frame: &dyn core::ToInputArray, detections: &mut core::Vector::<core::RotatedRect>) -> Result<()> {
 		input_array_arg!(frame);
 		unsafe { sys::cv_dnn_TextDetectionModel_detectTextRectangles_const_const__InputArrayR_vector_RotatedRect_R(self.opencv_as_extern(), frame.as_raw__InputArray(), detections.as_raw_mut_VectorOfRotatedRect()) }.into_result()
 	}
@@ -5644,7 +5740,9 @@ opencv_type_boxed! { TextDetectionModel_DB }
 impl Drop for TextDetectionModel_DB {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextDetectionModel_DB_delete(instance: *mut c_void); }
-		unsafe { cv_TextDetectionModel_DB_delete(self.as_raw_mut_TextDetectionModel_DB()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TextDetectionModel_DB_delete(self.as_raw_mut_TextDetectionModel_DB()) };
+		}
 	}
 }
 
@@ -5742,7 +5840,9 @@ opencv_type_boxed! { TextDetectionModel_EAST }
 impl Drop for TextDetectionModel_EAST {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextDetectionModel_EAST_delete(instance: *mut c_void); }
-		unsafe { cv_TextDetectionModel_EAST_delete(self.as_raw_mut_TextDetectionModel_EAST()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TextDetectionModel_EAST_delete(self.as_raw_mut_TextDetectionModel_EAST()) };
+		}
 	}
 }
 
@@ -5868,7 +5968,9 @@ opencv_type_boxed! { TextRecognitionModel }
 impl Drop for TextRecognitionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TextRecognitionModel_delete(instance: *mut c_void); }
-		unsafe { cv_TextRecognitionModel_delete(self.as_raw_mut_TextRecognitionModel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TextRecognitionModel_delete(self.as_raw_mut_TextRecognitionModel()) };
+		}
 	}
 }
 
@@ -5933,7 +6035,9 @@ opencv_type_boxed! { _Range }
 impl Drop for _Range {
 	fn drop(&mut self) {
 		extern "C" { fn cv__Range_delete(instance: *mut c_void); }
-		unsafe { cv__Range_delete(self.as_raw_mut__Range()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv__Range_delete(self.as_raw_mut__Range()) };
+		}
 	}
 }
 
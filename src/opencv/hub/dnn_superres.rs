@@ -126,7 +126,9 @@ opencv_type_boxed! { DnnSuperResImpl }
 impl Drop for DnnSuperResImpl {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DnnSuperResImpl_delete(instance: *mut c_void); }
-		unsafe { cv_DnnSuperResImpl_delete(self.as_raw_mut_DnnSuperResImpl()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DnnSuperResImpl_delete(self.as_raw_mut_DnnSuperResImpl()) };
+		}
 	}
 }
 
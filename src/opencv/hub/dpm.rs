@@ -129,7 +129,9 @@ opencv_type_boxed! { DPMDetector_ObjectDetection }
 impl Drop for DPMDetector_ObjectDetection {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DPMDetector_ObjectDetection_delete(instance: *mut c_void); }
-		unsafe { cv_DPMDetector_ObjectDetection_delete(self.as_raw_mut_DPMDetector_ObjectDetection()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DPMDetector_ObjectDetection_delete(self.as_raw_mut_DPMDetector_ObjectDetection()) };
+		}
 	}
 }
 
@@ -441,7 +441,9 @@ opencv_type_boxed! { CParams }
 impl Drop for CParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CParams_delete(instance: *mut c_void); }
-		unsafe { cv_CParams_delete(self.as_raw_mut_CParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CParams_delete(self.as_raw_mut_CParams()) };
+		}
 	}
 }
 
@@ -1036,7 +1038,9 @@ opencv_type_boxed! { FacemarkAAM_Config }
 impl Drop for FacemarkAAM_Config {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Config_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Config_delete(self.as_raw_mut_FacemarkAAM_Config()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkAAM_Config_delete(self.as_raw_mut_FacemarkAAM_Config()) };
+		}
 	}
 }
 
@@ -1089,7 +1093,9 @@ opencv_type_boxed! { FacemarkAAM_Data }
 impl Drop for FacemarkAAM_Data {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Data_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Data_delete(self.as_raw_mut_FacemarkAAM_Data()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkAAM_Data_delete(self.as_raw_mut_FacemarkAAM_Data()) };
+		}
 	}
 }
 
@@ -1173,7 +1179,9 @@ opencv_type_boxed! { FacemarkAAM_Model }
 impl Drop for FacemarkAAM_Model {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Model_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Model_delete(self.as_raw_mut_FacemarkAAM_Model()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkAAM_Model_delete(self.as_raw_mut_FacemarkAAM_Model()) };
+		}
 	}
 }
 
@@ -1289,7 +1297,9 @@ opencv_type_boxed! { FacemarkAAM_Model_Texture }
 impl Drop for FacemarkAAM_Model_Texture {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Model_Texture_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Model_Texture_delete(self.as_raw_mut_FacemarkAAM_Model_Texture()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkAAM_Model_Texture_delete(self.as_raw_mut_FacemarkAAM_Model_Texture()) };
+		}
 	}
 }
 
@@ -1414,7 +1424,9 @@ opencv_type_boxed! { FacemarkAAM_Params }
 impl Drop for FacemarkAAM_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkAAM_Params_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkAAM_Params_delete(self.as_raw_mut_FacemarkAAM_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkAAM_Params_delete(self.as_raw_mut_FacemarkAAM_Params()) };
+		}
 	}
 }
 
@@ -1591,7 +1603,9 @@ opencv_type_boxed! { FacemarkKazemi_Params }
 impl Drop for FacemarkKazemi_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkKazemi_Params_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkKazemi_Params_delete(self.as_raw_mut_FacemarkKazemi_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkKazemi_Params_delete(self.as_raw_mut_FacemarkKazemi_Params()) };
+		}
 	}
 }
 
@@ -1778,7 +1792,9 @@ opencv_type_boxed! { FacemarkLBF_Params }
 impl Drop for FacemarkLBF_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FacemarkLBF_Params_delete(instance: *mut c_void); }
-		unsafe { cv_FacemarkLBF_Params_delete(self.as_raw_mut_FacemarkLBF_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FacemarkLBF_Params_delete(self.as_raw_mut_FacemarkLBF_Params()) };
+		}
 	}
 }
 
@@ -2324,7 +2340,9 @@ opencv_type_boxed! { StandardCollector }
 impl Drop for StandardCollector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StandardCollector_delete(instance: *mut c_void); }
-		unsafe { cv_StandardCollector_delete(self.as_raw_mut_StandardCollector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_StandardCollector_delete(self.as_raw_mut_StandardCollector()) };
+		}
 	}
 }
 
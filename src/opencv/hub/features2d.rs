@@ -614,7 +614,9 @@ opencv_type_boxed! { BFMatcher }
 impl Drop for BFMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BFMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_BFMatcher_delete(self.as_raw_mut_BFMatcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BFMatcher_delete(self.as_raw_mut_BFMatcher()) };
+		}
 	}
 }
 
@@ -780,7 +782,9 @@ opencv_type_boxed! { BOWImgDescriptorExtractor }
 impl Drop for BOWImgDescriptorExtractor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BOWImgDescriptorExtractor_delete(instance: *mut c_void); }
-		unsafe { cv_BOWImgDescriptorExtractor_delete(self.as_raw_mut_BOWImgDescriptorExtractor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BOWImgDescriptorExtractor_delete(self.as_raw_mut_BOWImgDescriptorExtractor()) };
+		}
 	}
 }
 
@@ -848,7 +852,9 @@ opencv_type_boxed! { BOWKMeansTrainer }
 impl Drop for BOWKMeansTrainer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BOWKMeansTrainer_delete(instance: *mut c_void); }
-		unsafe { cv_BOWKMeansTrainer_delete(self.as_raw_mut_BOWKMeansTrainer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BOWKMeansTrainer_delete(self.as_raw_mut_BOWKMeansTrainer()) };
+		}
 	}
 }
 
@@ -990,7 +996,9 @@ opencv_type_boxed! { BRISK }
 impl Drop for BRISK {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BRISK_delete(instance: *mut c_void); }
-		unsafe { cv_BRISK_delete(self.as_raw_mut_BRISK()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BRISK_delete(self.as_raw_mut_BRISK()) };
+		}
 	}
 }
 
@@ -1596,7 +1604,9 @@ opencv_type_boxed! { Feature2D }
 impl Drop for Feature2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Feature2D_delete(instance: *mut c_void); }
-		unsafe { cv_Feature2D_delete(self.as_raw_mut_Feature2D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Feature2D_delete(self.as_raw_mut_Feature2D()) };
+		}
 	}
 }
 
@@ -1678,7 +1688,9 @@ opencv_type_boxed! { FlannBasedMatcher }
 impl Drop for FlannBasedMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FlannBasedMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_FlannBasedMatcher_delete(self.as_raw_mut_FlannBasedMatcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FlannBasedMatcher_delete(self.as_raw_mut_FlannBasedMatcher()) };
+		}
 	}
 }
 
@@ -1908,7 +1920,9 @@ opencv_type_boxed! { KeyPointsFilter }
 impl Drop for KeyPointsFilter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeyPointsFilter_delete(instance: *mut c_void); }
-		unsafe { cv_KeyPointsFilter_delete(self.as_raw_mut_KeyPointsFilter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KeyPointsFilter_delete(self.as_raw_mut_KeyPointsFilter()) };
+		}
 	}
 }
 
@@ -2213,7 +2227,9 @@ opencv_type_boxed! { SIFT }
 impl Drop for SIFT {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SIFT_delete(instance: *mut c_void); }
-		unsafe { cv_SIFT_delete(self.as_raw_mut_SIFT()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SIFT_delete(self.as_raw_mut_SIFT()) };
+		}
 	}
 }
 
@@ -2381,7 +2397,9 @@ opencv_type_boxed! { SimpleBlobDetector }
 impl Drop for SimpleBlobDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SimpleBlobDetector_delete(instance: *mut c_void); }
-		unsafe { cv_SimpleBlobDetector_delete(self.as_raw_mut_SimpleBlobDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SimpleBlobDetector_delete(self.as_raw_mut_SimpleBlobDetector()) };
+		}
 	}
 }
 
@@ -2451,7 +2469,7 @@ impl SimpleBlobDetector_Params {
 		unsafe { sys::cv_SimpleBlobDetector_Params_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode()) }.into_result()
 	}
 	
-	pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
+	pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
 		unsafe { sys::cv_SimpleBlobDetector_Params_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage()) }.into_result()
 	}
 	
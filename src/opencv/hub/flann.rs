@@ -252,7 +252,9 @@ opencv_type_boxed! { AutotunedIndexParams }
 impl Drop for AutotunedIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AutotunedIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_AutotunedIndexParams_delete(self.as_raw_mut_AutotunedIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_AutotunedIndexParams_delete(self.as_raw_mut_AutotunedIndexParams()) };
+		}
 	}
 }
 
@@ -300,7 +302,9 @@ opencv_type_boxed! { CompositeIndexParams }
 impl Drop for CompositeIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompositeIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_CompositeIndexParams_delete(self.as_raw_mut_CompositeIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CompositeIndexParams_delete(self.as_raw_mut_CompositeIndexParams()) };
+		}
 	}
 }
 
@@ -349,7 +353,9 @@ opencv_type_boxed! { HierarchicalClusteringIndexParams }
 impl Drop for HierarchicalClusteringIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HierarchicalClusteringIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_HierarchicalClusteringIndexParams_delete(self.as_raw_mut_HierarchicalClusteringIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_HierarchicalClusteringIndexParams_delete(self.as_raw_mut_HierarchicalClusteringIndexParams()) };
+		}
 	}
 }
 
@@ -445,7 +451,9 @@ opencv_type_boxed! { Index }
 impl Drop for Index {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Index_delete(instance: *mut c_void); }
-		unsafe { cv_Index_delete(self.as_raw_mut_Index()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Index_delete(self.as_raw_mut_Index()) };
+		}
 	}
 }
 
@@ -554,7 +562,9 @@ opencv_type_boxed! { IndexParams }
 impl Drop for IndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_IndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_IndexParams_delete(self.as_raw_mut_IndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_IndexParams_delete(self.as_raw_mut_IndexParams()) };
+		}
 	}
 }
 
@@ -592,7 +602,9 @@ opencv_type_boxed! { KDTreeIndexParams }
 impl Drop for KDTreeIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KDTreeIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_KDTreeIndexParams_delete(self.as_raw_mut_KDTreeIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KDTreeIndexParams_delete(self.as_raw_mut_KDTreeIndexParams()) };
+		}
 	}
 }
 
@@ -637,7 +649,9 @@ opencv_type_boxed! { KMeansIndexParams }
 impl Drop for KMeansIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KMeansIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_KMeansIndexParams_delete(self.as_raw_mut_KMeansIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KMeansIndexParams_delete(self.as_raw_mut_KMeansIndexParams()) };
+		}
 	}
 }
 
@@ -685,7 +699,9 @@ opencv_type_boxed! { LinearIndexParams }
 impl Drop for LinearIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LinearIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_LinearIndexParams_delete(self.as_raw_mut_LinearIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LinearIndexParams_delete(self.as_raw_mut_LinearIndexParams()) };
+		}
 	}
 }
 
@@ -728,7 +744,9 @@ opencv_type_boxed! { LshIndexParams }
 impl Drop for LshIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LshIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_LshIndexParams_delete(self.as_raw_mut_LshIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LshIndexParams_delete(self.as_raw_mut_LshIndexParams()) };
+		}
 	}
 }
 
@@ -771,7 +789,9 @@ opencv_type_boxed! { SavedIndexParams }
 impl Drop for SavedIndexParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SavedIndexParams_delete(instance: *mut c_void); }
-		unsafe { cv_SavedIndexParams_delete(self.as_raw_mut_SavedIndexParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SavedIndexParams_delete(self.as_raw_mut_SavedIndexParams()) };
+		}
 	}
 }
 
@@ -815,7 +835,9 @@ opencv_type_boxed! { SearchParams }
 impl Drop for SearchParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SearchParams_delete(instance: *mut c_void); }
-		unsafe { cv_SearchParams_delete(self.as_raw_mut_SearchParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SearchParams_delete(self.as_raw_mut_SearchParams()) };
+		}
 	}
 }
 
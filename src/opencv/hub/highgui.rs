@@ -1255,7 +1255,9 @@ opencv_type_boxed! { QtFont }
 impl Drop for QtFont {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QtFont_delete(instance: *mut c_void); }
-		unsafe { cv_QtFont_delete(self.as_raw_mut_QtFont()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QtFont_delete(self.as_raw_mut_QtFont()) };
+		}
 	}
 }
 
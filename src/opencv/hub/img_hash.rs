@@ -192,7 +192,9 @@ opencv_type_boxed! { AverageHash }
 impl Drop for AverageHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AverageHash_delete(instance: *mut c_void); }
-		unsafe { cv_AverageHash_delete(self.as_raw_mut_AverageHash()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_AverageHash_delete(self.as_raw_mut_AverageHash()) };
+		}
 	}
 }
 
@@ -257,7 +259,9 @@ opencv_type_boxed! { BlockMeanHash }
 impl Drop for BlockMeanHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BlockMeanHash_delete(instance: *mut c_void); }
-		unsafe { cv_BlockMeanHash_delete(self.as_raw_mut_BlockMeanHash()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BlockMeanHash_delete(self.as_raw_mut_BlockMeanHash()) };
+		}
 	}
 }
 
@@ -313,7 +317,9 @@ opencv_type_boxed! { ColorMomentHash }
 impl Drop for ColorMomentHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorMomentHash_delete(instance: *mut c_void); }
-		unsafe { cv_ColorMomentHash_delete(self.as_raw_mut_ColorMomentHash()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ColorMomentHash_delete(self.as_raw_mut_ColorMomentHash()) };
+		}
 	}
 }
 
@@ -386,7 +392,9 @@ opencv_type_boxed! { ImgHashBase }
 impl Drop for ImgHashBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ImgHashBase_delete(instance: *mut c_void); }
-		unsafe { cv_ImgHashBase_delete(self.as_raw_mut_ImgHashBase()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ImgHashBase_delete(self.as_raw_mut_ImgHashBase()) };
+		}
 	}
 }
 
@@ -449,7 +457,9 @@ opencv_type_boxed! { MarrHildrethHash }
 impl Drop for MarrHildrethHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MarrHildrethHash_delete(instance: *mut c_void); }
-		unsafe { cv_MarrHildrethHash_delete(self.as_raw_mut_MarrHildrethHash()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MarrHildrethHash_delete(self.as_raw_mut_MarrHildrethHash()) };
+		}
 	}
 }
 
@@ -514,7 +524,9 @@ opencv_type_boxed! { PHash }
 impl Drop for PHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PHash_delete(instance: *mut c_void); }
-		unsafe { cv_PHash_delete(self.as_raw_mut_PHash()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PHash_delete(self.as_raw_mut_PHash()) };
+		}
 	}
 }
 
@@ -600,7 +612,9 @@ opencv_type_boxed! { RadialVarianceHash }
 impl Drop for RadialVarianceHash {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RadialVarianceHash_delete(instance: *mut c_void); }
-		unsafe { cv_RadialVarianceHash_delete(self.as_raw_mut_RadialVarianceHash()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RadialVarianceHash_delete(self.as_raw_mut_RadialVarianceHash()) };
+		}
 	}
 }
 
@@ -477,3 +477,4 @@ pub fn imwritemulti(filename: &str, img: &dyn core::ToInputArray, params: &core:
 	input_array_arg!(img);
 	unsafe { sys::cv_imwritemulti_const_StringR_const__InputArrayR_const_vector_int_R(filename.opencv_as_extern(), img.as_raw__InputArray(), params.as_raw_VectorOfi32()) }.into_result()
 }
+pub use crate::manual::imgcodecs::*;
@@ -6522,7 +6522,9 @@ opencv_type_boxed! { LineIterator }
 impl Drop for LineIterator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LineIterator_delete(instance: *mut c_void); }
-		unsafe { cv_LineIterator_delete(self.as_raw_mut_LineIterator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LineIterator_delete(self.as_raw_mut_LineIterator()) };
+		}
 	}
 }
 
@@ -6890,7 +6892,9 @@ opencv_type_boxed! { Subdiv2D }
 impl Drop for Subdiv2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Subdiv2D_delete(instance: *mut c_void); }
-		unsafe { cv_Subdiv2D_delete(self.as_raw_mut_Subdiv2D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Subdiv2D_delete(self.as_raw_mut_Subdiv2D()) };
+		}
 	}
 }
 
@@ -7091,7 +7095,9 @@ opencv_type_boxed! { IntelligentScissorsMB }
 impl Drop for IntelligentScissorsMB {
 	fn drop(&mut self) {
 		extern "C" { fn cv_IntelligentScissorsMB_delete(instance: *mut c_void); }
-		unsafe { cv_IntelligentScissorsMB_delete(self.as_raw_mut_IntelligentScissorsMB()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_IntelligentScissorsMB_delete(self.as_raw_mut_IntelligentScissorsMB()) };
+		}
 	}
 }
 
@@ -305,7 +305,9 @@ opencv_type_boxed! { BinaryDescriptor }
 impl Drop for BinaryDescriptor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BinaryDescriptor_delete(instance: *mut c_void); }
-		unsafe { cv_BinaryDescriptor_delete(self.as_raw_mut_BinaryDescriptor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BinaryDescriptor_delete(self.as_raw_mut_BinaryDescriptor()) };
+		}
 	}
 }
 
@@ -418,7 +420,9 @@ opencv_type_boxed! { BinaryDescriptor_Params }
 impl Drop for BinaryDescriptor_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BinaryDescriptor_Params_delete(instance: *mut c_void); }
-		unsafe { cv_BinaryDescriptor_Params_delete(self.as_raw_mut_BinaryDescriptor_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BinaryDescriptor_Params_delete(self.as_raw_mut_BinaryDescriptor_Params()) };
+		}
 	}
 }
 
@@ -692,7 +696,9 @@ opencv_type_boxed! { BinaryDescriptorMatcher }
 impl Drop for BinaryDescriptorMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BinaryDescriptorMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_BinaryDescriptorMatcher_delete(self.as_raw_mut_BinaryDescriptorMatcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BinaryDescriptorMatcher_delete(self.as_raw_mut_BinaryDescriptorMatcher()) };
+		}
 	}
 }
 
@@ -798,22 +804,22 @@ opencv_type_simple! { crate::line_descriptor::KeyLine }
 
 impl KeyLine {
 	/// Returns the start point of the line in the original image
-	pub fn get_start_point(self) -> Result<core::Point2f> {
+	pub fn get_start_point(&self) -> Result<core::Point2f> {
 		unsafe { sys::cv_line_descriptor_KeyLine_getStartPoint_const(self.opencv_as_extern()) }.into_result()
 	}
 	
 	/// Returns the end point of the line in the original image
-	pub fn get_end_point(self) -> Result<core::Point2f> {
+	pub fn get_end_point(&self) -> Result<core::Point2f> {
 		unsafe { sys::cv_line_descriptor_KeyLine_getEndPoint_const(self.opencv_as_extern()) }.into_result()
 	}
 	
 	/// Returns the start point of the line in the octave it was extracted from
-	pub fn get_start_point_in_octave(self) -> Result<core::Point2f> {
+	pub fn get_start_point_in_octave(&self) -> Result<core::Point2f> {
 		unsafe { sys::cv_line_descriptor_KeyLine_getStartPointInOctave_const(self.opencv_as_extern()) }.into_result()
 	}
 	
 	/// Returns the end point of the line in the octave it was extracted from
-	pub fn get_end_point_in_octave(self) -> Result<core::Point2f> {
+	pub fn get_end_point_in_octave(&self) -> Result<core::Point2f> {
 		unsafe { sys::cv_line_descriptor_KeyLine_getEndPointInOctave_const(self.opencv_as_extern()) }.into_result()
 	}
 	
@@ -877,7 +883,9 @@ opencv_type_boxed! { LSDDetector }
 impl Drop for LSDDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LSDDetector_delete(instance: *mut c_void); }
-		unsafe { cv_LSDDetector_delete(self.as_raw_mut_LSDDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LSDDetector_delete(self.as_raw_mut_LSDDetector()) };
+		}
 	}
 }
 
@@ -953,3 +961,4 @@ impl LSDParam {
 	}
 	
 }
+pub use crate::manual::line_descriptor::*;
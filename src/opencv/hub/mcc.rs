@@ -665,7 +665,9 @@ opencv_type_boxed! { ColorCorrectionModel }
 impl Drop for ColorCorrectionModel {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorCorrectionModel_delete(instance: *mut c_void); }
-		unsafe { cv_ColorCorrectionModel_delete(self.as_raw_mut_ColorCorrectionModel()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ColorCorrectionModel_delete(self.as_raw_mut_ColorCorrectionModel()) };
+		}
 	}
 }
 
@@ -1192,7 +1194,9 @@ opencv_type_boxed! { MCC_DetectorParameters }
 impl Drop for MCC_DetectorParameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MCC_DetectorParameters_delete(instance: *mut c_void); }
-		unsafe { cv_MCC_DetectorParameters_delete(self.as_raw_mut_MCC_DetectorParameters()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MCC_DetectorParameters_delete(self.as_raw_mut_MCC_DetectorParameters()) };
+		}
 	}
 }
 
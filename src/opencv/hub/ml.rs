@@ -1119,7 +1119,9 @@ opencv_type_boxed! { DTrees_Node }
 impl Drop for DTrees_Node {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DTrees_Node_delete(instance: *mut c_void); }
-		unsafe { cv_DTrees_Node_delete(self.as_raw_mut_DTrees_Node()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DTrees_Node_delete(self.as_raw_mut_DTrees_Node()) };
+		}
 	}
 }
 
@@ -1249,7 +1251,9 @@ opencv_type_boxed! { DTrees_Split }
 impl Drop for DTrees_Split {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DTrees_Split_delete(instance: *mut c_void); }
-		unsafe { cv_DTrees_Split_delete(self.as_raw_mut_DTrees_Split()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DTrees_Split_delete(self.as_raw_mut_DTrees_Split()) };
+		}
 	}
 }
 
@@ -1930,7 +1934,9 @@ opencv_type_boxed! { ParamGrid }
 impl Drop for ParamGrid {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ParamGrid_delete(instance: *mut c_void); }
-		unsafe { cv_ParamGrid_delete(self.as_raw_mut_ParamGrid()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ParamGrid_delete(self.as_raw_mut_ParamGrid()) };
+		}
 	}
 }
 
@@ -488,7 +488,9 @@ opencv_type_boxed! { CascadeClassifier }
 impl Drop for CascadeClassifier {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CascadeClassifier_delete(instance: *mut c_void); }
-		unsafe { cv_CascadeClassifier_delete(self.as_raw_mut_CascadeClassifier()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CascadeClassifier_delete(self.as_raw_mut_CascadeClassifier()) };
+		}
 	}
 }
 
@@ -577,7 +579,9 @@ opencv_type_boxed! { DetectionBasedTracker }
 impl Drop for DetectionBasedTracker {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionBasedTracker_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionBasedTracker_delete(self.as_raw_mut_DetectionBasedTracker()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectionBasedTracker_delete(self.as_raw_mut_DetectionBasedTracker()) };
+		}
 	}
 }
 
@@ -639,7 +643,9 @@ opencv_type_boxed! { DetectionBasedTracker_ExtObject }
 impl Drop for DetectionBasedTracker_ExtObject {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionBasedTracker_ExtObject_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionBasedTracker_ExtObject_delete(self.as_raw_mut_DetectionBasedTracker_ExtObject()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectionBasedTracker_ExtObject_delete(self.as_raw_mut_DetectionBasedTracker_ExtObject()) };
+		}
 	}
 }
 
@@ -735,7 +741,9 @@ opencv_type_boxed! { DetectionBasedTracker_Parameters }
 impl Drop for DetectionBasedTracker_Parameters {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionBasedTracker_Parameters_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionBasedTracker_Parameters_delete(self.as_raw_mut_DetectionBasedTracker_Parameters()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectionBasedTracker_Parameters_delete(self.as_raw_mut_DetectionBasedTracker_Parameters()) };
+		}
 	}
 }
 
@@ -805,7 +813,9 @@ opencv_type_boxed! { DetectionROI }
 impl Drop for DetectionROI {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DetectionROI_delete(instance: *mut c_void); }
-		unsafe { cv_DetectionROI_delete(self.as_raw_mut_DetectionROI()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DetectionROI_delete(self.as_raw_mut_DetectionROI()) };
+		}
 	}
 }
 
@@ -1274,7 +1284,9 @@ opencv_type_boxed! { HOGDescriptor }
 impl Drop for HOGDescriptor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HOGDescriptor_delete(instance: *mut c_void); }
-		unsafe { cv_HOGDescriptor_delete(self.as_raw_mut_HOGDescriptor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_HOGDescriptor_delete(self.as_raw_mut_HOGDescriptor()) };
+		}
 	}
 }
 
@@ -1521,7 +1533,9 @@ opencv_type_boxed! { QRCodeDetector }
 impl Drop for QRCodeDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QRCodeDetector_delete(instance: *mut c_void); }
-		unsafe { cv_QRCodeDetector_delete(self.as_raw_mut_QRCodeDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QRCodeDetector_delete(self.as_raw_mut_QRCodeDetector()) };
+		}
 	}
 }
 
@@ -1571,7 +1585,9 @@ opencv_type_boxed! { SimilarRects }
 impl Drop for SimilarRects {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SimilarRects_delete(instance: *mut c_void); }
-		unsafe { cv_SimilarRects_delete(self.as_raw_mut_SimilarRects()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SimilarRects_delete(self.as_raw_mut_SimilarRects()) };
+		}
 	}
 }
 
@@ -1047,7 +1047,9 @@ opencv_type_boxed! { GPCDetails }
 impl Drop for GPCDetails {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCDetails_delete(instance: *mut c_void); }
-		unsafe { cv_GPCDetails_delete(self.as_raw_mut_GPCDetails()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GPCDetails_delete(self.as_raw_mut_GPCDetails()) };
+		}
 	}
 }
 
@@ -1132,7 +1134,9 @@ opencv_type_boxed! { GPCPatchDescriptor }
 impl Drop for GPCPatchDescriptor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCPatchDescriptor_delete(instance: *mut c_void); }
-		unsafe { cv_GPCPatchDescriptor_delete(self.as_raw_mut_GPCPatchDescriptor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GPCPatchDescriptor_delete(self.as_raw_mut_GPCPatchDescriptor()) };
+		}
 	}
 }
 
@@ -1196,7 +1200,9 @@ opencv_type_boxed! { GPCPatchSample }
 impl Drop for GPCPatchSample {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCPatchSample_delete(instance: *mut c_void); }
-		unsafe { cv_GPCPatchSample_delete(self.as_raw_mut_GPCPatchSample()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GPCPatchSample_delete(self.as_raw_mut_GPCPatchSample()) };
+		}
 	}
 }
 
@@ -1241,7 +1247,7 @@ impl GPCTrainingParams {
 		unsafe { sys::cv_optflow_GPCTrainingParams_GPCTrainingParams_unsigned_int_int_GPCDescType_bool(_max_tree_depth, _min_number_of_samples, _descriptor_type, _print_progress) }.into_result()
 	}
 	
-	pub fn check(self) -> Result<bool> {
+	pub fn check(&self) -> Result<bool> {
 		unsafe { sys::cv_optflow_GPCTrainingParams_check_const(self.opencv_as_extern()) }.into_result()
 	}
 	
@@ -1272,7 +1278,9 @@ opencv_type_boxed! { GPCTrainingSamples }
 impl Drop for GPCTrainingSamples {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCTrainingSamples_delete(instance: *mut c_void); }
-		unsafe { cv_GPCTrainingSamples_delete(self.as_raw_mut_GPCTrainingSamples()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GPCTrainingSamples_delete(self.as_raw_mut_GPCTrainingSamples()) };
+		}
 	}
 }
 
@@ -1343,7 +1351,9 @@ opencv_type_boxed! { GPCTree }
 impl Drop for GPCTree {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GPCTree_delete(instance: *mut c_void); }
-		unsafe { cv_GPCTree_delete(self.as_raw_mut_GPCTree()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GPCTree_delete(self.as_raw_mut_GPCTree()) };
+		}
 	}
 }
 
@@ -1415,7 +1425,9 @@ opencv_type_boxed! { OpticalFlowPCAFlow }
 impl Drop for OpticalFlowPCAFlow {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OpticalFlowPCAFlow_delete(instance: *mut c_void); }
-		unsafe { cv_OpticalFlowPCAFlow_delete(self.as_raw_mut_OpticalFlowPCAFlow()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OpticalFlowPCAFlow_delete(self.as_raw_mut_OpticalFlowPCAFlow()) };
+		}
 	}
 }
 
@@ -1501,7 +1513,9 @@ opencv_type_boxed! { PCAPrior }
 impl Drop for PCAPrior {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PCAPrior_delete(instance: *mut c_void); }
-		unsafe { cv_PCAPrior_delete(self.as_raw_mut_PCAPrior()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PCAPrior_delete(self.as_raw_mut_PCAPrior()) };
+		}
 	}
 }
 
@@ -1826,7 +1840,9 @@ opencv_type_boxed! { RLOFOpticalFlowParameter }
 impl Drop for RLOFOpticalFlowParameter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RLOFOpticalFlowParameter_delete(instance: *mut c_void); }
-		unsafe { cv_RLOFOpticalFlowParameter_delete(self.as_raw_mut_RLOFOpticalFlowParameter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RLOFOpticalFlowParameter_delete(self.as_raw_mut_RLOFOpticalFlowParameter()) };
+		}
 	}
 }
 
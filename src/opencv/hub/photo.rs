@@ -1202,3 +1202,4 @@ pub trait TonemapReinhard: crate::photo::Tonemap {
 	}
 	
 }
+pub use crate::manual::photo::*;
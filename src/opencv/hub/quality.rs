@@ -53,7 +53,9 @@ opencv_type_boxed! { QualityBRISQUE }
 impl Drop for QualityBRISQUE {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityBRISQUE_delete(instance: *mut c_void); }
-		unsafe { cv_QualityBRISQUE_delete(self.as_raw_mut_QualityBRISQUE()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QualityBRISQUE_delete(self.as_raw_mut_QualityBRISQUE()) };
+		}
 	}
 }
 
@@ -194,7 +196,9 @@ opencv_type_boxed! { QualityGMSD }
 impl Drop for QualityGMSD {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityGMSD_delete(instance: *mut c_void); }
-		unsafe { cv_QualityGMSD_delete(self.as_raw_mut_QualityGMSD()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QualityGMSD_delete(self.as_raw_mut_QualityGMSD()) };
+		}
 	}
 }
 
@@ -282,7 +286,9 @@ opencv_type_boxed! { QualityMSE }
 impl Drop for QualityMSE {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityMSE_delete(instance: *mut c_void); }
-		unsafe { cv_QualityMSE_delete(self.as_raw_mut_QualityMSE()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QualityMSE_delete(self.as_raw_mut_QualityMSE()) };
+		}
 	}
 }
 
@@ -382,7 +388,9 @@ opencv_type_boxed! { QualityPSNR }
 impl Drop for QualityPSNR {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualityPSNR_delete(instance: *mut c_void); }
-		unsafe { cv_QualityPSNR_delete(self.as_raw_mut_QualityPSNR()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QualityPSNR_delete(self.as_raw_mut_QualityPSNR()) };
+		}
 	}
 }
 
@@ -479,7 +487,9 @@ opencv_type_boxed! { QualitySSIM }
 impl Drop for QualitySSIM {
 	fn drop(&mut self) {
 		extern "C" { fn cv_QualitySSIM_delete(instance: *mut c_void); }
-		unsafe { cv_QualitySSIM_delete(self.as_raw_mut_QualitySSIM()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_QualitySSIM_delete(self.as_raw_mut_QualitySSIM()) };
+		}
 	}
 }
 
@@ -371,19 +371,19 @@ impl Kinfu_Intr {
 		unsafe { sys::cv_kinfu_Intr_Intr_Matx33f(m.opencv_as_extern()) }.into_result()
 	}
 	
-	pub fn scale(self, pyr: i32) -> Result<crate::rgbd::Kinfu_Intr> {
+	pub fn scale(&self, pyr: i32) -> Result<crate::rgbd::Kinfu_Intr> {
 		unsafe { sys::cv_kinfu_Intr_scale_const_int(self.opencv_as_extern(), pyr) }.into_result()
 	}
 	
-	pub fn make_reprojector(self) -> Result<crate::rgbd::Kinfu_Intr_Reprojector> {
+	pub fn make_reprojector(&self) -> Result<crate::rgbd::Kinfu_Intr_Reprojector> {
 		unsafe { sys::cv_kinfu_Intr_makeReprojector_const(self.opencv_as_extern()) }.into_result()
 	}
 	
-	pub fn make_projector(self) -> Result<crate::rgbd::Kinfu_Intr_Projector> {
+	pub fn make_projector(&self) -> Result<crate::rgbd::Kinfu_Intr_Projector> {
 		unsafe { sys::cv_kinfu_Intr_makeProjector_const(self.opencv_as_extern()) }.into_result()
 	}
 	
-	pub fn get_mat(self) -> Result<core::Matx33f> {
+	pub fn get_mat(&self) -> Result<core::Matx33f> {
 		unsafe { sys::cv_kinfu_Intr_getMat_const(self.opencv_as_extern()) }.into_result()
 	}
 	
@@ -814,7 +814,9 @@ opencv_type_boxed! { Kinfu_Params }
 impl Drop for Kinfu_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Kinfu_Params_delete(instance: *mut c_void); }
-		unsafe { cv_Kinfu_Params_delete(self.as_raw_mut_Kinfu_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Kinfu_Params_delete(self.as_raw_mut_Kinfu_Params()) };
+		}
 	}
 }
 
@@ -1054,7 +1056,9 @@ opencv_type_boxed! { Kinfu_VolumeParams }
 impl Drop for Kinfu_VolumeParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Kinfu_VolumeParams_delete(instance: *mut c_void); }
-		unsafe { cv_Kinfu_VolumeParams_delete(self.as_raw_mut_Kinfu_VolumeParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Kinfu_VolumeParams_delete(self.as_raw_mut_Kinfu_VolumeParams()) };
+		}
 	}
 }
 
@@ -1406,7 +1410,9 @@ opencv_type_boxed! { Params }
 impl Drop for Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Params_delete(instance: *mut c_void); }
-		unsafe { cv_Params_delete(self.as_raw_mut_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Params_delete(self.as_raw_mut_Params()) };
+		}
 	}
 }
 
@@ -1497,7 +1503,9 @@ opencv_type_boxed! { Linemod_ColorGradient }
 impl Drop for Linemod_ColorGradient {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_ColorGradient_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_ColorGradient_delete(self.as_raw_mut_Linemod_ColorGradient()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Linemod_ColorGradient_delete(self.as_raw_mut_Linemod_ColorGradient()) };
+		}
 	}
 }
 
@@ -1601,7 +1609,9 @@ opencv_type_boxed! { Linemod_DepthNormal }
 impl Drop for Linemod_DepthNormal {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_DepthNormal_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_DepthNormal_delete(self.as_raw_mut_Linemod_DepthNormal()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Linemod_DepthNormal_delete(self.as_raw_mut_Linemod_DepthNormal()) };
+		}
 	}
 }
 
@@ -1788,7 +1798,9 @@ opencv_type_boxed! { Linemod_Detector }
 impl Drop for Linemod_Detector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_Detector_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_Detector_delete(self.as_raw_mut_Linemod_Detector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Linemod_Detector_delete(self.as_raw_mut_Linemod_Detector()) };
+		}
 	}
 }
 
@@ -1848,7 +1860,7 @@ impl Linemod_Feature {
 		unsafe { sys::cv_linemod_Feature_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode()) }.into_result()
 	}
 	
-	pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
+	pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
 		unsafe { sys::cv_linemod_Feature_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage()) }.into_result()
 	}
 	
@@ -1912,7 +1924,9 @@ opencv_type_boxed! { Linemod_Match }
 impl Drop for Linemod_Match {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_Match_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_Match_delete(self.as_raw_mut_Linemod_Match()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Linemod_Match_delete(self.as_raw_mut_Linemod_Match()) };
+		}
 	}
 }
 
@@ -2074,7 +2088,9 @@ opencv_type_boxed! { Linemod_Template }
 impl Drop for Linemod_Template {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Linemod_Template_delete(instance: *mut c_void); }
-		unsafe { cv_Linemod_Template_delete(self.as_raw_mut_Linemod_Template()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Linemod_Template_delete(self.as_raw_mut_Linemod_Template()) };
+		}
 	}
 }
 
@@ -2140,7 +2156,9 @@ opencv_type_boxed! { DepthCleaner }
 impl Drop for DepthCleaner {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DepthCleaner_delete(instance: *mut c_void); }
-		unsafe { cv_DepthCleaner_delete(self.as_raw_mut_DepthCleaner()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DepthCleaner_delete(self.as_raw_mut_DepthCleaner()) };
+		}
 	}
 }
 
@@ -2291,7 +2309,9 @@ opencv_type_boxed! { FastICPOdometry }
 impl Drop for FastICPOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FastICPOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_FastICPOdometry_delete(self.as_raw_mut_FastICPOdometry()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FastICPOdometry_delete(self.as_raw_mut_FastICPOdometry()) };
+		}
 	}
 }
 
@@ -2457,7 +2477,9 @@ opencv_type_boxed! { ICPOdometry }
 impl Drop for ICPOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ICPOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_ICPOdometry_delete(self.as_raw_mut_ICPOdometry()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ICPOdometry_delete(self.as_raw_mut_ICPOdometry()) };
+		}
 	}
 }
 
@@ -2733,7 +2755,9 @@ opencv_type_boxed! { OdometryFrame }
 impl Drop for OdometryFrame {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OdometryFrame_delete(instance: *mut c_void); }
-		unsafe { cv_OdometryFrame_delete(self.as_raw_mut_OdometryFrame()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OdometryFrame_delete(self.as_raw_mut_OdometryFrame()) };
+		}
 	}
 }
 
@@ -2840,7 +2864,9 @@ opencv_type_boxed! { RgbdFrame }
 impl Drop for RgbdFrame {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdFrame_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdFrame_delete(self.as_raw_mut_RgbdFrame()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RgbdFrame_delete(self.as_raw_mut_RgbdFrame()) };
+		}
 	}
 }
 
@@ -2986,7 +3012,9 @@ opencv_type_boxed! { RgbdICPOdometry }
 impl Drop for RgbdICPOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdICPOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdICPOdometry_delete(self.as_raw_mut_RgbdICPOdometry()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RgbdICPOdometry_delete(self.as_raw_mut_RgbdICPOdometry()) };
+		}
 	}
 }
 
@@ -3144,7 +3172,9 @@ opencv_type_boxed! { RgbdNormals }
 impl Drop for RgbdNormals {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdNormals_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdNormals_delete(self.as_raw_mut_RgbdNormals()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RgbdNormals_delete(self.as_raw_mut_RgbdNormals()) };
+		}
 	}
 }
 
@@ -3300,7 +3330,9 @@ opencv_type_boxed! { RgbdOdometry }
 impl Drop for RgbdOdometry {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdOdometry_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdOdometry_delete(self.as_raw_mut_RgbdOdometry()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RgbdOdometry_delete(self.as_raw_mut_RgbdOdometry()) };
+		}
 	}
 }
 
@@ -3444,7 +3476,9 @@ opencv_type_boxed! { RgbdPlane }
 impl Drop for RgbdPlane {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RgbdPlane_delete(instance: *mut c_void); }
-		unsafe { cv_RgbdPlane_delete(self.as_raw_mut_RgbdPlane()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RgbdPlane_delete(self.as_raw_mut_RgbdPlane()) };
+		}
 	}
 }
 
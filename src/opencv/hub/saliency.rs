@@ -120,7 +120,9 @@ opencv_type_boxed! { MotionSaliencyBinWangApr2014 }
 impl Drop for MotionSaliencyBinWangApr2014 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionSaliencyBinWangApr2014_delete(instance: *mut c_void); }
-		unsafe { cv_MotionSaliencyBinWangApr2014_delete(self.as_raw_mut_MotionSaliencyBinWangApr2014()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MotionSaliencyBinWangApr2014_delete(self.as_raw_mut_MotionSaliencyBinWangApr2014()) };
+		}
 	}
 }
 
@@ -254,7 +256,9 @@ opencv_type_boxed! { ObjectnessBING }
 impl Drop for ObjectnessBING {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ObjectnessBING_delete(instance: *mut c_void); }
-		unsafe { cv_ObjectnessBING_delete(self.as_raw_mut_ObjectnessBING()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ObjectnessBING_delete(self.as_raw_mut_ObjectnessBING()) };
+		}
 	}
 }
 
@@ -370,7 +374,9 @@ opencv_type_boxed! { StaticSaliencyFineGrained }
 impl Drop for StaticSaliencyFineGrained {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StaticSaliencyFineGrained_delete(instance: *mut c_void); }
-		unsafe { cv_StaticSaliencyFineGrained_delete(self.as_raw_mut_StaticSaliencyFineGrained()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_StaticSaliencyFineGrained_delete(self.as_raw_mut_StaticSaliencyFineGrained()) };
+		}
 	}
 }
 
@@ -469,7 +475,9 @@ opencv_type_boxed! { StaticSaliencySpectralResidual }
 impl Drop for StaticSaliencySpectralResidual {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StaticSaliencySpectralResidual_delete(instance: *mut c_void); }
-		unsafe { cv_StaticSaliencySpectralResidual_delete(self.as_raw_mut_StaticSaliencySpectralResidual()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_StaticSaliencySpectralResidual_delete(self.as_raw_mut_StaticSaliencySpectralResidual()) };
+		}
 	}
 }
 
@@ -272,7 +272,9 @@ opencv_type_boxed! { AffineWarper }
 impl Drop for AffineWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_AffineWarper_delete(instance: *mut c_void); }
-		unsafe { cv_AffineWarper_delete(self.as_raw_mut_AffineWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_AffineWarper_delete(self.as_raw_mut_AffineWarper()) };
+		}
 	}
 }
 
@@ -315,7 +317,9 @@ opencv_type_boxed! { CompressedRectilinearPortraitWarper }
 impl Drop for CompressedRectilinearPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompressedRectilinearPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_CompressedRectilinearPortraitWarper_delete(self.as_raw_mut_CompressedRectilinearPortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CompressedRectilinearPortraitWarper_delete(self.as_raw_mut_CompressedRectilinearPortraitWarper()) };
+		}
 	}
 }
 
@@ -365,7 +369,9 @@ opencv_type_boxed! { CompressedRectilinearWarper }
 impl Drop for CompressedRectilinearWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CompressedRectilinearWarper_delete(instance: *mut c_void); }
-		unsafe { cv_CompressedRectilinearWarper_delete(self.as_raw_mut_CompressedRectilinearWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CompressedRectilinearWarper_delete(self.as_raw_mut_CompressedRectilinearWarper()) };
+		}
 	}
 }
 
@@ -421,7 +427,9 @@ opencv_type_boxed! { CylindricalWarper }
 impl Drop for CylindricalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CylindricalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_CylindricalWarper_delete(self.as_raw_mut_CylindricalWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CylindricalWarper_delete(self.as_raw_mut_CylindricalWarper()) };
+		}
 	}
 }
 
@@ -464,7 +472,9 @@ opencv_type_boxed! { CylindricalWarperGpu }
 impl Drop for CylindricalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_CylindricalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_CylindricalWarperGpu_delete(self.as_raw_mut_CylindricalWarperGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_CylindricalWarperGpu_delete(self.as_raw_mut_CylindricalWarperGpu()) };
+		}
 	}
 }
 
@@ -507,7 +517,9 @@ opencv_type_boxed! { FisheyeWarper }
 impl Drop for FisheyeWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FisheyeWarper_delete(instance: *mut c_void); }
-		unsafe { cv_FisheyeWarper_delete(self.as_raw_mut_FisheyeWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FisheyeWarper_delete(self.as_raw_mut_FisheyeWarper()) };
+		}
 	}
 }
 
@@ -550,7 +562,9 @@ opencv_type_boxed! { MercatorWarper }
 impl Drop for MercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_MercatorWarper_delete(self.as_raw_mut_MercatorWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MercatorWarper_delete(self.as_raw_mut_MercatorWarper()) };
+		}
 	}
 }
 
@@ -593,7 +607,9 @@ opencv_type_boxed! { PaniniPortraitWarper }
 impl Drop for PaniniPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PaniniPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PaniniPortraitWarper_delete(self.as_raw_mut_PaniniPortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PaniniPortraitWarper_delete(self.as_raw_mut_PaniniPortraitWarper()) };
+		}
 	}
 }
 
@@ -643,7 +659,9 @@ opencv_type_boxed! { PaniniWarper }
 impl Drop for PaniniWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PaniniWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PaniniWarper_delete(self.as_raw_mut_PaniniWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PaniniWarper_delete(self.as_raw_mut_PaniniWarper()) };
+		}
 	}
 }
 
@@ -699,7 +717,9 @@ opencv_type_boxed! { PlaneWarper }
 impl Drop for PlaneWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PlaneWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PlaneWarper_delete(self.as_raw_mut_PlaneWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PlaneWarper_delete(self.as_raw_mut_PlaneWarper()) };
+		}
 	}
 }
 
@@ -742,7 +762,9 @@ opencv_type_boxed! { PlaneWarperGpu }
 impl Drop for PlaneWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PlaneWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_PlaneWarperGpu_delete(self.as_raw_mut_PlaneWarperGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PlaneWarperGpu_delete(self.as_raw_mut_PlaneWarperGpu()) };
+		}
 	}
 }
 
@@ -876,7 +898,9 @@ opencv_type_boxed! { PyRotationWarper }
 impl Drop for PyRotationWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PyRotationWarper_delete(instance: *mut c_void); }
-		unsafe { cv_PyRotationWarper_delete(self.as_raw_mut_PyRotationWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PyRotationWarper_delete(self.as_raw_mut_PyRotationWarper()) };
+		}
 	}
 }
 
@@ -925,7 +949,9 @@ opencv_type_boxed! { SphericalWarper }
 impl Drop for SphericalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SphericalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_SphericalWarper_delete(self.as_raw_mut_SphericalWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SphericalWarper_delete(self.as_raw_mut_SphericalWarper()) };
+		}
 	}
 }
 
@@ -968,7 +994,9 @@ opencv_type_boxed! { SphericalWarperGpu }
 impl Drop for SphericalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SphericalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_SphericalWarperGpu_delete(self.as_raw_mut_SphericalWarperGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SphericalWarperGpu_delete(self.as_raw_mut_SphericalWarperGpu()) };
+		}
 	}
 }
 
@@ -1011,7 +1039,9 @@ opencv_type_boxed! { StereographicWarper }
 impl Drop for StereographicWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StereographicWarper_delete(instance: *mut c_void); }
-		unsafe { cv_StereographicWarper_delete(self.as_raw_mut_StereographicWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_StereographicWarper_delete(self.as_raw_mut_StereographicWarper()) };
+		}
 	}
 }
 
@@ -1374,7 +1404,9 @@ opencv_type_boxed! { Stitcher }
 impl Drop for Stitcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Stitcher_delete(instance: *mut c_void); }
-		unsafe { cv_Stitcher_delete(self.as_raw_mut_Stitcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Stitcher_delete(self.as_raw_mut_Stitcher()) };
+		}
 	}
 }
 
@@ -1428,7 +1460,9 @@ opencv_type_boxed! { TransverseMercatorWarper }
 impl Drop for TransverseMercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TransverseMercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_TransverseMercatorWarper_delete(self.as_raw_mut_TransverseMercatorWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TransverseMercatorWarper_delete(self.as_raw_mut_TransverseMercatorWarper()) };
+		}
 	}
 }
 
@@ -1490,7 +1524,9 @@ opencv_type_boxed! { Detail_AffineBasedEstimator }
 impl Drop for Detail_AffineBasedEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_AffineBasedEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_AffineBasedEstimator_delete(self.as_raw_mut_Detail_AffineBasedEstimator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_AffineBasedEstimator_delete(self.as_raw_mut_Detail_AffineBasedEstimator()) };
+		}
 	}
 }
 
@@ -1549,7 +1585,9 @@ opencv_type_boxed! { Detail_AffineBestOf2NearestMatcher }
 impl Drop for Detail_AffineBestOf2NearestMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_AffineBestOf2NearestMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_AffineBestOf2NearestMatcher_delete(self.as_raw_mut_Detail_AffineBestOf2NearestMatcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_AffineBestOf2NearestMatcher_delete(self.as_raw_mut_Detail_AffineBestOf2NearestMatcher()) };
+		}
 	}
 }
 
@@ -1700,7 +1738,9 @@ opencv_type_boxed! { Detail_AffineWarper }
 impl Drop for Detail_AffineWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_AffineWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_AffineWarper_delete(self.as_raw_mut_Detail_AffineWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_AffineWarper_delete(self.as_raw_mut_Detail_AffineWarper()) };
+		}
 	}
 }
 
@@ -1767,7 +1807,9 @@ opencv_type_boxed! { Detail_BestOf2NearestMatcher }
 impl Drop for Detail_BestOf2NearestMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BestOf2NearestMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BestOf2NearestMatcher_delete(self.as_raw_mut_Detail_BestOf2NearestMatcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BestOf2NearestMatcher_delete(self.as_raw_mut_Detail_BestOf2NearestMatcher()) };
+		}
 	}
 }
 
@@ -1834,7 +1876,9 @@ opencv_type_boxed! { Detail_BestOf2NearestRangeMatcher }
 impl Drop for Detail_BestOf2NearestRangeMatcher {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BestOf2NearestRangeMatcher_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BestOf2NearestRangeMatcher_delete(self.as_raw_mut_Detail_BestOf2NearestRangeMatcher()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BestOf2NearestRangeMatcher_delete(self.as_raw_mut_Detail_BestOf2NearestRangeMatcher()) };
+		}
 	}
 }
 
@@ -1937,7 +1981,9 @@ opencv_type_boxed! { Detail_Blender }
 impl Drop for Detail_Blender {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_Blender_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_Blender_delete(self.as_raw_mut_Detail_Blender()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_Blender_delete(self.as_raw_mut_Detail_Blender()) };
+		}
 	}
 }
 
@@ -1981,7 +2027,9 @@ opencv_type_boxed! { Detail_BlocksChannelsCompensator }
 impl Drop for Detail_BlocksChannelsCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BlocksChannelsCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BlocksChannelsCompensator_delete(self.as_raw_mut_Detail_BlocksChannelsCompensator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BlocksChannelsCompensator_delete(self.as_raw_mut_Detail_BlocksChannelsCompensator()) };
+		}
 	}
 }
 
@@ -2108,7 +2156,9 @@ opencv_type_boxed! { Detail_BlocksGainCompensator }
 impl Drop for Detail_BlocksGainCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BlocksGainCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BlocksGainCompensator_delete(self.as_raw_mut_Detail_BlocksGainCompensator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BlocksGainCompensator_delete(self.as_raw_mut_Detail_BlocksGainCompensator()) };
+		}
 	}
 }
 
@@ -2179,7 +2229,9 @@ opencv_type_boxed! { Detail_BundleAdjusterAffine }
 impl Drop for Detail_BundleAdjusterAffine {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterAffine_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterAffine_delete(self.as_raw_mut_Detail_BundleAdjusterAffine()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BundleAdjusterAffine_delete(self.as_raw_mut_Detail_BundleAdjusterAffine()) };
+		}
 	}
 }
 
@@ -2243,7 +2295,9 @@ opencv_type_boxed! { Detail_BundleAdjusterAffinePartial }
 impl Drop for Detail_BundleAdjusterAffinePartial {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterAffinePartial_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterAffinePartial_delete(self.as_raw_mut_Detail_BundleAdjusterAffinePartial()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BundleAdjusterAffinePartial_delete(self.as_raw_mut_Detail_BundleAdjusterAffinePartial()) };
+		}
 	}
 }
 
@@ -2330,7 +2384,9 @@ opencv_type_boxed! { Detail_BundleAdjusterRay }
 impl Drop for Detail_BundleAdjusterRay {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterRay_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterRay_delete(self.as_raw_mut_Detail_BundleAdjusterRay()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BundleAdjusterRay_delete(self.as_raw_mut_Detail_BundleAdjusterRay()) };
+		}
 	}
 }
 
@@ -2388,7 +2444,9 @@ opencv_type_boxed! { Detail_BundleAdjusterReproj }
 impl Drop for Detail_BundleAdjusterReproj {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_BundleAdjusterReproj_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_BundleAdjusterReproj_delete(self.as_raw_mut_Detail_BundleAdjusterReproj()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_BundleAdjusterReproj_delete(self.as_raw_mut_Detail_BundleAdjusterReproj()) };
+		}
 	}
 }
 
@@ -2496,7 +2554,9 @@ opencv_type_boxed! { Detail_CameraParams }
 impl Drop for Detail_CameraParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CameraParams_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CameraParams_delete(self.as_raw_mut_Detail_CameraParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CameraParams_delete(self.as_raw_mut_Detail_CameraParams()) };
+		}
 	}
 }
 
@@ -2576,7 +2636,9 @@ opencv_type_boxed! { Detail_ChannelsCompensator }
 impl Drop for Detail_ChannelsCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_ChannelsCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_ChannelsCompensator_delete(self.as_raw_mut_Detail_ChannelsCompensator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_ChannelsCompensator_delete(self.as_raw_mut_Detail_ChannelsCompensator()) };
+		}
 	}
 }
 
@@ -2645,7 +2707,9 @@ opencv_type_boxed! { Detail_CompressedRectilinearPortraitProjector }
 impl Drop for Detail_CompressedRectilinearPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearPortraitProjector_delete(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CompressedRectilinearPortraitProjector_delete(self.as_raw_mut_Detail_CompressedRectilinearPortraitProjector()) };
+		}
 	}
 }
 
@@ -2684,7 +2748,9 @@ opencv_type_boxed! { Detail_CompressedRectilinearPortraitWarper }
 impl Drop for Detail_CompressedRectilinearPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearPortraitWarper_delete(self.as_raw_mut_Detail_CompressedRectilinearPortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CompressedRectilinearPortraitWarper_delete(self.as_raw_mut_Detail_CompressedRectilinearPortraitWarper()) };
+		}
 	}
 }
 
@@ -2754,7 +2820,9 @@ opencv_type_boxed! { Detail_CompressedRectilinearProjector }
 impl Drop for Detail_CompressedRectilinearProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearProjector_delete(self.as_raw_mut_Detail_CompressedRectilinearProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CompressedRectilinearProjector_delete(self.as_raw_mut_Detail_CompressedRectilinearProjector()) };
+		}
 	}
 }
 
@@ -2793,7 +2861,9 @@ opencv_type_boxed! { Detail_CompressedRectilinearWarper }
 impl Drop for Detail_CompressedRectilinearWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CompressedRectilinearWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CompressedRectilinearWarper_delete(self.as_raw_mut_Detail_CompressedRectilinearWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CompressedRectilinearWarper_delete(self.as_raw_mut_Detail_CompressedRectilinearWarper()) };
+		}
 	}
 }
 
@@ -2847,7 +2917,9 @@ opencv_type_boxed! { Detail_CylindricalPortraitProjector }
 impl Drop for Detail_CylindricalPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalPortraitProjector_delete(self.as_raw_mut_Detail_CylindricalPortraitProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CylindricalPortraitProjector_delete(self.as_raw_mut_Detail_CylindricalPortraitProjector()) };
+		}
 	}
 }
 
@@ -2886,7 +2958,9 @@ opencv_type_boxed! { Detail_CylindricalPortraitWarper }
 impl Drop for Detail_CylindricalPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalPortraitWarper_delete(self.as_raw_mut_Detail_CylindricalPortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CylindricalPortraitWarper_delete(self.as_raw_mut_Detail_CylindricalPortraitWarper()) };
+		}
 	}
 }
 
@@ -2937,7 +3011,9 @@ opencv_type_boxed! { Detail_CylindricalProjector }
 impl Drop for Detail_CylindricalProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalProjector_delete(self.as_raw_mut_Detail_CylindricalProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CylindricalProjector_delete(self.as_raw_mut_Detail_CylindricalProjector()) };
+		}
 	}
 }
 
@@ -2994,7 +3070,9 @@ opencv_type_boxed! { Detail_CylindricalWarper }
 impl Drop for Detail_CylindricalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalWarper_delete(self.as_raw_mut_Detail_CylindricalWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CylindricalWarper_delete(self.as_raw_mut_Detail_CylindricalWarper()) };
+		}
 	}
 }
 
@@ -3069,7 +3147,9 @@ opencv_type_boxed! { Detail_CylindricalWarperGpu }
 impl Drop for Detail_CylindricalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_CylindricalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_CylindricalWarperGpu_delete(self.as_raw_mut_Detail_CylindricalWarperGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_CylindricalWarperGpu_delete(self.as_raw_mut_Detail_CylindricalWarperGpu()) };
+		}
 	}
 }
 
@@ -3145,7 +3225,9 @@ opencv_type_boxed! { Detail_DisjointSets }
 impl Drop for Detail_DisjointSets {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_DisjointSets_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_DisjointSets_delete(self.as_raw_mut_Detail_DisjointSets()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_DisjointSets_delete(self.as_raw_mut_Detail_DisjointSets()) };
+		}
 	}
 }
 
@@ -3202,7 +3284,9 @@ opencv_type_boxed! { Detail_DpSeamFinder }
 impl Drop for Detail_DpSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_DpSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_DpSeamFinder_delete(self.as_raw_mut_Detail_DpSeamFinder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_DpSeamFinder_delete(self.as_raw_mut_Detail_DpSeamFinder()) };
+		}
 	}
 }
 
@@ -3349,7 +3433,9 @@ opencv_type_boxed! { Detail_FeatherBlender }
 impl Drop for Detail_FeatherBlender {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_FeatherBlender_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_FeatherBlender_delete(self.as_raw_mut_Detail_FeatherBlender()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_FeatherBlender_delete(self.as_raw_mut_Detail_FeatherBlender()) };
+		}
 	}
 }
 
@@ -3420,7 +3506,9 @@ opencv_type_boxed! { Detail_FisheyeProjector }
 impl Drop for Detail_FisheyeProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_FisheyeProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_FisheyeProjector_delete(self.as_raw_mut_Detail_FisheyeProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_FisheyeProjector_delete(self.as_raw_mut_Detail_FisheyeProjector()) };
+		}
 	}
 }
 
@@ -3459,7 +3547,9 @@ opencv_type_boxed! { Detail_FisheyeWarper }
 impl Drop for Detail_FisheyeWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_FisheyeWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_FisheyeWarper_delete(self.as_raw_mut_Detail_FisheyeWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_FisheyeWarper_delete(self.as_raw_mut_Detail_FisheyeWarper()) };
+		}
 	}
 }
 
@@ -3544,7 +3634,9 @@ opencv_type_boxed! { Detail_GainCompensator }
 impl Drop for Detail_GainCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GainCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GainCompensator_delete(self.as_raw_mut_Detail_GainCompensator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_GainCompensator_delete(self.as_raw_mut_Detail_GainCompensator()) };
+		}
 	}
 }
 
@@ -3603,7 +3695,9 @@ opencv_type_boxed! { Detail_Graph }
 impl Drop for Detail_Graph {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_Graph_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_Graph_delete(self.as_raw_mut_Detail_Graph()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_Graph_delete(self.as_raw_mut_Detail_Graph()) };
+		}
 	}
 }
 
@@ -3649,7 +3743,9 @@ opencv_type_boxed! { Detail_GraphCutSeamFinder }
 impl Drop for Detail_GraphCutSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphCutSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphCutSeamFinder_delete(self.as_raw_mut_Detail_GraphCutSeamFinder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_GraphCutSeamFinder_delete(self.as_raw_mut_Detail_GraphCutSeamFinder()) };
+		}
 	}
 }
 
@@ -3711,7 +3807,9 @@ opencv_type_boxed! { Detail_GraphCutSeamFinderBase }
 impl Drop for Detail_GraphCutSeamFinderBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphCutSeamFinderBase_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphCutSeamFinderBase_delete(self.as_raw_mut_Detail_GraphCutSeamFinderBase()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_GraphCutSeamFinderBase_delete(self.as_raw_mut_Detail_GraphCutSeamFinderBase()) };
+		}
 	}
 }
 
@@ -3753,7 +3851,9 @@ opencv_type_boxed! { Detail_GraphCutSeamFinderGpu }
 impl Drop for Detail_GraphCutSeamFinderGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphCutSeamFinderGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphCutSeamFinderGpu_delete(self.as_raw_mut_Detail_GraphCutSeamFinderGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_GraphCutSeamFinderGpu_delete(self.as_raw_mut_Detail_GraphCutSeamFinderGpu()) };
+		}
 	}
 }
 
@@ -3834,7 +3934,9 @@ opencv_type_boxed! { Detail_GraphEdge }
 impl Drop for Detail_GraphEdge {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_GraphEdge_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_GraphEdge_delete(self.as_raw_mut_Detail_GraphEdge()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_GraphEdge_delete(self.as_raw_mut_Detail_GraphEdge()) };
+		}
 	}
 }
 
@@ -3874,7 +3976,9 @@ opencv_type_boxed! { Detail_HomographyBasedEstimator }
 impl Drop for Detail_HomographyBasedEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_HomographyBasedEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_HomographyBasedEstimator_delete(self.as_raw_mut_Detail_HomographyBasedEstimator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_HomographyBasedEstimator_delete(self.as_raw_mut_Detail_HomographyBasedEstimator()) };
+		}
 	}
 }
 
@@ -3957,7 +4061,9 @@ opencv_type_boxed! { Detail_ImageFeatures }
 impl Drop for Detail_ImageFeatures {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_ImageFeatures_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_ImageFeatures_delete(self.as_raw_mut_Detail_ImageFeatures()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_ImageFeatures_delete(self.as_raw_mut_Detail_ImageFeatures()) };
+		}
 	}
 }
 
@@ -4077,7 +4183,9 @@ opencv_type_boxed! { Detail_MatchesInfo }
 impl Drop for Detail_MatchesInfo {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MatchesInfo_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MatchesInfo_delete(self.as_raw_mut_Detail_MatchesInfo()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_MatchesInfo_delete(self.as_raw_mut_Detail_MatchesInfo()) };
+		}
 	}
 }
 
@@ -4127,7 +4235,9 @@ opencv_type_boxed! { Detail_MercatorProjector }
 impl Drop for Detail_MercatorProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MercatorProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MercatorProjector_delete(self.as_raw_mut_Detail_MercatorProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_MercatorProjector_delete(self.as_raw_mut_Detail_MercatorProjector()) };
+		}
 	}
 }
 
@@ -4166,7 +4276,9 @@ opencv_type_boxed! { Detail_MercatorWarper }
 impl Drop for Detail_MercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MercatorWarper_delete(self.as_raw_mut_Detail_MercatorWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_MercatorWarper_delete(self.as_raw_mut_Detail_MercatorWarper()) };
+		}
 	}
 }
 
@@ -4235,7 +4347,9 @@ opencv_type_boxed! { Detail_MultiBandBlender }
 impl Drop for Detail_MultiBandBlender {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_MultiBandBlender_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_MultiBandBlender_delete(self.as_raw_mut_Detail_MultiBandBlender()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_MultiBandBlender_delete(self.as_raw_mut_Detail_MultiBandBlender()) };
+		}
 	}
 }
 
@@ -4284,7 +4398,9 @@ opencv_type_boxed! { Detail_NoBundleAdjuster }
 impl Drop for Detail_NoBundleAdjuster {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_NoBundleAdjuster_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_NoBundleAdjuster_delete(self.as_raw_mut_Detail_NoBundleAdjuster()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_NoBundleAdjuster_delete(self.as_raw_mut_Detail_NoBundleAdjuster()) };
+		}
 	}
 }
 
@@ -4348,7 +4464,9 @@ opencv_type_boxed! { Detail_NoExposureCompensator }
 impl Drop for Detail_NoExposureCompensator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_NoExposureCompensator_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_NoExposureCompensator_delete(self.as_raw_mut_Detail_NoExposureCompensator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_NoExposureCompensator_delete(self.as_raw_mut_Detail_NoExposureCompensator()) };
+		}
 	}
 }
 
@@ -4393,7 +4511,9 @@ opencv_type_boxed! { Detail_NoSeamFinder }
 impl Drop for Detail_NoSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_NoSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_NoSeamFinder_delete(self.as_raw_mut_Detail_NoSeamFinder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_NoSeamFinder_delete(self.as_raw_mut_Detail_NoSeamFinder()) };
+		}
 	}
 }
 
@@ -4467,7 +4587,9 @@ opencv_type_boxed! { Detail_PaniniPortraitProjector }
 impl Drop for Detail_PaniniPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniPortraitProjector_delete(self.as_raw_mut_Detail_PaniniPortraitProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PaniniPortraitProjector_delete(self.as_raw_mut_Detail_PaniniPortraitProjector()) };
+		}
 	}
 }
 
@@ -4506,7 +4628,9 @@ opencv_type_boxed! { Detail_PaniniPortraitWarper }
 impl Drop for Detail_PaniniPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniPortraitWarper_delete(self.as_raw_mut_Detail_PaniniPortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PaniniPortraitWarper_delete(self.as_raw_mut_Detail_PaniniPortraitWarper()) };
+		}
 	}
 }
 
@@ -4576,7 +4700,9 @@ opencv_type_boxed! { Detail_PaniniProjector }
 impl Drop for Detail_PaniniProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniProjector_delete(self.as_raw_mut_Detail_PaniniProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PaniniProjector_delete(self.as_raw_mut_Detail_PaniniProjector()) };
+		}
 	}
 }
 
@@ -4615,7 +4741,9 @@ opencv_type_boxed! { Detail_PaniniWarper }
 impl Drop for Detail_PaniniWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PaniniWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PaniniWarper_delete(self.as_raw_mut_Detail_PaniniWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PaniniWarper_delete(self.as_raw_mut_Detail_PaniniWarper()) };
+		}
 	}
 }
 
@@ -4669,7 +4797,9 @@ opencv_type_boxed! { Detail_PlanePortraitProjector }
 impl Drop for Detail_PlanePortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlanePortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlanePortraitProjector_delete(self.as_raw_mut_Detail_PlanePortraitProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PlanePortraitProjector_delete(self.as_raw_mut_Detail_PlanePortraitProjector()) };
+		}
 	}
 }
 
@@ -4708,7 +4838,9 @@ opencv_type_boxed! { Detail_PlanePortraitWarper }
 impl Drop for Detail_PlanePortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlanePortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlanePortraitWarper_delete(self.as_raw_mut_Detail_PlanePortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PlanePortraitWarper_delete(self.as_raw_mut_Detail_PlanePortraitWarper()) };
+		}
 	}
 }
 
@@ -4759,7 +4891,9 @@ opencv_type_boxed! { Detail_PlaneProjector }
 impl Drop for Detail_PlaneProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlaneProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlaneProjector_delete(self.as_raw_mut_Detail_PlaneProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PlaneProjector_delete(self.as_raw_mut_Detail_PlaneProjector()) };
+		}
 	}
 }
 
@@ -4873,7 +5007,9 @@ opencv_type_boxed! { Detail_PlaneWarper }
 impl Drop for Detail_PlaneWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlaneWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlaneWarper_delete(self.as_raw_mut_Detail_PlaneWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PlaneWarper_delete(self.as_raw_mut_Detail_PlaneWarper()) };
+		}
 	}
 }
 
@@ -4983,7 +5119,9 @@ opencv_type_boxed! { Detail_PlaneWarperGpu }
 impl Drop for Detail_PlaneWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_PlaneWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_PlaneWarperGpu_delete(self.as_raw_mut_Detail_PlaneWarperGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_PlaneWarperGpu_delete(self.as_raw_mut_Detail_PlaneWarperGpu()) };
+		}
 	}
 }
 
@@ -5074,7 +5212,9 @@ opencv_type_boxed! { Detail_ProjectorBase }
 impl Drop for Detail_ProjectorBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_ProjectorBase_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_ProjectorBase_delete(self.as_raw_mut_Detail_ProjectorBase()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_ProjectorBase_delete(self.as_raw_mut_Detail_ProjectorBase()) };
+		}
 	}
 }
 
@@ -5241,7 +5381,9 @@ opencv_type_boxed! { Detail_SphericalPortraitProjector }
 impl Drop for Detail_SphericalPortraitProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalPortraitProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalPortraitProjector_delete(self.as_raw_mut_Detail_SphericalPortraitProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_SphericalPortraitProjector_delete(self.as_raw_mut_Detail_SphericalPortraitProjector()) };
+		}
 	}
 }
 
@@ -5280,7 +5422,9 @@ opencv_type_boxed! { Detail_SphericalPortraitWarper }
 impl Drop for Detail_SphericalPortraitWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalPortraitWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalPortraitWarper_delete(self.as_raw_mut_Detail_SphericalPortraitWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_SphericalPortraitWarper_delete(self.as_raw_mut_Detail_SphericalPortraitWarper()) };
+		}
 	}
 }
 
@@ -5368,7 +5512,9 @@ opencv_type_boxed! { Detail_SphericalWarper }
 impl Drop for Detail_SphericalWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalWarper_delete(self.as_raw_mut_Detail_SphericalWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_SphericalWarper_delete(self.as_raw_mut_Detail_SphericalWarper()) };
+		}
 	}
 }
 
@@ -5444,7 +5590,9 @@ opencv_type_boxed! { Detail_SphericalWarperGpu }
 impl Drop for Detail_SphericalWarperGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_SphericalWarperGpu_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_SphericalWarperGpu_delete(self.as_raw_mut_Detail_SphericalWarperGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_SphericalWarperGpu_delete(self.as_raw_mut_Detail_SphericalWarperGpu()) };
+		}
 	}
 }
 
@@ -5500,7 +5648,9 @@ opencv_type_boxed! { Detail_StereographicProjector }
 impl Drop for Detail_StereographicProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_StereographicProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_StereographicProjector_delete(self.as_raw_mut_Detail_StereographicProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_StereographicProjector_delete(self.as_raw_mut_Detail_StereographicProjector()) };
+		}
 	}
 }
 
@@ -5539,7 +5689,9 @@ opencv_type_boxed! { Detail_StereographicWarper }
 impl Drop for Detail_StereographicWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_StereographicWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_StereographicWarper_delete(self.as_raw_mut_Detail_StereographicWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_StereographicWarper_delete(self.as_raw_mut_Detail_StereographicWarper()) };
+		}
 	}
 }
 
@@ -5590,7 +5742,9 @@ opencv_type_boxed! { Detail_TransverseMercatorProjector }
 impl Drop for Detail_TransverseMercatorProjector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_TransverseMercatorProjector_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_TransverseMercatorProjector_delete(self.as_raw_mut_Detail_TransverseMercatorProjector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_TransverseMercatorProjector_delete(self.as_raw_mut_Detail_TransverseMercatorProjector()) };
+		}
 	}
 }
 
@@ -5629,7 +5783,9 @@ opencv_type_boxed! { Detail_TransverseMercatorWarper }
 impl Drop for Detail_TransverseMercatorWarper {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_TransverseMercatorWarper_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_TransverseMercatorWarper_delete(self.as_raw_mut_Detail_TransverseMercatorWarper()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_TransverseMercatorWarper_delete(self.as_raw_mut_Detail_TransverseMercatorWarper()) };
+		}
 	}
 }
 
@@ -5682,7 +5838,9 @@ opencv_type_boxed! { Detail_VoronoiSeamFinder }
 impl Drop for Detail_VoronoiSeamFinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Detail_VoronoiSeamFinder_delete(instance: *mut c_void); }
-		unsafe { cv_Detail_VoronoiSeamFinder_delete(self.as_raw_mut_Detail_VoronoiSeamFinder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Detail_VoronoiSeamFinder_delete(self.as_raw_mut_Detail_VoronoiSeamFinder()) };
+		}
 	}
 }
 
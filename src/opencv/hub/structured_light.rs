@@ -166,7 +166,9 @@ opencv_type_boxed! { GrayCodePattern_Params }
 impl Drop for GrayCodePattern_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GrayCodePattern_Params_delete(instance: *mut c_void); }
-		unsafe { cv_GrayCodePattern_Params_delete(self.as_raw_mut_GrayCodePattern_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GrayCodePattern_Params_delete(self.as_raw_mut_GrayCodePattern_Params()) };
+		}
 	}
 }
 
@@ -376,7 +378,9 @@ opencv_type_boxed! { SinusoidalPattern_Params }
 impl Drop for SinusoidalPattern_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SinusoidalPattern_Params_delete(instance: *mut c_void); }
-		unsafe { cv_SinusoidalPattern_Params_delete(self.as_raw_mut_SinusoidalPattern_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SinusoidalPattern_Params_delete(self.as_raw_mut_SinusoidalPattern_Params()) };
+		}
 	}
 }
 
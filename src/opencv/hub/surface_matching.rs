@@ -447,7 +447,9 @@ opencv_type_boxed! { ICP }
 impl Drop for ICP {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ICP_delete(instance: *mut c_void); }
-		unsafe { cv_ICP_delete(self.as_raw_mut_ICP()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ICP_delete(self.as_raw_mut_ICP()) };
+		}
 	}
 }
 
@@ -571,7 +573,9 @@ opencv_type_boxed! { PPF3DDetector }
 impl Drop for PPF3DDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PPF3DDetector_delete(instance: *mut c_void); }
-		unsafe { cv_PPF3DDetector_delete(self.as_raw_mut_PPF3DDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PPF3DDetector_delete(self.as_raw_mut_PPF3DDetector()) };
+		}
 	}
 }
 
@@ -733,7 +737,9 @@ opencv_type_boxed! { Pose3D }
 impl Drop for Pose3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Pose3D_delete(instance: *mut c_void); }
-		unsafe { cv_Pose3D_delete(self.as_raw_mut_Pose3D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Pose3D_delete(self.as_raw_mut_Pose3D()) };
+		}
 	}
 }
 
@@ -825,7 +831,9 @@ opencv_type_boxed! { PoseCluster3D }
 impl Drop for PoseCluster3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PoseCluster3D_delete(instance: *mut c_void); }
-		unsafe { cv_PoseCluster3D_delete(self.as_raw_mut_PoseCluster3D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PoseCluster3D_delete(self.as_raw_mut_PoseCluster3D()) };
+		}
 	}
 }
 
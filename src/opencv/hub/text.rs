@@ -844,7 +844,9 @@ opencv_type_boxed! { ERStat }
 impl Drop for ERStat {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ERStat_delete(instance: *mut c_void); }
-		unsafe { cv_ERStat_delete(self.as_raw_mut_ERStat()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ERStat_delete(self.as_raw_mut_ERStat()) };
+		}
 	}
 }
 
@@ -964,7 +966,9 @@ opencv_type_boxed! { OCRBeamSearchDecoder }
 impl Drop for OCRBeamSearchDecoder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRBeamSearchDecoder_delete(instance: *mut c_void); }
-		unsafe { cv_OCRBeamSearchDecoder_delete(self.as_raw_mut_OCRBeamSearchDecoder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OCRBeamSearchDecoder_delete(self.as_raw_mut_OCRBeamSearchDecoder()) };
+		}
 	}
 }
 
@@ -1103,7 +1107,9 @@ opencv_type_boxed! { OCRBeamSearchDecoder_ClassifierCallback }
 impl Drop for OCRBeamSearchDecoder_ClassifierCallback {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRBeamSearchDecoder_ClassifierCallback_delete(instance: *mut c_void); }
-		unsafe { cv_OCRBeamSearchDecoder_ClassifierCallback_delete(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OCRBeamSearchDecoder_ClassifierCallback_delete(self.as_raw_mut_OCRBeamSearchDecoder_ClassifierCallback()) };
+		}
 	}
 }
 
@@ -1236,7 +1242,9 @@ opencv_type_boxed! { OCRHMMDecoder }
 impl Drop for OCRHMMDecoder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRHMMDecoder_delete(instance: *mut c_void); }
-		unsafe { cv_OCRHMMDecoder_delete(self.as_raw_mut_OCRHMMDecoder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OCRHMMDecoder_delete(self.as_raw_mut_OCRHMMDecoder()) };
+		}
 	}
 }
 
@@ -1362,7 +1370,9 @@ opencv_type_boxed! { OCRHMMDecoder_ClassifierCallback }
 impl Drop for OCRHMMDecoder_ClassifierCallback {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OCRHMMDecoder_ClassifierCallback_delete(instance: *mut c_void); }
-		unsafe { cv_OCRHMMDecoder_ClassifierCallback_delete(self.as_raw_mut_OCRHMMDecoder_ClassifierCallback()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OCRHMMDecoder_ClassifierCallback_delete(self.as_raw_mut_OCRHMMDecoder_ClassifierCallback()) };
+		}
 	}
 }
 
@@ -292,7 +292,9 @@ opencv_type_boxed! { TrackerCSRT_Params }
 impl Drop for TrackerCSRT_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TrackerCSRT_Params_delete(instance: *mut c_void); }
-		unsafe { cv_TrackerCSRT_Params_delete(self.as_raw_mut_TrackerCSRT_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TrackerCSRT_Params_delete(self.as_raw_mut_TrackerCSRT_Params()) };
+		}
 	}
 }
 
@@ -634,7 +634,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::DMatch, *const c_void, *mut c_void,
 		cv_VectorOfDMatch_data, cv_VectorOfDMatch_data_mut,
-		cv_VectorOfDMatch_clone,
+		cv_VectorOfDMatch_clone, cv_VectorOfDMatch_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::DMatch> {}
@@ -677,7 +677,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::KeyPoint, *const c_void, *mut c_void,
 		cv_VectorOfKeyPoint_data, cv_VectorOfKeyPoint_data_mut,
-		cv_VectorOfKeyPoint_clone,
+		cv_VectorOfKeyPoint_clone, cv_VectorOfKeyPoint_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::KeyPoint> {}
@@ -740,7 +740,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Point, *const c_void, *mut c_void,
 		cv_VectorOfPoint_data, cv_VectorOfPoint_data_mut,
-		cv_VectorOfPoint_clone,
+		cv_VectorOfPoint_clone, cv_VectorOfPoint_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point> {}
@@ -814,7 +814,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Point2d, *const c_void, *mut c_void,
 		cv_VectorOfPoint2d_data, cv_VectorOfPoint2d_data_mut,
-		cv_VectorOfPoint2d_clone,
+		cv_VectorOfPoint2d_clone, cv_VectorOfPoint2d_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point2d> {}
@@ -888,7 +888,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Point2f, *const c_void, *mut c_void,
 		cv_VectorOfPoint2f_data, cv_VectorOfPoint2f_data_mut,
-		cv_VectorOfPoint2f_clone,
+		cv_VectorOfPoint2f_clone, cv_VectorOfPoint2f_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point2f> {}
@@ -962,7 +962,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Point3d, *const c_void, *mut c_void,
 		cv_VectorOfPoint3d_data, cv_VectorOfPoint3d_data_mut,
-		cv_VectorOfPoint3d_clone,
+		cv_VectorOfPoint3d_clone, cv_VectorOfPoint3d_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point3d> {}
@@ -1036,7 +1036,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Point3f, *const c_void, *mut c_void,
 		cv_VectorOfPoint3f_data, cv_VectorOfPoint3f_data_mut,
-		cv_VectorOfPoint3f_clone,
+		cv_VectorOfPoint3f_clone, cv_VectorOfPoint3f_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point3f> {}
@@ -1110,7 +1110,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Point3i, *const c_void, *mut c_void,
 		cv_VectorOfPoint3i_data, cv_VectorOfPoint3i_data_mut,
-		cv_VectorOfPoint3i_clone,
+		cv_VectorOfPoint3i_clone, cv_VectorOfPoint3i_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Point3i> {}
@@ -1204,7 +1204,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Rect, *const c_void, *mut c_void,
 		cv_VectorOfRect_data, cv_VectorOfRect_data_mut,
-		cv_VectorOfRect_clone,
+		cv_VectorOfRect_clone, cv_VectorOfRect_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Rect> {}
@@ -1278,7 +1278,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Rect2d, *const c_void, *mut c_void,
 		cv_VectorOfRect2d_data, cv_VectorOfRect2d_data_mut,
-		cv_VectorOfRect2d_clone,
+		cv_VectorOfRect2d_clone, cv_VectorOfRect2d_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Rect2d> {}
@@ -1372,7 +1372,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Scalar, *const c_void, *mut c_void,
 		cv_VectorOfScalar_data, cv_VectorOfScalar_data_mut,
-		cv_VectorOfScalar_clone,
+		cv_VectorOfScalar_clone, cv_VectorOfScalar_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Scalar> {}
@@ -1446,7 +1446,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Size, *const c_void, *mut c_void,
 		cv_VectorOfSize_data, cv_VectorOfSize_data_mut,
-		cv_VectorOfSize_clone,
+		cv_VectorOfSize_clone, cv_VectorOfSize_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Size> {}
@@ -1560,7 +1560,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec2i, *const c_void, *mut c_void,
 		cv_VectorOfVec2i_data, cv_VectorOfVec2i_data_mut,
-		cv_VectorOfVec2i_clone,
+		cv_VectorOfVec2i_clone, cv_VectorOfVec2i_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec2i> {}
@@ -1634,7 +1634,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec3d, *const c_void, *mut c_void,
 		cv_VectorOfVec3d_data, cv_VectorOfVec3d_data_mut,
-		cv_VectorOfVec3d_clone,
+		cv_VectorOfVec3d_clone, cv_VectorOfVec3d_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec3d> {}
@@ -1708,7 +1708,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec3f, *const c_void, *mut c_void,
 		cv_VectorOfVec3f_data, cv_VectorOfVec3f_data_mut,
-		cv_VectorOfVec3f_clone,
+		cv_VectorOfVec3f_clone, cv_VectorOfVec3f_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec3f> {}
@@ -1782,7 +1782,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec3i, *const c_void, *mut c_void,
 		cv_VectorOfVec3i_data, cv_VectorOfVec3i_data_mut,
-		cv_VectorOfVec3i_clone,
+		cv_VectorOfVec3i_clone, cv_VectorOfVec3i_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec3i> {}
@@ -1856,7 +1856,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec4f, *const c_void, *mut c_void,
 		cv_VectorOfVec4f_data, cv_VectorOfVec4f_data_mut,
-		cv_VectorOfVec4f_clone,
+		cv_VectorOfVec4f_clone, cv_VectorOfVec4f_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec4f> {}
@@ -1930,7 +1930,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec4i, *const c_void, *mut c_void,
 		cv_VectorOfVec4i_data, cv_VectorOfVec4i_data_mut,
-		cv_VectorOfVec4i_clone,
+		cv_VectorOfVec4i_clone, cv_VectorOfVec4i_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec4i> {}
@@ -2004,7 +2004,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { core::Vec6f, *const c_void, *mut c_void,
 		cv_VectorOfVec6f_data, cv_VectorOfVec6f_data_mut,
-		cv_VectorOfVec6f_clone,
+		cv_VectorOfVec6f_clone, cv_VectorOfVec6f_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<core::Vec6f> {}
@@ -2888,7 +2888,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { f32, *const c_void, *mut c_void,
 		cv_VectorOff32_data, cv_VectorOff32_data_mut,
-		cv_VectorOff32_clone,
+		cv_VectorOff32_clone, cv_VectorOff32_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<f32> {}
@@ -2962,7 +2962,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { f64, *const c_void, *mut c_void,
 		cv_VectorOff64_data, cv_VectorOff64_data_mut,
-		cv_VectorOff64_clone,
+		cv_VectorOff64_clone, cv_VectorOff64_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<f64> {}
@@ -3036,7 +3036,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { i32, *const c_void, *mut c_void,
 		cv_VectorOfi32_data, cv_VectorOfi32_data_mut,
-		cv_VectorOfi32_clone,
+		cv_VectorOfi32_clone, cv_VectorOfi32_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<i32> {}
@@ -3110,7 +3110,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { i8, *const c_void, *mut c_void,
 		cv_VectorOfi8_data, cv_VectorOfi8_data_mut,
-		cv_VectorOfi8_clone,
+		cv_VectorOfi8_clone, cv_VectorOfi8_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<i8> {}
@@ -3184,7 +3184,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { size_t, *const c_void, *mut c_void,
 		cv_VectorOfsize_t_data, cv_VectorOfsize_t_data_mut,
-		cv_VectorOfsize_t_clone,
+		cv_VectorOfsize_t_clone, cv_VectorOfsize_t_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<size_t> {}
@@ -3207,7 +3207,7 @@ mod core_types {
 	}
 	vector_copy_non_bool! { u8, *const c_void, *mut c_void,
 		cv_VectorOfu8_data, cv_VectorOfu8_data_mut,
-		cv_VectorOfu8_clone,
+		cv_VectorOfu8_clone, cv_VectorOfu8_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<u8> {}
@@ -5516,7 +5516,7 @@ mod dnn_types {
 	}
 	vector_copy_non_bool! { crate::dnn::Target, *const c_void, *mut c_void,
 		cv_VectorOfTarget_data, cv_VectorOfTarget_data_mut,
-		cv_VectorOfTarget_clone,
+		cv_VectorOfTarget_clone, cv_VectorOfTarget_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<crate::dnn::Target> {}
@@ -6452,7 +6452,7 @@ mod flann_types {
 	}
 	vector_copy_non_bool! { crate::flann::FlannIndexType, *const c_void, *mut c_void,
 		cv_VectorOfFlannIndexType_data, cv_VectorOfFlannIndexType_data_mut,
-		cv_VectorOfFlannIndexType_clone,
+		cv_VectorOfFlannIndexType_clone, cv_VectorOfFlannIndexType_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<crate::flann::FlannIndexType> {}
@@ -6912,7 +6912,7 @@ mod line_descriptor_types {
 	}
 	vector_copy_non_bool! { crate::line_descriptor::KeyLine, *const c_void, *mut c_void,
 		cv_VectorOfKeyLine_data, cv_VectorOfKeyLine_data_mut,
-		cv_VectorOfKeyLine_clone,
+		cv_VectorOfKeyLine_clone, cv_VectorOfKeyLine_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<crate::line_descriptor::KeyLine> {}
@@ -8750,7 +8750,7 @@ mod rgbd_types {
 	}
 	vector_copy_non_bool! { crate::rgbd::Linemod_Feature, *const c_void, *mut c_void,
 		cv_VectorOfLinemod_Feature_data, cv_VectorOfLinemod_Feature_data_mut,
-		cv_VectorOfLinemod_Feature_clone,
+		cv_VectorOfLinemod_Feature_clone, cv_VectorOfLinemod_Feature_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<crate::rgbd::Linemod_Feature> {}
@@ -9308,7 +9308,7 @@ mod stereo_types {
 	}
 	vector_copy_non_bool! { crate::stereo::MatchQuasiDense, *const c_void, *mut c_void,
 		cv_VectorOfMatchQuasiDense_data, cv_VectorOfMatchQuasiDense_data_mut,
-		cv_VectorOfMatchQuasiDense_clone,
+		cv_VectorOfMatchQuasiDense_clone, cv_VectorOfMatchQuasiDense_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<crate::stereo::MatchQuasiDense> {}
@@ -11366,7 +11366,7 @@ mod videoio_types {
 	}
 	vector_copy_non_bool! { crate::videoio::VideoCaptureAPIs, *const c_void, *mut c_void,
 		cv_VectorOfVideoCaptureAPIs_data, cv_VectorOfVideoCaptureAPIs_data_mut,
-		cv_VectorOfVideoCaptureAPIs_clone,
+		cv_VectorOfVideoCaptureAPIs_clone, cv_VectorOfVideoCaptureAPIs_from_slice,
 	}
 	
 	unsafe impl Send for core::Vector::<crate::videoio::VideoCaptureAPIs> {}
@@ -1277,7 +1277,9 @@ opencv_type_boxed! { KalmanFilter }
 impl Drop for KalmanFilter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KalmanFilter_delete(instance: *mut c_void); }
-		unsafe { cv_KalmanFilter_delete(self.as_raw_mut_KalmanFilter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KalmanFilter_delete(self.as_raw_mut_KalmanFilter()) };
+		}
 	}
 }
 
@@ -1503,7 +1505,9 @@ opencv_type_boxed! { TrackerGOTURN_Params }
 impl Drop for TrackerGOTURN_Params {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TrackerGOTURN_Params_delete(instance: *mut c_void); }
-		unsafe { cv_TrackerGOTURN_Params_delete(self.as_raw_mut_TrackerGOTURN_Params()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TrackerGOTURN_Params_delete(self.as_raw_mut_TrackerGOTURN_Params()) };
+		}
 	}
 }
 
@@ -1210,7 +1210,9 @@ opencv_type_boxed! { VideoCapture }
 impl Drop for VideoCapture {
 	fn drop(&mut self) {
 		extern "C" { fn cv_VideoCapture_delete(instance: *mut c_void); }
-		unsafe { cv_VideoCapture_delete(self.as_raw_mut_VideoCapture()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_VideoCapture_delete(self.as_raw_mut_VideoCapture()) };
+		}
 	}
 }
 
@@ -1491,7 +1493,9 @@ opencv_type_boxed! { VideoWriter }
 impl Drop for VideoWriter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_VideoWriter_delete(instance: *mut c_void); }
-		unsafe { cv_VideoWriter_delete(self.as_raw_mut_VideoWriter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_VideoWriter_delete(self.as_raw_mut_VideoWriter()) };
+		}
 	}
 }
 
@@ -1615,3 +1619,4 @@ impl VideoWriter {
 	}
 	
 }
+pub use crate::manual::videoio::*;
@@ -154,7 +154,9 @@ opencv_type_boxed! { ColorAverageInpainter }
 impl Drop for ColorAverageInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorAverageInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_ColorAverageInpainter_delete(self.as_raw_mut_ColorAverageInpainter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ColorAverageInpainter_delete(self.as_raw_mut_ColorAverageInpainter()) };
+		}
 	}
 }
 
@@ -197,7 +199,9 @@ opencv_type_boxed! { ColorInpainter }
 impl Drop for ColorInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ColorInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_ColorInpainter_delete(self.as_raw_mut_ColorInpainter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ColorInpainter_delete(self.as_raw_mut_ColorInpainter()) };
+		}
 	}
 }
 
@@ -255,7 +259,9 @@ opencv_type_boxed! { ConsistentMosaicInpainter }
 impl Drop for ConsistentMosaicInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ConsistentMosaicInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_ConsistentMosaicInpainter_delete(self.as_raw_mut_ConsistentMosaicInpainter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ConsistentMosaicInpainter_delete(self.as_raw_mut_ConsistentMosaicInpainter()) };
+		}
 	}
 }
 
@@ -349,7 +355,9 @@ opencv_type_boxed! { DensePyrLkOptFlowEstimatorGpu }
 impl Drop for DensePyrLkOptFlowEstimatorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_DensePyrLkOptFlowEstimatorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_DensePyrLkOptFlowEstimatorGpu_delete(self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_DensePyrLkOptFlowEstimatorGpu_delete(self.as_raw_mut_DensePyrLkOptFlowEstimatorGpu()) };
+		}
 	}
 }
 
@@ -409,7 +417,9 @@ opencv_type_boxed! { FastMarchingMethod }
 impl Drop for FastMarchingMethod {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FastMarchingMethod_delete(instance: *mut c_void); }
-		unsafe { cv_FastMarchingMethod_delete(self.as_raw_mut_FastMarchingMethod()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FastMarchingMethod_delete(self.as_raw_mut_FastMarchingMethod()) };
+		}
 	}
 }
 
@@ -453,7 +463,9 @@ opencv_type_boxed! { FromFileMotionReader }
 impl Drop for FromFileMotionReader {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FromFileMotionReader_delete(instance: *mut c_void); }
-		unsafe { cv_FromFileMotionReader_delete(self.as_raw_mut_FromFileMotionReader()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FromFileMotionReader_delete(self.as_raw_mut_FromFileMotionReader()) };
+		}
 	}
 }
 
@@ -515,7 +527,9 @@ opencv_type_boxed! { GaussianMotionFilter }
 impl Drop for GaussianMotionFilter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_GaussianMotionFilter_delete(instance: *mut c_void); }
-		unsafe { cv_GaussianMotionFilter_delete(self.as_raw_mut_GaussianMotionFilter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_GaussianMotionFilter_delete(self.as_raw_mut_GaussianMotionFilter()) };
+		}
 	}
 }
 
@@ -766,7 +780,9 @@ opencv_type_boxed! { InpaintingPipeline }
 impl Drop for InpaintingPipeline {
 	fn drop(&mut self) {
 		extern "C" { fn cv_InpaintingPipeline_delete(instance: *mut c_void); }
-		unsafe { cv_InpaintingPipeline_delete(self.as_raw_mut_InpaintingPipeline()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_InpaintingPipeline_delete(self.as_raw_mut_InpaintingPipeline()) };
+		}
 	}
 }
 
@@ -860,7 +876,9 @@ opencv_type_boxed! { KeypointBasedMotionEstimator }
 impl Drop for KeypointBasedMotionEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeypointBasedMotionEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_KeypointBasedMotionEstimator_delete(self.as_raw_mut_KeypointBasedMotionEstimator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KeypointBasedMotionEstimator_delete(self.as_raw_mut_KeypointBasedMotionEstimator()) };
+		}
 	}
 }
 
@@ -931,7 +949,9 @@ opencv_type_boxed! { KeypointBasedMotionEstimatorGpu }
 impl Drop for KeypointBasedMotionEstimatorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeypointBasedMotionEstimatorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_KeypointBasedMotionEstimatorGpu_delete(self.as_raw_mut_KeypointBasedMotionEstimatorGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KeypointBasedMotionEstimatorGpu_delete(self.as_raw_mut_KeypointBasedMotionEstimatorGpu()) };
+		}
 	}
 }
 
@@ -979,7 +999,9 @@ opencv_type_boxed! { LogToStdout }
 impl Drop for LogToStdout {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LogToStdout_delete(instance: *mut c_void); }
-		unsafe { cv_LogToStdout_delete(self.as_raw_mut_LogToStdout()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LogToStdout_delete(self.as_raw_mut_LogToStdout()) };
+		}
 	}
 }
 
@@ -1078,7 +1100,9 @@ opencv_type_boxed! { LpMotionStabilizer }
 impl Drop for LpMotionStabilizer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LpMotionStabilizer_delete(instance: *mut c_void); }
-		unsafe { cv_LpMotionStabilizer_delete(self.as_raw_mut_LpMotionStabilizer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LpMotionStabilizer_delete(self.as_raw_mut_LpMotionStabilizer()) };
+		}
 	}
 }
 
@@ -1131,7 +1155,9 @@ opencv_type_boxed! { MaskFrameSource }
 impl Drop for MaskFrameSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MaskFrameSource_delete(instance: *mut c_void); }
-		unsafe { cv_MaskFrameSource_delete(self.as_raw_mut_MaskFrameSource()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MaskFrameSource_delete(self.as_raw_mut_MaskFrameSource()) };
+		}
 	}
 }
 
@@ -1178,7 +1204,9 @@ opencv_type_boxed! { MoreAccurateMotionWobbleSuppressor }
 impl Drop for MoreAccurateMotionWobbleSuppressor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MoreAccurateMotionWobbleSuppressor_delete(instance: *mut c_void); }
-		unsafe { cv_MoreAccurateMotionWobbleSuppressor_delete(self.as_raw_mut_MoreAccurateMotionWobbleSuppressor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MoreAccurateMotionWobbleSuppressor_delete(self.as_raw_mut_MoreAccurateMotionWobbleSuppressor()) };
+		}
 	}
 }
 
@@ -1244,7 +1272,9 @@ opencv_type_boxed! { MoreAccurateMotionWobbleSuppressorGpu }
 impl Drop for MoreAccurateMotionWobbleSuppressorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MoreAccurateMotionWobbleSuppressorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_MoreAccurateMotionWobbleSuppressorGpu_delete(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MoreAccurateMotionWobbleSuppressorGpu_delete(self.as_raw_mut_MoreAccurateMotionWobbleSuppressorGpu()) };
+		}
 	}
 }
 
@@ -1342,7 +1372,9 @@ opencv_type_boxed! { MotionEstimatorL1 }
 impl Drop for MotionEstimatorL1 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionEstimatorL1_delete(instance: *mut c_void); }
-		unsafe { cv_MotionEstimatorL1_delete(self.as_raw_mut_MotionEstimatorL1()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MotionEstimatorL1_delete(self.as_raw_mut_MotionEstimatorL1()) };
+		}
 	}
 }
 
@@ -1413,7 +1445,9 @@ opencv_type_boxed! { MotionEstimatorRansacL2 }
 impl Drop for MotionEstimatorRansacL2 {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionEstimatorRansacL2_delete(instance: *mut c_void); }
-		unsafe { cv_MotionEstimatorRansacL2_delete(self.as_raw_mut_MotionEstimatorRansacL2()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MotionEstimatorRansacL2_delete(self.as_raw_mut_MotionEstimatorRansacL2()) };
+		}
 	}
 }
 
@@ -1508,7 +1542,9 @@ opencv_type_boxed! { MotionInpainter }
 impl Drop for MotionInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_MotionInpainter_delete(self.as_raw_mut_MotionInpainter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MotionInpainter_delete(self.as_raw_mut_MotionInpainter()) };
+		}
 	}
 }
 
@@ -1563,7 +1599,9 @@ opencv_type_boxed! { MotionStabilizationPipeline }
 impl Drop for MotionStabilizationPipeline {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MotionStabilizationPipeline_delete(instance: *mut c_void); }
-		unsafe { cv_MotionStabilizationPipeline_delete(self.as_raw_mut_MotionStabilizationPipeline()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MotionStabilizationPipeline_delete(self.as_raw_mut_MotionStabilizationPipeline()) };
+		}
 	}
 }
 
@@ -1606,7 +1644,9 @@ opencv_type_boxed! { NullDeblurer }
 impl Drop for NullDeblurer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullDeblurer_delete(instance: *mut c_void); }
-		unsafe { cv_NullDeblurer_delete(self.as_raw_mut_NullDeblurer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NullDeblurer_delete(self.as_raw_mut_NullDeblurer()) };
+		}
 	}
 }
 
@@ -1653,7 +1693,9 @@ opencv_type_boxed! { NullFrameSource }
 impl Drop for NullFrameSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullFrameSource_delete(instance: *mut c_void); }
-		unsafe { cv_NullFrameSource_delete(self.as_raw_mut_NullFrameSource()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NullFrameSource_delete(self.as_raw_mut_NullFrameSource()) };
+		}
 	}
 }
 
@@ -1696,7 +1738,9 @@ opencv_type_boxed! { NullInpainter }
 impl Drop for NullInpainter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullInpainter_delete(instance: *mut c_void); }
-		unsafe { cv_NullInpainter_delete(self.as_raw_mut_NullInpainter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NullInpainter_delete(self.as_raw_mut_NullInpainter()) };
+		}
 	}
 }
 
@@ -1740,7 +1784,9 @@ opencv_type_boxed! { NullLog }
 impl Drop for NullLog {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullLog_delete(instance: *mut c_void); }
-		unsafe { cv_NullLog_delete(self.as_raw_mut_NullLog()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NullLog_delete(self.as_raw_mut_NullLog()) };
+		}
 	}
 }
 
@@ -1786,7 +1832,9 @@ opencv_type_boxed! { NullOutlierRejector }
 impl Drop for NullOutlierRejector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullOutlierRejector_delete(instance: *mut c_void); }
-		unsafe { cv_NullOutlierRejector_delete(self.as_raw_mut_NullOutlierRejector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NullOutlierRejector_delete(self.as_raw_mut_NullOutlierRejector()) };
+		}
 	}
 }
 
@@ -1829,7 +1877,9 @@ opencv_type_boxed! { NullWobbleSuppressor }
 impl Drop for NullWobbleSuppressor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_NullWobbleSuppressor_delete(instance: *mut c_void); }
-		unsafe { cv_NullWobbleSuppressor_delete(self.as_raw_mut_NullWobbleSuppressor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_NullWobbleSuppressor_delete(self.as_raw_mut_NullWobbleSuppressor()) };
+		}
 	}
 }
 
@@ -1884,7 +1934,9 @@ opencv_type_boxed! { OnePassStabilizer }
 impl Drop for OnePassStabilizer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_OnePassStabilizer_delete(instance: *mut c_void); }
-		unsafe { cv_OnePassStabilizer_delete(self.as_raw_mut_OnePassStabilizer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_OnePassStabilizer_delete(self.as_raw_mut_OnePassStabilizer()) };
+		}
 	}
 }
 
@@ -1948,7 +2000,9 @@ opencv_type_boxed! { PyrLkOptFlowEstimatorBase }
 impl Drop for PyrLkOptFlowEstimatorBase {
 	fn drop(&mut self) {
 		extern "C" { fn cv_PyrLkOptFlowEstimatorBase_delete(instance: *mut c_void); }
-		unsafe { cv_PyrLkOptFlowEstimatorBase_delete(self.as_raw_mut_PyrLkOptFlowEstimatorBase()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_PyrLkOptFlowEstimatorBase_delete(self.as_raw_mut_PyrLkOptFlowEstimatorBase()) };
+		}
 	}
 }
 
@@ -2034,7 +2088,9 @@ opencv_type_boxed! { RansacParams }
 impl Drop for RansacParams {
 	fn drop(&mut self) {
 		extern "C" { fn cv_RansacParams_delete(instance: *mut c_void); }
-		unsafe { cv_RansacParams_delete(self.as_raw_mut_RansacParams()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_RansacParams_delete(self.as_raw_mut_RansacParams()) };
+		}
 	}
 }
 
@@ -2100,7 +2156,9 @@ opencv_type_boxed! { SparsePyrLkOptFlowEstimator }
 impl Drop for SparsePyrLkOptFlowEstimator {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparsePyrLkOptFlowEstimator_delete(instance: *mut c_void); }
-		unsafe { cv_SparsePyrLkOptFlowEstimator_delete(self.as_raw_mut_SparsePyrLkOptFlowEstimator()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparsePyrLkOptFlowEstimator_delete(self.as_raw_mut_SparsePyrLkOptFlowEstimator()) };
+		}
 	}
 }
 
@@ -2162,7 +2220,9 @@ opencv_type_boxed! { SparsePyrLkOptFlowEstimatorGpu }
 impl Drop for SparsePyrLkOptFlowEstimatorGpu {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SparsePyrLkOptFlowEstimatorGpu_delete(instance: *mut c_void); }
-		unsafe { cv_SparsePyrLkOptFlowEstimatorGpu_delete(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SparsePyrLkOptFlowEstimatorGpu_delete(self.as_raw_mut_SparsePyrLkOptFlowEstimatorGpu()) };
+		}
 	}
 }
 
@@ -2315,7 +2375,9 @@ opencv_type_boxed! { ToFileMotionWriter }
 impl Drop for ToFileMotionWriter {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ToFileMotionWriter_delete(instance: *mut c_void); }
-		unsafe { cv_ToFileMotionWriter_delete(self.as_raw_mut_ToFileMotionWriter()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ToFileMotionWriter_delete(self.as_raw_mut_ToFileMotionWriter()) };
+		}
 	}
 }
 
@@ -2382,7 +2444,9 @@ opencv_type_boxed! { TranslationBasedLocalOutlierRejector }
 impl Drop for TranslationBasedLocalOutlierRejector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TranslationBasedLocalOutlierRejector_delete(instance: *mut c_void); }
-		unsafe { cv_TranslationBasedLocalOutlierRejector_delete(self.as_raw_mut_TranslationBasedLocalOutlierRejector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TranslationBasedLocalOutlierRejector_delete(self.as_raw_mut_TranslationBasedLocalOutlierRejector()) };
+		}
 	}
 }
 
@@ -2457,7 +2521,9 @@ opencv_type_boxed! { TwoPassStabilizer }
 impl Drop for TwoPassStabilizer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_TwoPassStabilizer_delete(instance: *mut c_void); }
-		unsafe { cv_TwoPassStabilizer_delete(self.as_raw_mut_TwoPassStabilizer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_TwoPassStabilizer_delete(self.as_raw_mut_TwoPassStabilizer()) };
+		}
 	}
 }
 
@@ -2529,7 +2595,9 @@ opencv_type_boxed! { VideoFileSource }
 impl Drop for VideoFileSource {
 	fn drop(&mut self) {
 		extern "C" { fn cv_VideoFileSource_delete(instance: *mut c_void); }
-		unsafe { cv_VideoFileSource_delete(self.as_raw_mut_VideoFileSource()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_VideoFileSource_delete(self.as_raw_mut_VideoFileSource()) };
+		}
 	}
 }
 
@@ -2587,7 +2655,9 @@ opencv_type_boxed! { WeightingDeblurer }
 impl Drop for WeightingDeblurer {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WeightingDeblurer_delete(instance: *mut c_void); }
-		unsafe { cv_WeightingDeblurer_delete(self.as_raw_mut_WeightingDeblurer()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WeightingDeblurer_delete(self.as_raw_mut_WeightingDeblurer()) };
+		}
 	}
 }
 
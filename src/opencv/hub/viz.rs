@@ -391,7 +391,9 @@ opencv_type_boxed! { Camera }
 impl Drop for Camera {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Camera_delete(instance: *mut c_void); }
-		unsafe { cv_Camera_delete(self.as_raw_mut_Camera()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Camera_delete(self.as_raw_mut_Camera()) };
+		}
 	}
 }
 
@@ -516,7 +518,9 @@ opencv_type_boxed! { Color }
 impl Drop for Color {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Color_delete(instance: *mut c_void); }
-		unsafe { cv_Color_delete(self.as_raw_mut_Color()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Color_delete(self.as_raw_mut_Color()) };
+		}
 	}
 }
 
@@ -744,7 +748,9 @@ opencv_type_boxed! { KeyboardEvent }
 impl Drop for KeyboardEvent {
 	fn drop(&mut self) {
 		extern "C" { fn cv_KeyboardEvent_delete(instance: *mut c_void); }
-		unsafe { cv_KeyboardEvent_delete(self.as_raw_mut_KeyboardEvent()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_KeyboardEvent_delete(self.as_raw_mut_KeyboardEvent()) };
+		}
 	}
 }
 
@@ -850,7 +856,9 @@ opencv_type_boxed! { Mesh }
 impl Drop for Mesh {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Mesh_delete(instance: *mut c_void); }
-		unsafe { cv_Mesh_delete(self.as_raw_mut_Mesh()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Mesh_delete(self.as_raw_mut_Mesh()) };
+		}
 	}
 }
 
@@ -939,7 +947,9 @@ opencv_type_boxed! { MouseEvent }
 impl Drop for MouseEvent {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MouseEvent_delete(instance: *mut c_void); }
-		unsafe { cv_MouseEvent_delete(self.as_raw_mut_MouseEvent()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MouseEvent_delete(self.as_raw_mut_MouseEvent()) };
+		}
 	}
 }
 
@@ -1362,7 +1372,9 @@ opencv_type_boxed! { Viz3d }
 impl Drop for Viz3d {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Viz3d_delete(instance: *mut c_void); }
-		unsafe { cv_Viz3d_delete(self.as_raw_mut_Viz3d()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Viz3d_delete(self.as_raw_mut_Viz3d()) };
+		}
 	}
 }
 
@@ -1414,7 +1426,9 @@ opencv_type_boxed! { WArrow }
 impl Drop for WArrow {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WArrow_delete(instance: *mut c_void); }
-		unsafe { cv_WArrow_delete(self.as_raw_mut_WArrow()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WArrow_delete(self.as_raw_mut_WArrow()) };
+		}
 	}
 }
 
@@ -1478,7 +1492,9 @@ opencv_type_boxed! { WCameraPosition }
 impl Drop for WCameraPosition {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCameraPosition_delete(instance: *mut c_void); }
-		unsafe { cv_WCameraPosition_delete(self.as_raw_mut_WCameraPosition()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCameraPosition_delete(self.as_raw_mut_WCameraPosition()) };
+		}
 	}
 }
 
@@ -1610,7 +1626,9 @@ opencv_type_boxed! { WCircle }
 impl Drop for WCircle {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCircle_delete(instance: *mut c_void); }
-		unsafe { cv_WCircle_delete(self.as_raw_mut_WCircle()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCircle_delete(self.as_raw_mut_WCircle()) };
+		}
 	}
 }
 
@@ -1692,7 +1710,9 @@ opencv_type_boxed! { WCloud }
 impl Drop for WCloud {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCloud_delete(instance: *mut c_void); }
-		unsafe { cv_WCloud_delete(self.as_raw_mut_WCloud()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCloud_delete(self.as_raw_mut_WCloud()) };
+		}
 	}
 }
 
@@ -1834,7 +1854,9 @@ opencv_type_boxed! { WCloudCollection }
 impl Drop for WCloudCollection {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCloudCollection_delete(instance: *mut c_void); }
-		unsafe { cv_WCloudCollection_delete(self.as_raw_mut_WCloudCollection()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCloudCollection_delete(self.as_raw_mut_WCloudCollection()) };
+		}
 	}
 }
 
@@ -1884,7 +1906,9 @@ opencv_type_boxed! { WCloudNormals }
 impl Drop for WCloudNormals {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCloudNormals_delete(instance: *mut c_void); }
-		unsafe { cv_WCloudNormals_delete(self.as_raw_mut_WCloudNormals()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCloudNormals_delete(self.as_raw_mut_WCloudNormals()) };
+		}
 	}
 }
 
@@ -1952,7 +1976,9 @@ opencv_type_boxed! { WCone }
 impl Drop for WCone {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCone_delete(instance: *mut c_void); }
-		unsafe { cv_WCone_delete(self.as_raw_mut_WCone()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCone_delete(self.as_raw_mut_WCone()) };
+		}
 	}
 }
 
@@ -2029,7 +2055,9 @@ opencv_type_boxed! { WCoordinateSystem }
 impl Drop for WCoordinateSystem {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCoordinateSystem_delete(instance: *mut c_void); }
-		unsafe { cv_WCoordinateSystem_delete(self.as_raw_mut_WCoordinateSystem()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCoordinateSystem_delete(self.as_raw_mut_WCoordinateSystem()) };
+		}
 	}
 }
 
@@ -2086,7 +2114,9 @@ opencv_type_boxed! { WCube }
 impl Drop for WCube {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCube_delete(instance: *mut c_void); }
-		unsafe { cv_WCube_delete(self.as_raw_mut_WCube()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCube_delete(self.as_raw_mut_WCube()) };
+		}
 	}
 }
 
@@ -2151,7 +2181,9 @@ opencv_type_boxed! { WCylinder }
 impl Drop for WCylinder {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WCylinder_delete(instance: *mut c_void); }
-		unsafe { cv_WCylinder_delete(self.as_raw_mut_WCylinder()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WCylinder_delete(self.as_raw_mut_WCylinder()) };
+		}
 	}
 }
 
@@ -2213,7 +2245,9 @@ opencv_type_boxed! { WGrid }
 impl Drop for WGrid {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WGrid_delete(instance: *mut c_void); }
-		unsafe { cv_WGrid_delete(self.as_raw_mut_WGrid()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WGrid_delete(self.as_raw_mut_WGrid()) };
+		}
 	}
 }
 
@@ -2301,7 +2335,9 @@ opencv_type_boxed! { WImage3D }
 impl Drop for WImage3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WImage3D_delete(instance: *mut c_void); }
-		unsafe { cv_WImage3D_delete(self.as_raw_mut_WImage3D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WImage3D_delete(self.as_raw_mut_WImage3D()) };
+		}
 	}
 }
 
@@ -2379,7 +2415,9 @@ opencv_type_boxed! { WImageOverlay }
 impl Drop for WImageOverlay {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WImageOverlay_delete(instance: *mut c_void); }
-		unsafe { cv_WImageOverlay_delete(self.as_raw_mut_WImageOverlay()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WImageOverlay_delete(self.as_raw_mut_WImageOverlay()) };
+		}
 	}
 }
 
@@ -2435,7 +2473,9 @@ opencv_type_boxed! { WLine }
 impl Drop for WLine {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WLine_delete(instance: *mut c_void); }
-		unsafe { cv_WLine_delete(self.as_raw_mut_WLine()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WLine_delete(self.as_raw_mut_WLine()) };
+		}
 	}
 }
 
@@ -2508,7 +2548,9 @@ opencv_type_boxed! { WMesh }
 impl Drop for WMesh {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WMesh_delete(instance: *mut c_void); }
-		unsafe { cv_WMesh_delete(self.as_raw_mut_WMesh()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WMesh_delete(self.as_raw_mut_WMesh()) };
+		}
 	}
 }
 
@@ -2567,7 +2609,9 @@ opencv_type_boxed! { WPaintedCloud }
 impl Drop for WPaintedCloud {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WPaintedCloud_delete(instance: *mut c_void); }
-		unsafe { cv_WPaintedCloud_delete(self.as_raw_mut_WPaintedCloud()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WPaintedCloud_delete(self.as_raw_mut_WPaintedCloud()) };
+		}
 	}
 }
 
@@ -2631,7 +2675,9 @@ opencv_type_boxed! { WPlane }
 impl Drop for WPlane {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WPlane_delete(instance: *mut c_void); }
-		unsafe { cv_WPlane_delete(self.as_raw_mut_WPlane()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WPlane_delete(self.as_raw_mut_WPlane()) };
+		}
 	}
 }
 
@@ -2706,7 +2752,9 @@ opencv_type_boxed! { WPolyLine }
 impl Drop for WPolyLine {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WPolyLine_delete(instance: *mut c_void); }
-		unsafe { cv_WPolyLine_delete(self.as_raw_mut_WPolyLine()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WPolyLine_delete(self.as_raw_mut_WPolyLine()) };
+		}
 	}
 }
 
@@ -2771,7 +2819,9 @@ opencv_type_boxed! { WSphere }
 impl Drop for WSphere {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WSphere_delete(instance: *mut c_void); }
-		unsafe { cv_WSphere_delete(self.as_raw_mut_WSphere()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WSphere_delete(self.as_raw_mut_WSphere()) };
+		}
 	}
 }
 
@@ -2846,7 +2896,9 @@ opencv_type_boxed! { WText }
 impl Drop for WText {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WText_delete(instance: *mut c_void); }
-		unsafe { cv_WText_delete(self.as_raw_mut_WText()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WText_delete(self.as_raw_mut_WText()) };
+		}
 	}
 }
 
@@ -2922,7 +2974,9 @@ opencv_type_boxed! { WText3D }
 impl Drop for WText3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WText3D_delete(instance: *mut c_void); }
-		unsafe { cv_WText3D_delete(self.as_raw_mut_WText3D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WText3D_delete(self.as_raw_mut_WText3D()) };
+		}
 	}
 }
 
@@ -2986,7 +3040,9 @@ opencv_type_boxed! { WTrajectory }
 impl Drop for WTrajectory {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WTrajectory_delete(instance: *mut c_void); }
-		unsafe { cv_WTrajectory_delete(self.as_raw_mut_WTrajectory()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WTrajectory_delete(self.as_raw_mut_WTrajectory()) };
+		}
 	}
 }
 
@@ -3055,7 +3111,9 @@ opencv_type_boxed! { WTrajectoryFrustums }
 impl Drop for WTrajectoryFrustums {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WTrajectoryFrustums_delete(instance: *mut c_void); }
-		unsafe { cv_WTrajectoryFrustums_delete(self.as_raw_mut_WTrajectoryFrustums()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WTrajectoryFrustums_delete(self.as_raw_mut_WTrajectoryFrustums()) };
+		}
 	}
 }
 
@@ -3143,7 +3201,9 @@ opencv_type_boxed! { WTrajectorySpheres }
 impl Drop for WTrajectorySpheres {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WTrajectorySpheres_delete(instance: *mut c_void); }
-		unsafe { cv_WTrajectorySpheres_delete(self.as_raw_mut_WTrajectorySpheres()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WTrajectorySpheres_delete(self.as_raw_mut_WTrajectorySpheres()) };
+		}
 	}
 }
 
@@ -3231,7 +3291,9 @@ opencv_type_boxed! { WWidgetMerger }
 impl Drop for WWidgetMerger {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WWidgetMerger_delete(instance: *mut c_void); }
-		unsafe { cv_WWidgetMerger_delete(self.as_raw_mut_WWidgetMerger()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WWidgetMerger_delete(self.as_raw_mut_WWidgetMerger()) };
+		}
 	}
 }
 
@@ -3339,7 +3401,9 @@ opencv_type_boxed! { Widget }
 impl Drop for Widget {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Widget_delete(instance: *mut c_void); }
-		unsafe { cv_Widget_delete(self.as_raw_mut_Widget()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Widget_delete(self.as_raw_mut_Widget()) };
+		}
 	}
 }
 
@@ -3400,7 +3464,9 @@ opencv_type_boxed! { Widget2D }
 impl Drop for Widget2D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Widget2D_delete(instance: *mut c_void); }
-		unsafe { cv_Widget2D_delete(self.as_raw_mut_Widget2D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Widget2D_delete(self.as_raw_mut_Widget2D()) };
+		}
 	}
 }
 
@@ -3482,7 +3548,9 @@ opencv_type_boxed! { Widget3D }
 impl Drop for Widget3D {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Widget3D_delete(instance: *mut c_void); }
-		unsafe { cv_Widget3D_delete(self.as_raw_mut_Widget3D()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Widget3D_delete(self.as_raw_mut_Widget3D()) };
+		}
 	}
 }
 
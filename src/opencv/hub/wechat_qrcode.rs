@@ -56,7 +56,9 @@ opencv_type_boxed! { WeChatQRCode }
 impl Drop for WeChatQRCode {
 	fn drop(&mut self) {
 		extern "C" { fn cv_WeChatQRCode_delete(instance: *mut c_void); }
-		unsafe { cv_WeChatQRCode_delete(self.as_raw_mut_WeChatQRCode()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_WeChatQRCode_delete(self.as_raw_mut_WeChatQRCode()) };
+		}
 	}
 }
 
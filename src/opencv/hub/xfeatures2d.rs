@@ -424,7 +424,9 @@ opencv_type_boxed! { SURF_CUDA }
 impl Drop for SURF_CUDA {
 	fn drop(&mut self) {
 		extern "C" { fn cv_SURF_CUDA_delete(instance: *mut c_void); }
-		unsafe { cv_SURF_CUDA_delete(self.as_raw_mut_SURF_CUDA()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_SURF_CUDA_delete(self.as_raw_mut_SURF_CUDA()) };
+		}
 	}
 }
 
@@ -588,7 +590,9 @@ opencv_type_boxed! { BEBLID }
 impl Drop for BEBLID {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BEBLID_delete(instance: *mut c_void); }
-		unsafe { cv_BEBLID_delete(self.as_raw_mut_BEBLID()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BEBLID_delete(self.as_raw_mut_BEBLID()) };
+		}
 	}
 }
 
@@ -716,7 +720,9 @@ opencv_type_boxed! { BriefDescriptorExtractor }
 impl Drop for BriefDescriptorExtractor {
 	fn drop(&mut self) {
 		extern "C" { fn cv_BriefDescriptorExtractor_delete(instance: *mut c_void); }
-		unsafe { cv_BriefDescriptorExtractor_delete(self.as_raw_mut_BriefDescriptorExtractor()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_BriefDescriptorExtractor_delete(self.as_raw_mut_BriefDescriptorExtractor()) };
+		}
 	}
 }
 
@@ -909,7 +915,9 @@ opencv_type_boxed! { Elliptic_KeyPoint }
 impl Drop for Elliptic_KeyPoint {
 	fn drop(&mut self) {
 		extern "C" { fn cv_Elliptic_KeyPoint_delete(instance: *mut c_void); }
-		unsafe { cv_Elliptic_KeyPoint_delete(self.as_raw_mut_Elliptic_KeyPoint()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_Elliptic_KeyPoint_delete(self.as_raw_mut_Elliptic_KeyPoint()) };
+		}
 	}
 }
 
@@ -975,7 +983,9 @@ opencv_type_boxed! { FREAK }
 impl Drop for FREAK {
 	fn drop(&mut self) {
 		extern "C" { fn cv_FREAK_delete(instance: *mut c_void); }
-		unsafe { cv_FREAK_delete(self.as_raw_mut_FREAK()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_FREAK_delete(self.as_raw_mut_FREAK()) };
+		}
 	}
 }
 
@@ -1041,7 +1051,9 @@ opencv_type_boxed! { HarrisLaplaceFeatureDetector }
 impl Drop for HarrisLaplaceFeatureDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_HarrisLaplaceFeatureDetector_delete(instance: *mut c_void); }
-		unsafe { cv_HarrisLaplaceFeatureDetector_delete(self.as_raw_mut_HarrisLaplaceFeatureDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_HarrisLaplaceFeatureDetector_delete(self.as_raw_mut_HarrisLaplaceFeatureDetector()) };
+		}
 	}
 }
 
@@ -1136,7 +1148,9 @@ opencv_type_boxed! { LATCH }
 impl Drop for LATCH {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LATCH_delete(instance: *mut c_void); }
-		unsafe { cv_LATCH_delete(self.as_raw_mut_LATCH()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LATCH_delete(self.as_raw_mut_LATCH()) };
+		}
 	}
 }
 
@@ -1203,7 +1217,9 @@ opencv_type_boxed! { LUCID }
 impl Drop for LUCID {
 	fn drop(&mut self) {
 		extern "C" { fn cv_LUCID_delete(instance: *mut c_void); }
-		unsafe { cv_LUCID_delete(self.as_raw_mut_LUCID()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_LUCID_delete(self.as_raw_mut_LUCID()) };
+		}
 	}
 }
 
@@ -1278,7 +1294,9 @@ opencv_type_boxed! { MSDDetector }
 impl Drop for MSDDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_MSDDetector_delete(instance: *mut c_void); }
-		unsafe { cv_MSDDetector_delete(self.as_raw_mut_MSDDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_MSDDetector_delete(self.as_raw_mut_MSDDetector()) };
+		}
 	}
 }
 
@@ -1896,7 +1914,9 @@ opencv_type_boxed! { StarDetector }
 impl Drop for StarDetector {
 	fn drop(&mut self) {
 		extern "C" { fn cv_StarDetector_delete(instance: *mut c_void); }
-		unsafe { cv_StarDetector_delete(self.as_raw_mut_StarDetector()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_StarDetector_delete(self.as_raw_mut_StarDetector()) };
+		}
 	}
 }
 
@@ -1791,7 +1791,9 @@ opencv_type_boxed! { ContourFitting }
 impl Drop for ContourFitting {
 	fn drop(&mut self) {
 		extern "C" { fn cv_ContourFitting_delete(instance: *mut c_void); }
-		unsafe { cv_ContourFitting_delete(self.as_raw_mut_ContourFitting()) };
+		if core::destructor_guard_allows_delete() {
+			unsafe { cv_ContourFitting_delete(self.as_raw_mut_ContourFitting()) };
+		}
 	}
 }
 
@@ -2322,7 +2324,7 @@ impl EdgeDrawing_Params {
 		unsafe { sys::cv_ximgproc_EdgeDrawing_Params_read_const_FileNodeR(self.opencv_as_extern(), fn_.as_raw_FileNode()) }.into_result()
 	}
 	
-	pub fn write(self, fs: &mut core::FileStorage) -> Result<()> {
+	pub fn write(&self, fs: &mut core::FileStorage) -> Result<()> {
 		unsafe { sys::cv_ximgproc_EdgeDrawing_Params_write_const_FileStorageR(self.opencv_as_extern(), fs.as_raw_mut_FileStorage()) }.into_result()
 	}
 	
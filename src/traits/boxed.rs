@@ -1,5 +1,9 @@
 use std::ffi::c_void;
 
+/// Access to the underlying C++ object of the boxed wrapper types
+///
+/// Can be used to pass the objects to the hand-written FFI functions for the functionality that's not
+/// exposed by the safe API, see `tests/boxed.rs` for an example.
 pub trait Boxed: Sized {
 	/// Wrap the specified raw pointer
 	/// # Safety
@@ -3,6 +3,7 @@
 template struct Result<void*>;
 template struct Result<cv::Size>;
 template struct Result<const unsigned char*>;
+template struct Result<unsigned char*>;
 
 template<typename T> inline Result<void*> ocvrs_input_array(const T* instance) {
 	try {
@@ -76,6 +77,30 @@ extern "C" {
 		return instance->ptr != instance->sliceEnd;
 	}
 
+	Result<const unsigned char*> cv_manual_SparseMat_find(const cv::SparseMat* instance, const int* idx) {
+		try {
+			return Ok<const unsigned char*>(const_cast<cv::SparseMat*>(instance)->ptr(idx, false));
+		} OCVRS_CATCH(Result<const unsigned char*>)
+	}
+
+	Result<unsigned char*> cv_manual_SparseMat_ref(cv::SparseMat* instance, const int* idx) {
+		try {
+			return Ok<unsigned char*>(instance->ptr(idx, true));
+		} OCVRS_CATCH(Result<unsigned char*>)
+	}
+
+	bool cv_manual_SparseMatConstIterator_has_elements(const cv::SparseMatConstIterator* instance) {
+		return instance->ptr != NULL;
+	}
+
+	const int* cv_manual_SparseMatConstIterator_idx(const cv::SparseMatConstIterator* instance) {
+		return instance->node()->idx;
+	}
+
+	void cv_manual_SparseMatConstIterator_incr(cv::SparseMatConstIterator* instance) {
+		++(*instance);
+	}
+
 	Result<void*> cv_InputArray_input_array(cv::_InputArray* instance) { return ocvrs_input_array(instance); }
 	Result<void*> cv_OutputArray_output_array(cv::_OutputArray* instance) { return ocvrs_output_array(instance); }
 	Result<void*> cv_InputOutputArray_input_output_array(cv::_InputOutputArray* instance) { return ocvrs_input_output_array(instance); }
@@ -0,0 +1,18 @@
+#include "ocvrs_common.hpp"
+#include <opencv2/line_descriptor.hpp>
+
+template struct Result<void*>;
+
+extern "C" {
+	Result<void*> cv_manual_BinaryDescriptor_params(const cv::line_descriptor::BinaryDescriptor* instance) {
+		try {
+			// Params are private, but BinaryDescriptor::write() stores all of them and Params::read() restores them
+			cv::FileStorage out(".yml", cv::FileStorage::WRITE | cv::FileStorage::MEMORY);
+			instance->write(out);
+			cv::FileStorage in(out.releaseAndGetString(), cv::FileStorage::READ | cv::FileStorage::MEMORY);
+			cv::line_descriptor::BinaryDescriptor::Params* params = new cv::line_descriptor::BinaryDescriptor::Params();
+			params->read(in.root());
+			return Ok<void*>(params);
+		} OCVRS_CATCH(Result<void*>)
+	}
+}
@@ -11,6 +11,10 @@ use opencv::{
 	types::{PtrOfIndexParams, VectorOfVec4f},
 };
 
+extern "C" {
+	fn memset(dest: *mut c_void, c: i32, n: usize) -> *mut c_void;
+}
+
 #[test]
 fn layout() -> Result<()> {
 	let mat = Mat::new_rows_cols_with_default(1, 3, f32::typ(), Scalar::all(10.))?;
@@ -100,3 +104,23 @@ fn into_raw_ptrofboxed() -> Result<()> {
 	assert_eq!("my string", b.get_string("string", "-")?);
 	Ok(())
 }
+
+#[test]
+fn hand_written_ffi() -> Result<()> {
+	let mut data = vec![0u16; 12];
+	let mut mat = unsafe { Mat::from_raw_parts_unchecked(3, 4, u16::typ(), data.as_mut_ptr() as *mut c_void) }?;
+	assert_eq!(data.as_ptr() as *const u8, mat.data()? as *const u8);
+	assert_eq!(12, mat.total()?);
+
+	// fill the Mat data through a C function that has no binding
+	let len = mat.total()? * mat.elem_size()?;
+	unsafe { memset(mat.data_mut() as *mut u8 as *mut c_void, 0x01, len) };
+	assert_eq!(0x0101, *mat.at_2d::<u16>(2, 3)?);
+
+	let ptr = mat.as_raw();
+	let total = unsafe { opencv::sys::cv_Mat_total_const(ptr) }.into_result()?;
+	assert_eq!(12, total);
+	drop(mat);
+	assert!(data.iter().all(|&x| x == 0x0101));
+	Ok(())
+}
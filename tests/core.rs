@@ -7,6 +7,7 @@ use opencv::{
         CV_MAKETYPE,
         Moments,
         Point2f,
+        Rect,
         RotatedRect,
         Scalar,
         Size2f,
@@ -52,6 +53,18 @@ fn rotated_rect() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rotated_rect_points_array() -> Result<()> {
+    let rect = RotatedRect::new(Point2f::new(20., 10.), Size2f::new(40., 10.), 0.)?;
+    let pts = rect.points_array()?;
+    assert_eq!(Point2f::new(0., 15.), pts[0]);
+    assert_eq!(Point2f::new(0., 5.), pts[1]);
+    assert_eq!(Point2f::new(40., 5.), pts[2]);
+    assert_eq!(Point2f::new(40., 15.), pts[3]);
+    assert_eq!(Rect::new(0, 5, 41, 11), rect.bounding_rect()?);
+    Ok(())
+}
+
 #[test]
 fn in_range() -> Result<()> {
     let mut cs = VectorOfMat::new();
@@ -118,3 +131,280 @@ fn file_storage() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pca_project() -> Result<()> {
+    // samples lie close to the `z = x + y` plane so 2 components should be enough to describe them
+    let rows = (0..50)
+        .map(|i| {
+            let x = f64::from(i) * 0.1;
+            let y = f64::from(i * 7 % 11);
+            let noise = f64::from(i % 3 - 1) * 0.01;
+            [x, y, x + y + noise]
+        })
+        .collect::<Vec<_>>();
+    let data = Mat::from_slice_2d(&rows)?;
+    let mut pca = core::PCA::new(&data, &Mat::default(), core::PCA_Flags::DATA_AS_ROW as i32, 2)?;
+    assert_eq!(core::Size::new(3, 2), pca.eigenvectors().size()?);
+    assert_eq!(2, pca.eigenvalues().rows());
+    assert_eq!(3, pca.mean().cols());
+
+    let projected = pca.project(&data)?;
+    assert_eq!(50, projected.rows());
+    assert_eq!(2, projected.cols());
+    let reconstructed = pca.back_project(&projected)?;
+    let max_err = data.data_typed::<f64>()?.iter()
+        .zip(reconstructed.data_typed::<f64>()?)
+        .map(|(a, b)| (a - b).abs())
+        .fold(0., f64::max);
+    assert!(max_err < 0.02, "Reconstruction error too large: {}", max_err);
+    Ok(())
+}
+
+#[test]
+fn solve_line_fit() -> Result<()> {
+    // least squares fit of `y = a * x + b` to (0, 1), (1, 3), (2, 4), (3, 8), by hand: a = 2.2, b = 0.7
+    let a = Mat::from_slice_2d(&[[0., 1.], [1., 1.], [2., 1.], [3., 1.]])?;
+    let b = Mat::from_slice_2d(&[[1.], [3.], [4.], [8.]])?;
+    let assert_fit = |x: &Mat| -> Result<()> {
+        assert_eq!(core::Size::new(1, 2), x.size()?);
+        let x = x.data_typed::<f64>()?;
+        assert!((x[0] - 2.2).abs() < 1e-9, "a: {}", x[0]);
+        assert!((x[1] - 0.7).abs() < 1e-9, "b: {}", x[1]);
+        Ok(())
+    };
+    for &flags in &[
+        core::DecompTypes::DECOMP_SVD as i32,
+        core::DecompTypes::DECOMP_QR as i32,
+        core::DecompTypes::DECOMP_LU as i32 | core::DecompTypes::DECOMP_NORMAL as i32,
+        core::DecompTypes::DECOMP_CHOLESKY as i32 | core::DecompTypes::DECOMP_NORMAL as i32,
+    ] {
+        let mut x = Mat::default();
+        assert!(core::solve(&a, &b, &mut x, flags)?);
+        assert_fit(&x)?;
+    }
+
+    let mut svd = core::SVD::new(&a, 0)?;
+    let mut x = Mat::default();
+    svd.back_subst(&b, &mut x)?;
+    assert_fit(&x)?;
+    assert_eq!(core::Size::new(2, 4), svd.u().size()?);
+    assert_eq!(core::Size::new(1, 2), svd.w().size()?);
+    assert_eq!(core::Size::new(2, 2), svd.vt().size()?);
+
+    // homogeneous system for the points lying exactly on `y = 2 * x + 1`, the solution is proportional to (2, 1, -1)
+    let h = Mat::from_slice_2d(&[[0., 1., 1.], [1., 1., 3.], [2., 1., 5.], [3., 1., 7.]])?;
+    let mut z = Mat::default();
+    core::SVD::solve_z(&h, &mut z)?;
+    let z = z.data_typed::<f64>()?;
+    assert!((z[0] / z[2] + 2.).abs() < 1e-9);
+    assert!((z[1] / z[2] + 1.).abs() < 1e-9);
+    Ok(())
+}
+
+#[test]
+fn file_storage_round_trip() -> Result<()> {
+    use opencv::core::{FileStorage, FileStorage_Mode};
+
+    let mat = Mat::from_slice_2d(&[[1., 2., 3.], [4., 5., 6.]])?;
+    let mut st = FileStorage::new(".yml", FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+    st.write_mat("matrix", &mat)?;
+    st.start_write_struct("params", core::FileNode_MAP, "")?;
+    st.write_i32("octaves", 3)?;
+    st.write_f64("scale", 0.5)?;
+    st.start_write_struct("inner", core::FileNode_MAP, "")?;
+    st.write_str("name", "lsd")?;
+    st.end_write_struct()?;
+    st.end_write_struct()?;
+    st.start_write_struct("seq", core::FileNode_SEQ, "")?;
+    for i in 0..4 {
+        core::write_scalar_i32(&mut st, i * 10)?;
+    }
+    st.end_write_struct()?;
+    let serialized = st.release_and_get_string()?;
+    assert!(serialized.starts_with("%YAML"));
+
+    let st = FileStorage::new(&serialized, FileStorage_Mode::READ as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+    let read_mat = st.get("matrix")?.mat()?;
+    assert_eq!(mat.size()?, read_mat.size()?);
+    assert_eq!(mat.typ()?, read_mat.typ()?);
+    assert_eq!(mat.data_typed::<f64>()?, read_mat.data_typed::<f64>()?);
+
+    let params = st.get("params")?;
+    assert!(params.is_map()?);
+    assert_eq!(3, params.get("octaves")?.to_i32()?);
+    assert_eq!(0.5, params.get("scale")?.to_f64()?);
+    let inner = params.get("inner")?;
+    assert!(inner.is_map()?);
+    assert_eq!("lsd", inner.get("name")?.to_string()?);
+    let names = params.children()?.iter().map(|n| n.name()).collect::<Result<Vec<_>>>()?;
+    assert_eq!(vec!["octaves", "scale", "inner"], names);
+
+    let seq = st.get("seq")?;
+    assert!(seq.is_seq()?);
+    let values = seq.children()?.iter().map(|n| n.to_i32()).collect::<Result<Vec<_>>>()?;
+    assert_eq!(vec![0, 10, 20, 30], values);
+    assert!(params.get("octaves")?.children()?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn term_criteria_kmeans() -> Result<()> {
+    use opencv::core::{TermCriteria, TermCriteria_Type};
+
+    let def = TermCriteria::default()?;
+    assert_eq!(0, def.typ);
+    assert_eq!(0, def.max_count);
+    assert_eq!(0., def.epsilon);
+    let criteria = TermCriteria::new(TermCriteria_Type::COUNT as i32 | TermCriteria_Type::EPS as i32, 100, 1e-6)?;
+    assert_eq!(3, criteria.typ);
+    assert_eq!(100, criteria.max_count);
+    assert_eq!(1e-6, criteria.epsilon);
+
+    // the initial labels alternate between the two clusters so the algorithm needs several iterations to converge
+    let data = Mat::from_slice_2d(&[[0f32], [1.], [2.], [10.], [11.], [12.]])?;
+    let initial_labels = Mat::from_slice_2d(&[[0i32], [1], [0], [1], [0], [1]])?;
+    let run = |criteria: TermCriteria| -> Result<(f64, Mat)> {
+        let mut labels = initial_labels.try_clone()?;
+        let mut centers = Mat::default();
+        let compactness = core::kmeans(&data, 2, &mut labels, criteria, 1, core::KMEANS_USE_INITIAL_LABELS, &mut centers)?;
+        Ok((compactness, labels))
+    };
+    let (few_compactness, _) = run(TermCriteria::new(TermCriteria_Type::COUNT as i32, 1, 0.)?)?;
+    let (compactness, labels) = run(criteria)?;
+    assert!((compactness - 4.).abs() < 1e-6, "Compactness: {}", compactness);
+    assert!(few_compactness > compactness);
+    let labels = labels.data_typed::<i32>()?;
+    assert!(labels[..3].iter().all(|&l| l == labels[0]));
+    assert!(labels[3..].iter().all(|&l| l == labels[3]));
+    assert_ne!(labels[0], labels[3]);
+    Ok(())
+}
+
+#[test]
+fn rng() -> Result<()> {
+    use opencv::core::{RNG, RngDistType};
+
+    // reference implementation of the multiply-with-carry generator used by `cv::RNG`
+    let mut state = 0x1234_5678_9abc_u64;
+    let mut next = || {
+        state = u64::from(state as u32) * 4_164_903_690 + (state >> 32);
+        state as u32
+    };
+    let expected = (0..100).map(|_| (next() % 1000) as i32).collect::<Vec<_>>();
+    let mut rng = RNG::new(0x1234_5678_9abc)?;
+    let actual = (0..100).map(|_| rng.uniform_i32(0, 1000)).collect::<Result<Vec<_>>>()?;
+    assert_eq!(expected, actual);
+    assert_eq!(expected.iter().sum::<i32>(), actual.iter().sum::<i32>());
+
+    let val = rng.uniform_f32(-1., 1.)?;
+    assert!((-1. ..1.).contains(&val));
+    let val = rng.uniform_f64(10., 20.)?;
+    assert!((10. ..20.).contains(&val));
+    assert!(rng.gaussian(1.)?.is_finite());
+
+    let fill = |seed: u64, dist: RngDistType| -> Result<Mat> {
+        let mut mat = Mat::new_rows_cols_with_default(16, 16, CV_64F, Scalar::all(0.))?;
+        RNG::new(seed)?.fill_dist(&mut mat, dist, &Scalar::all(5.), &Scalar::all(10.), false)?;
+        Ok(mat)
+    };
+    let uniform = fill(42, RngDistType::Uniform)?;
+    assert!(uniform.data_typed::<f64>()?.iter().all(|v| (5. ..10.).contains(v)));
+    assert_eq!(uniform.data_typed::<f64>()?, fill(42, RngDistType::Uniform)?.data_typed::<f64>()?);
+    assert_ne!(uniform.data_typed::<f64>()?, fill(43, RngDistType::Uniform)?.data_typed::<f64>()?);
+    let normal = fill(42, RngDistType::Normal)?;
+    let mean = core::mean(&normal, &Mat::default())?[0];
+    assert!((mean - 5.).abs() < 3., "Mean: {}", mean);
+    assert_eq!(core::RNG_NORMAL, i32::from(RngDistType::Normal));
+
+    core::set_rng_seed(42)?;
+    assert_eq!(42, core::the_rng()?.state());
+    Ok(())
+}
+
+#[test]
+fn norm_typed() -> Result<()> {
+    use opencv::core::NormType;
+
+    // two 32-byte descriptors differing in 3 bits of the first byte, 8 bits of the last and 4 bits in the middle
+    let mut a = [0u8; 32];
+    let mut b = [0u8; 32];
+    a[0] = 0b1011;
+    b[31] = 0xff;
+    a[16] = 0b1100_0000;
+    b[16] = 0b0000_0011;
+    let descriptors = Mat::from_slice_2d(&[a, b])?;
+    let (row_a, row_b) = (descriptors.row(0)?, descriptors.row(1)?);
+    assert_eq!(3. + 8. + 4., core::norm2_masked(&row_a, &row_b, NormType::Hamming, None)?);
+    assert_eq!(3. + 2., core::norm_masked(&row_a, NormType::Hamming, None)?);
+    // pairs of bits: 0b10_11 -> 2, 0xff -> 4, 0b11_00_00_00 ^ 0b00_00_00_11 -> 2
+    assert_eq!(2. + 4. + 2., core::norm2_masked(&row_a, &row_b, NormType::Hamming2, None)?);
+
+    let v = Mat::from_slice(&[3f64, -4.])?;
+    assert_eq!(7., core::norm_masked(&v, NormType::L1, None)?);
+    assert_eq!(5., core::norm_masked(&v, NormType::L2, None)?);
+    assert_eq!(25., core::norm_masked(&v, NormType::L2Sqr, None)?);
+    assert_eq!(4., core::norm_masked(&v, NormType::Inf, None)?);
+    let mask = Mat::from_slice(&[1u8, 0])?;
+    assert_eq!(3., core::norm_masked(&v, NormType::L2, Some(&mask))?);
+
+    let mut normalized = Mat::default();
+    core::normalize_masked(&v, &mut normalized, 1., 0., NormType::L2, -1, None)?;
+    let assert_approx = |expected: &[f64], actual: &Mat| -> Result<()> {
+        let actual = actual.data_typed::<f64>()?;
+        assert_eq!(expected.len(), actual.len());
+        assert!(expected.iter().zip(actual).all(|(e, a)| (e - a).abs() < 1e-9), "{:?} != {:?}", expected, actual);
+        Ok(())
+    };
+    assert_approx(&[0.6, -0.8], &normalized)?;
+    core::normalize_masked(&v, &mut normalized, 0., 10., NormType::MinMax, -1, None)?;
+    assert_approx(&[10., 0.], &normalized)?;
+    assert_eq!(core::NORM_HAMMING, i32::from(NormType::Hamming));
+    Ok(())
+}
+
+#[test]
+fn min_max_loc_masked() -> Result<()> {
+    use opencv::{
+        core::{MinMaxResult, Point},
+        Error,
+    };
+
+    use matches::assert_matches;
+
+    let mat = Mat::from_slice_2d(&[
+        [3f32, 1., 4., 1.],
+        [5., 9., 2., 6.],
+        [5., 3., -5., 8.],
+    ])?;
+    assert_eq!(
+        MinMaxResult { min_val: -5., max_val: 9., min_loc: Point::new(2, 2), max_loc: Point::new(1, 1) },
+        core::min_max_loc_masked(&mat, None)?,
+    );
+    let mut mask = Mat::new_rows_cols_with_default(3, 4, CV_8U, Scalar::all(255.))?;
+    *mask.at_2d_mut::<u8>(1, 1)? = 0;
+    *mask.at_2d_mut::<u8>(2, 2)? = 0;
+    let res = core::min_max_loc_masked(&mat, Some(&mask))?;
+    assert_eq!((1., 8.), (res.min_val, res.max_val));
+    assert_eq!(Point::new(3, 2), res.max_loc);
+
+    let res = core::min_max_idx_masked(&mat, None)?;
+    assert_eq!((-5., 9.), (res.min_val, res.max_val));
+    assert_eq!(vec![2, 2], res.min_idx);
+    assert_eq!(vec![1, 1], res.max_idx);
+
+    let mut cube = Mat::new_nd_with_default(&[2, 3, 4], CV_64F, Scalar::all(0.))?;
+    *cube.at_3d_mut::<f64>(1, 2, 3)? = 7.;
+    *cube.at_3d_mut::<f64>(0, 1, 2)? = -7.;
+    let res = core::min_max_idx_masked(&cube, None)?;
+    assert_eq!((-7., 7.), (res.min_val, res.max_val));
+    assert_eq!(vec![0, 1, 2], res.min_idx);
+    assert_eq!(vec![1, 2, 3], res.max_idx);
+
+    assert_matches!(core::min_max_loc_masked(&Mat::default(), None), Err(Error { code: core::StsBadArg, .. }));
+    assert_matches!(core::min_max_idx_masked(&Mat::default(), None), Err(Error { code: core::StsBadArg, .. }));
+    let color = Mat::new_rows_cols_with_default(2, 2, CV_MAKETYPE(CV_8U, 3), Scalar::all(1.))?;
+    assert_matches!(core::min_max_loc_masked(&color, None), Err(Error { code: core::BadNumChannels, .. }));
+    assert_matches!(core::min_max_loc_masked(&cube, None), Err(Error { code: core::StsBadArg, ref message }) if message.contains("min_max_idx_masked"));
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use opencv::{
+	core,
+	prelude::*,
+	Result,
+	types::VectorOfi32,
+};
+
+/// Runs in its own test binary because `shutdown()` affects the whole process
+#[test]
+fn destructor_guard() -> Result<()> {
+	let mat = Mat::default();
+	let vec = VectorOfi32::from_iter(vec![1, 2, 3]);
+	drop(Mat::default());
+	assert_eq!(0, core::destructor_guard_leaked_count());
+
+	core::set_destructor_guard(true);
+	core::shutdown();
+	drop(mat);
+	drop(vec);
+	assert_eq!(2, core::destructor_guard_leaked_count());
+	Ok(())
+}
@@ -2,11 +2,15 @@
 
 use std::ffi::c_void;
 
+use matches::assert_matches;
+
 use opencv::{
-	core::{self, Size, Vec3b},
-	imgcodecs,
+	core::{self, Scalar, Size, Vec3b},
+	Error,
+	imgcodecs::{self, ImageSequence},
 	prelude::*,
 	Result,
+	types::VectorOfi32,
 };
 
 const PIXEL: &[u8] = include_bytes!("pixel.png");
@@ -33,3 +37,37 @@ fn decode() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn image_sequence() -> Result<()> {
+	let dir = std::env::temp_dir().join("ocvrs_image_sequence");
+	std::fs::create_dir_all(&dir).unwrap();
+	for i in (0..5).rev() {
+		let img = Mat::new_rows_cols_with_default(4, 4, core::CV_8UC1, Scalar::all(f64::from(i * 10)))?;
+		imgcodecs::imwrite(dir.join(format!("img_{}.png", i)).to_str().unwrap(), &img, &VectorOfi32::new())?;
+	}
+	std::fs::write(dir.join("notes.txt"), "not an image").unwrap();
+	let pattern = dir.join("img_?.png");
+	let pattern = pattern.to_str().unwrap();
+
+	let seq = ImageSequence::from_glob(pattern)?.with_flags(imgcodecs::IMREAD_GRAYSCALE);
+	assert_eq!(5, seq.remaining_paths().len());
+	let images = seq.collect::<Result<Vec<_>>>()?;
+	assert_eq!(5, images.len());
+	for (i, img) in images.iter().enumerate() {
+		assert_eq!(i as u8 * 10, *img.at_2d::<u8>(0, 0)?);
+	}
+	assert_eq!(5, ImageSequence::from_glob(dir.join("*.png").to_str().unwrap())?.count());
+	assert_eq!(0, ImageSequence::from_glob(dir.join("*.jpg").to_str().unwrap())?.count());
+
+	let mut seq = ImageSequence::from_glob(pattern)?;
+	std::fs::remove_file(dir.join("img_2.png")).unwrap();
+	assert!(seq.next().unwrap().is_ok());
+	assert!(seq.next().unwrap().is_ok());
+	assert_matches!(seq.next(), Some(Err(Error { code: core::StsObjectNotFound, .. })));
+	assert!(seq.next().is_none());
+	assert!(seq.next().is_none());
+
+	std::fs::remove_dir_all(&dir).unwrap();
+	Ok(())
+}
@@ -1,11 +1,11 @@
 #![cfg(ocvrs_has_module_imgproc)]
 
 use opencv::{
-	core::{Point, Point2f, Scalar, Size, Vec2f},
+	core::{self, Point, Point2f, Scalar, Size, Vec2f},
 	imgproc,
 	prelude::*,
 	Result,
-	types::VectorOfPoint,
+	types::{VectorOfPoint, VectorOfPoint2f, VectorOfVectorOfPoint},
 };
 
 #[test]
@@ -41,3 +41,16 @@ fn get_rotation_matrix_2d() -> Result<()> {
 	assert_eq!(-*mat.at_2d::<f64>(0, 1)?, *mat.at_2d::<f64>(1, 0)?);
 	Ok(())
 }
+
+#[test]
+fn vector_input_array() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(20, 20, u8::typ(), Scalar::all(0.))?;
+	let square = VectorOfPoint::from_iter(vec![Point::new(5, 5), Point::new(14, 5), Point::new(14, 14), Point::new(5, 14)]);
+	let polys = VectorOfVectorOfPoint::from_iter(vec![square]);
+	imgproc::fill_poly(&mut img, &polys, Scalar::all(255.), imgproc::LINE_8, 0, Point::default())?;
+	assert_eq!(100, core::count_non_zero(&img)?);
+
+	let pts = VectorOfPoint2f::from_iter(vec![Point2f::new(0., 0.), Point2f::new(4., 0.), Point2f::new(4., 3.)]);
+	assert_eq!(6., imgproc::contour_area(&pts, false)?);
+	Ok(())
+}
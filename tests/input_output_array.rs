@@ -73,7 +73,7 @@ fn input_output_array() -> Result<()> {
 			&[ 3,  7, 11, 12],
 			&[ 4,  8, 12, 16u8],
 		])?;
-		assert_eq!(mat.to_vec_2d::<u8>()?, expected.to_vec_2d()?);
+		assert_eq!(mat.to_vec_2d::<u8>()?, expected.to_vec_2d::<u8>()?);
 	}
 
 	Ok(())
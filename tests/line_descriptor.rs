@@ -0,0 +1,1576 @@
+#![cfg(ocvrs_has_module_line_descriptor)]
+
+use std::path::PathBuf;
+
+use matches::assert_matches;
+
+use opencv::{
+	core::{self, DMatch, MatView, Point2f, Rect, Scalar, Size, Vec3b, Vec4f, Vec4i},
+	Error,
+	imgcodecs,
+	imgproc,
+	line_descriptor::{
+		self,
+		BinaryDescriptor,
+		BinaryDescriptor_Params,
+		BinaryDescriptorMatcher,
+		BinaryDescriptorMatcherTrait,
+		BinaryDescriptorTrait,
+		ConfiguredLsdDetector,
+		DrawLinesMatchesFlags,
+		KeyLine,
+		LineColor,
+		LineDetectLoop,
+		LineDetector,
+		LSDDetector,
+		LSDDetectorTrait,
+		LSDParam,
+		SyncMatcher,
+	},
+	prelude::*,
+	Result,
+	types::{VectorOfDMatch, VectorOfi8, VectorOfKeyLine, VectorOfMat, VectorOfPoint, VectorOfVectorOfDMatch, VectorOfVectorOfKeyLine},
+};
+
+fn blox() -> Result<Mat> {
+	let blox_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/blox.jpg");
+	imgcodecs::imread(blox_path.to_str().unwrap(), imgcodecs::IMREAD_GRAYSCALE)
+}
+
+/// Detect the lines of `img` with the default `BinaryDescriptor` and compute their binary descriptors
+fn detect_and_compute(img: &Mat) -> Result<(VectorOfKeyLine, Mat)> {
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut lines = VectorOfKeyLine::new();
+	bd.detect(img, &mut lines, &Mat::default())?;
+	let mut descriptors = Mat::default();
+	bd.compute(img, &mut lines, &mut descriptors, false)?;
+	Ok((lines, descriptors))
+}
+
+/// Path in the temp dir that's unique to the test `name` and the current process
+fn temp_path(name: &str) -> PathBuf {
+	std::env::temp_dir().join(format!("ocvrs_line_descriptor_{}_{}", std::process::id(), name))
+}
+
+#[test]
+fn configured_lsd_detector() -> Result<()> {
+	let img = blox()?;
+	let mut det = ConfiguredLsdDetector::builder()
+		.octaves(2)
+		.octave_scale(2)
+		.build()?;
+	assert_eq!(2, det.octaves());
+	assert_eq!(2, det.octave_scale());
+
+	let mut raw = LSDDetector::default()?;
+	let mut expected = VectorOfKeyLine::new();
+	raw.detect(&img, &mut expected, 2, 2, &Mat::default())?;
+	let lines = det.detect(&img, None)?;
+	assert!(!lines.is_empty());
+	assert_eq!(expected.to_vec(), lines.to_vec());
+	assert_eq!(expected.to_vec(), det.detect_keylines(&img, None)?.to_vec());
+
+	det.set_octaves(1)?;
+	let mut expected = VectorOfKeyLine::new();
+	raw.detect(&img, &mut expected, 2, 1, &Mat::default())?;
+	assert_eq!(expected.to_vec(), det.detect(&img, None)?.to_vec());
+
+	assert_matches!(det.set_octaves(0), Err(Error { code: core::StsBadArg, .. }));
+	assert_eq!(1, det.octaves());
+	assert_matches!(
+		ConfiguredLsdDetector::builder().octave_scale(0).build().map(|_| ()),
+		Err(Error { code: core::StsBadArg, .. })
+	);
+	Ok(())
+}
+
+#[test]
+fn configured_lsd_detector_matches_raw() -> Result<()> {
+	let img = blox()?;
+	let mut mask = Mat::new_rows_cols_with_default(img.rows(), img.cols(), core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut mask, Rect::new(20, 20, 150, 120), Scalar::all(255.), imgproc::FILLED, imgproc::LINE_8, 0)?;
+	for &(params, octaves, octave_scale) in &[(LSDParam::fast(), 1, 2), (LSDParam::high_precision(), 3, 2), (LSDParam::default()?, 2, 3)] {
+		let mut det = ConfiguredLsdDetector::builder()
+			.params(params)
+			.octaves(octaves)
+			.octave_scale(octave_scale)
+			.build()?;
+		let mut raw = LSDDetector::new(params)?;
+		for mask in &[None, Some(&mask)] {
+			let mut expected = VectorOfKeyLine::new();
+			raw.detect(&img, &mut expected, octave_scale, octaves, mask.unwrap_or(&Mat::default()))?;
+			assert!(!expected.is_empty());
+			assert_eq!(expected.to_vec(), det.detect(&img, *mask)?.to_vec());
+		}
+	}
+	Ok(())
+}
+
+/// Raw representation of every `KeyLine` field for the bit-for-bit comparisons
+fn keyline_bits(keylines: &[KeyLine]) -> Vec<[u32; 17]> {
+	keylines.iter()
+		.map(|l| [
+			l.angle.to_bits(), l.class_id as u32, l.octave as u32, l.pt.x.to_bits(), l.pt.y.to_bits(), l.response.to_bits(),
+			l.size.to_bits(), l.start_point_x.to_bits(), l.start_point_y.to_bits(), l.end_point_x.to_bits(),
+			l.end_point_y.to_bits(), l.s_point_in_octave_x.to_bits(), l.s_point_in_octave_y.to_bits(),
+			l.e_point_in_octave_x.to_bits(), l.e_point_in_octave_y.to_bits(), l.line_length.to_bits(), l.num_of_pixels as u32,
+		])
+		.collect()
+}
+
+#[test]
+fn single_octave_bit_identical() -> Result<()> {
+	let img = blox()?;
+	for &params in &[LSDParam::default()?, LSDParam::fast(), LSDParam::high_precision()] {
+		let mut raw = LSDDetector::new(params)?;
+		let mut expected = VectorOfKeyLine::new();
+		raw.detect(&img, &mut expected, 2, 1, &Mat::default())?;
+		assert!(!expected.is_empty());
+		let expected = keyline_bits(expected.as_slice());
+
+		let mut configured = ConfiguredLsdDetector::builder().params(params).octaves(1).build()?;
+		assert_eq!(expected, keyline_bits(configured.detect(&img, None)?.as_slice()));
+		let mut masked = VectorOfKeyLine::new();
+		raw.detect_masked(&img, &mut masked, 2, 1, None)?;
+		assert_eq!(expected, keyline_bits(masked.as_slice()));
+		let mut detect_loop = LineDetectLoop::new(LSDDetector::new(params)?);
+		assert_eq!(expected, keyline_bits(detect_loop.process(&img, 2, 1)?));
+		assert_eq!(expected, keyline_bits(detect_loop.process(&img, 2, 1)?));
+	}
+	Ok(())
+}
+
+#[test]
+fn keyline_points() -> Result<()> {
+	let img = blox()?;
+	let mut det = LSDDetector::default()?;
+	let mut lines = VectorOfKeyLine::new();
+	det.detect(&img, &mut lines, 2, 1, &Mat::default())?;
+	assert!(!lines.is_empty());
+	for line in lines.as_slice() {
+		let start = line.get_start_point()?;
+		let end = line.get_end_point()?;
+		assert_eq!(core::Point2f::new(line.start_point_x, line.start_point_y), start);
+		assert_eq!(core::Point2f::new(line.end_point_x, line.end_point_y), end);
+		assert_eq!(core::Point2f::new(line.s_point_in_octave_x, line.s_point_in_octave_y), line.get_start_point_in_octave()?);
+		assert_eq!(core::Point2f::new(line.e_point_in_octave_x, line.e_point_in_octave_y), line.get_end_point_in_octave()?);
+	}
+	Ok(())
+}
+
+#[test]
+fn binary_descriptor_params() -> Result<()> {
+	let mut params = BinaryDescriptor_Params::default()?;
+	params.set_width_of_band_(9);
+	params.set_num_of_octave_(2);
+	params.set_reduction_ratio(3);
+	assert_eq!(9, params.width_of_band_());
+	assert_eq!(2, params.num_of_octave_());
+	assert_eq!(3, params.reduction_ratio());
+
+	let mut bd = BinaryDescriptor::new(&params)?;
+	assert_eq!(9, bd.get_width_of_band()?);
+	assert_eq!(2, bd.get_num_of_octaves()?);
+	assert_eq!(3, bd.get_reduction_ratio()?);
+	Ok(())
+}
+
+#[test]
+fn detect_on_mat_view() -> Result<()> {
+	let img = blox()?;
+	let data = img.data_typed::<u8>()?.to_vec();
+	let view = MatView::from_slice(&data, img.rows(), img.cols())?;
+	assert_eq!(data.as_ptr(), view.data_typed::<u8>()?.as_ptr());
+	let mut det = LSDDetector::default()?;
+	let mut expected = VectorOfKeyLine::new();
+	det.detect(&img, &mut expected, 2, 1, &Mat::default())?;
+	let mut lines = VectorOfKeyLine::new();
+	det.detect_view(&view, &mut lines, 2, 1)?;
+	assert_eq!(expected.to_vec(), lines.to_vec());
+	assert_eq!(data.as_ptr(), view.data_typed::<u8>()?.as_ptr());
+	Ok(())
+}
+
+#[test]
+fn detect_lines() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(200, 200, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut img, Rect::new(50, 50, 100, 80), Scalar::all(255.), 3, imgproc::LINE_8, 0)?;
+	let mut det = LSDDetector::default()?;
+	let lines = det.detect_lines(&img, 2, 1)?;
+	assert!(!lines.is_empty());
+	Ok(())
+}
+
+#[test]
+fn detect_on_roi_view() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut img, Rect::new(30, 30, 40, 40), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let roi = Rect::new(20, 20, 60, 60);
+	let view = MatView::roi(&img, roi)?;
+	assert_eq!(60, view.rows());
+	assert_eq!(60, view.cols());
+
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut lines = VectorOfKeyLine::new();
+	bd.detect_view(&view, &mut lines)?;
+	assert!(!lines.is_empty());
+	for line in lines {
+		for &(x, y) in &[(line.start_point_x, line.start_point_y), (line.end_point_x, line.end_point_y)] {
+			assert!(x >= 0. && x <= roi.width as f32);
+			assert!(y >= 0. && y <= roi.height as f32);
+		}
+	}
+
+	assert_matches!(MatView::roi(&img, Rect::new(50, 50, 60, 10)).map(|_| ()), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_matches!(MatView::roi(&img, Rect::new(i32::MAX, 0, 10, 10)).map(|_| ()), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_matches!(MatView::roi(&img, Rect::new(0, 10, 10, i32::MAX)).map(|_| ()), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_eq!(roi.size(), view.try_clone()?.size()?);
+	Ok(())
+}
+
+#[test]
+fn descriptor_rows() -> Result<()> {
+	let img = blox()?;
+	let (lines, descriptors) = detect_and_compute(&img)?;
+	let rows = descriptors.rows_iter::<u8>()?;
+	assert_eq!(lines.len(), rows.len());
+	for (i, (line, row)) in lines.iter().zip(rows).enumerate() {
+		assert_eq!(32, row.len());
+		assert_eq!(descriptors.at_row::<u8>(i as i32)?, row);
+		assert!(line.line_length > 0.);
+	}
+	Ok(())
+}
+
+#[test]
+fn binary_descriptor_row() -> Result<()> {
+	let img = blox()?;
+	let (mut lines, descriptors) = detect_and_compute(&img)?;
+	let row = line_descriptor::binary_descriptor_row(&descriptors, 0)?;
+	assert_eq!(32, row.len());
+	assert_eq!(descriptors.at_row::<u8>(0)?, &row[..]);
+
+	assert_matches!(line_descriptor::binary_descriptor_row(&descriptors, descriptors.rows()), Err(Error { code: core::StsOutOfRange, .. }));
+	let bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut float_descriptors = Mat::default();
+	bd.compute(&img, &mut lines, &mut float_descriptors, true)?;
+	assert_matches!(line_descriptor::binary_descriptor_row(&float_descriptors, 0), Err(Error { code: core::StsUnsupportedFormat, .. }));
+	let narrow = descriptors.col_bounds(0, 16)?;
+	assert_matches!(line_descriptor::binary_descriptor_row(&narrow, 0), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	Ok(())
+}
+
+#[test]
+fn compute_float() -> Result<()> {
+	let img = blox()?;
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut lines = VectorOfKeyLine::new();
+	bd.detect(&img, &mut lines, &Mat::default())?;
+	assert!(!lines.is_empty());
+	let (descriptors, kept) = bd.compute_float(&img, &lines)?;
+	assert_eq!(core::CV_32FC1, descriptors.typ()?);
+	assert!(!kept.is_empty());
+	assert!(kept.len() <= lines.len());
+	assert_eq!(kept.len(), descriptors.rows() as usize);
+
+	let mut expected_lines = lines.clone();
+	let mut expected = Mat::default();
+	bd.compute(&img, &mut expected_lines, &mut expected, true)?;
+	assert_eq!(expected_lines.to_vec(), kept);
+	assert_eq!(expected.data_typed::<f32>()?, descriptors.data_typed::<f32>()?);
+	Ok(())
+}
+
+#[test]
+fn detect_base_octave() -> Result<()> {
+	let img = blox()?;
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	bd.set_num_of_octaves(3)?;
+	let mut base = VectorOfKeyLine::new();
+	bd.detect_base_octave(&img, &mut base, None)?;
+	assert!(!base.is_empty());
+	assert!(base.iter().all(|l| l.octave == 0));
+	assert_eq!(3, bd.get_num_of_octaves()?);
+	Ok(())
+}
+
+#[test]
+fn descriptor_bits() -> Result<()> {
+	let bd = BinaryDescriptor::create_binary_descriptor()?;
+	assert_eq!(256, bd.descriptor_bits()?);
+	assert_eq!(line_descriptor::BINARY_DESCRIPTOR_BYTES as i32 * 8, bd.descriptor_bits()?);
+	assert_eq!(bd.descriptor_size()?, bd.descriptor_bits()?);
+	Ok(())
+}
+
+#[test]
+fn hamming_distance() -> Result<()> {
+	let img = blox()?;
+	let (_, descriptors) = detect_and_compute(&img)?;
+	let a = line_descriptor::binary_descriptor_row(&descriptors, 0)?;
+	assert_eq!(0, line_descriptor::hamming_distance(&a, &a));
+	let mut flipped = a;
+	flipped[7] ^= 0b0001_0000;
+	assert_eq!(1, line_descriptor::hamming_distance(&a, &flipped));
+	assert_eq!(256, line_descriptor::hamming_distance(&[0; 32], &[0xff; 32]));
+
+	assert_eq!(0, line_descriptor::descriptor_distance_mat(&descriptors, 1, 1)?);
+	let b = line_descriptor::binary_descriptor_row(&descriptors, 1)?;
+	assert_eq!(line_descriptor::hamming_distance(&a, &b), line_descriptor::descriptor_distance_mat(&descriptors, 0, 1)?);
+	assert_matches!(line_descriptor::descriptor_distance_mat(&descriptors, 0, -1), Err(Error { code: core::StsOutOfRange, .. }));
+	Ok(())
+}
+
+#[test]
+fn reshape_descriptors() -> Result<()> {
+	let img = blox()?;
+	let (_, descriptors) = detect_and_compute(&img)?;
+	assert!(descriptors.rows() >= 2);
+	assert_eq!(descriptors.rows() as usize * 32, descriptors.total()?);
+	assert_eq!(1, descriptors.elem_size()?);
+
+	let flat = descriptors.reshape(1, 1)?;
+	assert_eq!(descriptors.total()?, flat.cols() as usize);
+	assert_eq!(*descriptors.at_2d::<u8>(1, 5)?, *flat.at_2d::<u8>(0, 32 + 5)?);
+
+	let quads = descriptors.reshape(4, 0)?;
+	assert_eq!(8, quads.cols());
+	assert_eq!(4, quads.elem_size()?);
+	assert_eq!(*descriptors.at_2d::<u8>(1, 6)?, quads.at_2d::<core::Vec4b>(1, 1)?[2]);
+
+	let cube = descriptors.reshape_nd(1, &[descriptors.rows(), 4, 8])?;
+	assert_eq!(3, cube.dims());
+	assert_eq!(*descriptors.at_2d::<u8>(1, 13)?, *cube.at_3d::<u8>(1, 1, 5)?);
+
+	assert_matches!(descriptors.reshape(3, 0), Err(Error { code: core::BadNumChannels, .. }));
+	let non_continuous = descriptors.col_bounds(0, 16)?;
+	assert!(!non_continuous.is_continuous()?);
+	assert_matches!(non_continuous.reshape(1, 1), Err(Error { code: core::BadStep, .. }));
+	Ok(())
+}
+
+#[test]
+fn line_detect_loop() -> Result<()> {
+	let mut first = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::line(&mut first, core::Point::new(10, 50), core::Point::new(90, 50), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let mut second = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut second, Rect::new(20, 20, 60, 60), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+
+	let mut raw = LSDDetector::default()?;
+	let expected_first = raw.detect_lines(&first, 2, 1)?;
+	let expected_second = raw.detect_lines(&second, 2, 1)?;
+	assert_ne!(expected_first, expected_second);
+
+	let mut detect_loop = LineDetectLoop::new(LSDDetector::default()?);
+	assert_eq!(expected_first.as_slice(), detect_loop.process(&first, 2, 1)?);
+	assert_eq!(expected_second.as_slice(), detect_loop.process(&second, 2, 1)?);
+	assert_eq!(expected_second, detect_loop.keylines().to_vec());
+	let blank = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(0.))?;
+	assert!(detect_loop.process(&blank, 2, 1)?.is_empty());
+	Ok(())
+}
+
+#[test]
+fn lsd_param_validate() -> Result<()> {
+	LSDParam::default()?.validate()?;
+	LSDParam::fast().validate()?;
+	LSDParam::high_precision().validate()?;
+
+	let invalid: Vec<(&str, fn(&mut LSDParam))> = vec![
+		("scale", |p| p.scale = 0.),
+		("scale", |p| p.scale = f64::NAN),
+		("sigma_scale", |p| p.sigma_scale = -1.),
+		("quant", |p| p.quant = -0.5),
+		("ang_th", |p| p.ang_th = 0.),
+		("ang_th", |p| p.ang_th = 180.),
+		("log_eps", |p| p.log_eps = f64::INFINITY),
+		("density_th", |p| p.density_th = 1.5),
+		("density_th", |p| p.density_th = -0.1),
+		("n_bins", |p| p.n_bins = 0),
+	];
+	for (field, invalidate) in invalid {
+		let mut params = LSDParam::default()?;
+		invalidate(&mut params);
+		let field = format!("LSDParam::{} ", field);
+		assert_matches!(params.validate(), Err(Error { code: core::StsBadArg, ref message }) if message.contains(&field));
+		assert_matches!(LSDDetector::new_checked(params).map(|_| ()), Err(Error { code: core::StsBadArg, ref message }) if message.contains(&field));
+		assert_matches!(LSDDetector::create_lsd_detector_checked(params).map(|_| ()), Err(Error { code: core::StsBadArg, ref message }) if message.contains(&field));
+		assert_matches!(
+			ConfiguredLsdDetector::builder().params(params).build().map(|_| ()),
+			Err(Error { code: core::StsBadArg, ref message }) if message.contains(&field)
+		);
+	}
+	LSDDetector::new_checked(LSDParam::default()?)?;
+	LSDDetector::create_lsd_detector_checked(LSDParam::default()?)?;
+	Ok(())
+}
+
+#[test]
+fn draw_keylines_over_outimg() -> Result<()> {
+	let img = blox()?;
+	let mut det = LSDDetector::default()?;
+	let mut lines = VectorOfKeyLine::new();
+	det.detect(&img, &mut lines, 2, 1, &Mat::default())?;
+	assert!(!lines.is_empty());
+
+	let background = Vec3b::from([0, 0, 200]);
+	let mut out = Mat::new_rows_cols_with_default(img.rows(), img.cols(), core::CV_8UC3, Scalar::new(0., 0., 200., 0.))?;
+	line_descriptor::draw_keylines(&img, &lines, &mut out, Scalar::new(0., 255., 0., 0.), DrawLinesMatchesFlags::DRAW_OVER_OUTIMG)?;
+	let pixels = out.data_typed::<Vec3b>()?;
+	let kept = pixels.iter().filter(|&&px| px == background).count();
+	assert!(kept > 0);
+	assert!(kept < pixels.len());
+
+	let mut out = Mat::new_rows_cols_with_default(img.rows(), img.cols(), core::CV_8UC3, Scalar::new(0., 0., 200., 0.))?;
+	line_descriptor::draw_keylines(&img, &lines, &mut out, Scalar::new(0., 255., 0., 0.), DrawLinesMatchesFlags::DEFAULT)?;
+	assert!(out.data_typed::<Vec3b>()?.iter().all(|&px| px != background));
+	Ok(())
+}
+
+#[test]
+fn keylines_csv() -> Result<()> {
+	let img = blox()?;
+	let mut lines = VectorOfKeyLine::new();
+	LSDDetector::default()?.detect(&img, &mut lines, 2, 2, &Mat::default())?;
+	assert!(!lines.is_empty());
+	let mut csv = vec![];
+	line_descriptor::write_keylines_csv(&mut csv, &lines).unwrap();
+	let text = String::from_utf8(csv.clone()).unwrap();
+	assert!(text.starts_with("angle,class_id,octave,"));
+	assert_eq!(lines.len() + 1, text.lines().count());
+	let read = line_descriptor::read_keylines_csv(csv.as_slice()).unwrap();
+	assert_eq!(lines.to_vec(), read.to_vec());
+
+	let err = line_descriptor::read_keylines_csv("angle,class_id\n1,2\n".as_bytes()).unwrap_err();
+	assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+	let mut bad = text.lines().take(2).collect::<Vec<_>>().join("\n");
+	bad.push_str("x");
+	let err = line_descriptor::read_keylines_csv(bad.as_bytes()).unwrap_err();
+	assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+	Ok(())
+}
+
+#[test]
+fn keyline_from_points() -> Result<()> {
+	let line = KeyLine::from_points(Point2f::new(10., 20.), Point2f::new(30., 20.));
+	assert_eq!(0., line.angle);
+	assert_eq!(Point2f::new(20., 20.), line.pt);
+	assert_eq!(20., line.line_length);
+	assert_eq!(-1, line.class_id);
+	assert_eq!(0, line.octave);
+	assert_eq!(Point2f::new(10., 20.), line.get_start_point()?);
+	assert_eq!(Point2f::new(30., 20.), line.get_end_point_in_octave()?);
+
+	let line = KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(10., 10.));
+	assert!((line.angle - std::f32::consts::FRAC_PI_4).abs() < 1e-6);
+	assert_eq!(Point2f::new(5., 5.), line.pt);
+	assert!((line.line_length - 200f32.sqrt()).abs() < 1e-5);
+	Ok(())
+}
+
+#[test]
+fn keyline_approx_eq() -> Result<()> {
+	let line = KeyLine::from_points(Point2f::new(10., 20.), Point2f::new(30., 45.));
+	let mut perturbed = line;
+	perturbed.start_point_x += 1e-4;
+	perturbed.e_point_in_octave_y -= 5e-4;
+	perturbed.angle += 1e-4;
+	assert_ne!(line, perturbed);
+	assert!(line.approx_eq(&perturbed, 1e-3));
+	assert!(perturbed.approx_eq(&line, 1e-3));
+	assert!(!line.approx_eq(&perturbed, 1e-4));
+	assert!(line.approx_eq(&line, 0.));
+
+	let mut other_octave = line;
+	other_octave.octave = 1;
+	assert!(!line.approx_eq(&other_octave, 1.));
+	let mut other_class = line;
+	other_class.class_id = 3;
+	assert!(!line.approx_eq(&other_class, 1.));
+	let mut far = line;
+	far.pt.y += 0.1;
+	assert!(!line.approx_eq(&far, 1e-3));
+	Ok(())
+}
+
+#[test]
+fn keyline_segment_overlap() -> Result<()> {
+	let base = KeyLine::from_points(Point2f::new(10., 10.), Point2f::new(50., 10.));
+	assert_eq!(1., base.segment_overlap(&base));
+	let reversed = KeyLine::from_points(Point2f::new(50., 10.), Point2f::new(10., 10.));
+	assert!((base.segment_overlap(&reversed) - 1.).abs() < 1e-6);
+	let disjoint = KeyLine::from_points(Point2f::new(60., 20.), Point2f::new(90., 20.));
+	assert_eq!(0., base.segment_overlap(&disjoint));
+	let perpendicular = KeyLine::from_points(Point2f::new(30., 0.), Point2f::new(30., 40.));
+	assert_eq!(0., base.segment_overlap(&perpendicular));
+	let half = KeyLine::from_points(Point2f::new(30., 12.), Point2f::new(70., 12.));
+	assert!((base.segment_overlap(&half) - 20. / 60.).abs() < 1e-6);
+	let contained = KeyLine::from_points(Point2f::new(20., 10.), Point2f::new(40., 10.));
+	assert!((base.segment_overlap(&contained) - 0.5).abs() < 1e-6);
+	assert!((contained.segment_overlap(&base) - 0.5).abs() < 1e-6);
+	let tilted = KeyLine::from_points(Point2f::new(10., 10.), Point2f::new(50., 20.));
+	assert_eq!(0., base.segment_overlap(&tilted));
+	Ok(())
+}
+
+#[test]
+fn keyline_normalized_angle() -> Result<()> {
+	use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+	let line = KeyLine::from_points(Point2f::new(10., 10.), Point2f::new(40., 40.));
+	let swapped = KeyLine::from_points(Point2f::new(40., 40.), Point2f::new(10., 10.));
+	assert!((line.angle - swapped.angle).abs() > 3.);
+	assert!((line.normalized_angle() - swapped.normalized_angle()).abs() < 1e-6);
+	assert!((line.normalized_angle() - FRAC_PI_4).abs() < 1e-6);
+
+	let horizontal = KeyLine::from_points(Point2f::new(50., 10.), Point2f::new(10., 10.));
+	assert_eq!(PI, horizontal.angle);
+	assert_eq!(0., horizontal.normalized_angle());
+	let mut keyline = line;
+	for &(angle, expected) in &[(0., 0.), (-0., 0.), (PI, 0.), (-PI, 0.), (2. * PI, 0.), (-1e-8, 0.), (-FRAC_PI_2, FRAC_PI_2), (3. * FRAC_PI_4, 3. * FRAC_PI_4)] {
+		keyline.angle = angle;
+		let normalized = keyline.normalized_angle();
+		assert!((0. ..PI).contains(&normalized));
+		assert!((normalized - expected).abs() < 1e-6, "angle: {}, normalized: {}", angle, normalized);
+	}
+
+	let mut keylines = VectorOfKeyLine::from(&[line, swapped, horizontal][..]);
+	line_descriptor::normalize_angles(&mut keylines);
+	assert!((keylines[0].angle - keylines[1].angle).abs() < 1e-6);
+	assert_eq!(0., keylines[2].angle);
+	assert_eq!(swapped.start_point_x, keylines[1].start_point_x);
+	Ok(())
+}
+
+#[test]
+fn keyline_vector_conversion() -> Result<()> {
+	let lines = (0..100)
+		.map(|i| {
+			let i = i as f32;
+			let mut line = KeyLine::from_points(Point2f::new(i * 0.37, i * 1.13), Point2f::new(200. - i * 0.71, i * 2.29 + 1.));
+			line.class_id = i as i32;
+			line.octave = i as i32 % 3;
+			line.response = i / 100.;
+			line
+		})
+		.collect::<Vec<_>>();
+	let vector = line_descriptor::to_vector_of_keyline(&lines);
+	assert_eq!(100, vector.len());
+	let back = Vec::<KeyLine>::from(&vector);
+	assert_eq!(lines.len(), back.len());
+	for (orig, copy) in lines.iter().zip(&back) {
+		assert!(orig.approx_eq(copy, 0.));
+	}
+	assert_eq!(lines, back);
+	assert_eq!(vector.get(42)?, back[42]);
+	assert!(line_descriptor::to_vector_of_keyline(&[]).is_empty());
+	Ok(())
+}
+
+#[test]
+fn empty_mask() -> Result<()> {
+	let mask = core::empty_mask();
+	assert!(std::ptr::eq(mask, core::empty_mask()));
+	let other_thread = std::thread::spawn(|| core::empty_mask() as *const Mat as usize).join().unwrap();
+	assert_eq!(mask as *const Mat as usize, other_thread);
+	assert!(mask.empty()?);
+
+	let img = blox()?;
+	let mut with_default = VectorOfKeyLine::new();
+	LSDDetector::default()?.detect(&img, &mut with_default, 2, 1, &Mat::default())?;
+	let mut with_shared = VectorOfKeyLine::new();
+	LSDDetector::default()?.detect(&img, &mut with_shared, 2, 1, core::empty_mask())?;
+	assert_eq!(with_default.as_slice(), with_shared.as_slice());
+
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut detected = VectorOfKeyLine::new();
+	bd.detect(&img, &mut detected, core::empty_mask())?;
+	assert!(!detected.is_empty());
+	assert!(core::empty_mask().empty()?);
+	Ok(())
+}
+
+#[test]
+fn keyline_iter() -> Result<()> {
+	let img = blox()?;
+	let mut keylines = VectorOfKeyLine::new();
+	LSDDetector::default()?.detect(&img, &mut keylines, 2, 1, &Mat::default())?;
+	let total = keylines.len();
+	assert_eq!((total, Some(total)), keylines.iter().size_hint());
+	let long_count = keylines.iter().filter(|k| k.line_length > 30.).count();
+	let long = keylines.clone().into_iter().filter(|k| k.line_length > 30.).collect::<VectorOfKeyLine>();
+	assert_eq!(long_count, long.len());
+	assert!(long.iter().all(|k| k.line_length > 30.));
+
+	let mut merged = VectorOfKeyLine::new();
+	merged.extend(&long);
+	merged.extend(keylines.into_iter().filter(|k| k.line_length <= 30.));
+	assert_eq!(total, merged.len());
+	Ok(())
+}
+
+#[test]
+fn keyline_vector_from() -> Result<()> {
+	let lines = (0..30)
+		.map(|i| KeyLine::try_new(Point2f::new(20. + i as f32 * 5., 30.), Point2f::new(20. + i as f32 * 5., 90.), Size::new(200, 120), i, 0))
+		.collect::<Result<Vec<_>>>()?;
+	let from_slice = VectorOfKeyLine::from(lines.as_slice());
+	let from_vec = VectorOfKeyLine::from(lines.clone());
+	assert_eq!(lines, from_slice.to_vec());
+	assert_eq!(lines, from_vec.to_vec());
+
+	let img = blox()?;
+	let bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut keylines = from_slice;
+	let mut descriptors = Mat::default();
+	bd.compute(&img, &mut keylines, &mut descriptors, false)?;
+	assert_eq!(keylines.len() as i32, descriptors.rows());
+	Ok(())
+}
+
+#[test]
+fn keyline_generic_vector() -> Result<()> {
+	let mut vec = core::Vector::<KeyLine>::with_capacity(2);
+	vec.push(KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(10., 0.)));
+	vec.push(KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(0., 20.)));
+	vec.set(0, KeyLine::from_points(Point2f::new(1., 1.), Point2f::new(31., 1.)))?;
+	assert_eq!(2, vec.len());
+	assert_eq!(30., vec.get(0)?.line_length);
+	let alias: &VectorOfKeyLine = &vec;
+	let lines = alias.to_vec();
+	assert_eq!(vec![30., 20.], lines.iter().map(|l| l.line_length).collect::<Vec<_>>());
+	let collected = core::Vector::<KeyLine>::from_iter(lines);
+	assert_eq!(vec.as_slice(), collected.as_slice());
+
+	let img = blox()?;
+	let mut detected = core::Vector::<KeyLine>::new();
+	LSDDetector::default()?.detect(&img, &mut detected, 2, 1, &Mat::default())?;
+	assert!(!detected.is_empty());
+	vec.clear();
+	assert!(vec.is_empty());
+	Ok(())
+}
+
+#[test]
+fn draw_keylines_colored() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::line(&mut img, core::Point::new(10, 50), core::Point::new(90, 50), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let mut lines = VectorOfKeyLine::new();
+	LSDDetector::default()?.detect(&img, &mut lines, 2, 1, &Mat::default())?;
+	assert!(!lines.is_empty());
+	let mut out = Mat::default();
+	line_descriptor::draw_keylines_colored(&img, &lines, &mut out, LineColor::Rgb(255, 0, 0), DrawLinesMatchesFlags::DEFAULT)?;
+	let red = Vec3b::from([0, 0, 255]);
+	assert!(out.data_typed::<Vec3b>()?.iter().any(|&px| px == red));
+
+	assert_eq!(Scalar::all(-1.), Scalar::from(LineColor::Random));
+	assert_eq!(Scalar::new(3., 2., 1., 0.), Scalar::from(LineColor::Rgb(1, 2, 3)));
+	Ok(())
+}
+
+#[test]
+fn keyline_vector_index_mut() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC3, Scalar::all(0.))?;
+	let mut lines = VectorOfKeyLine::new();
+	lines.push(KeyLine::from_points(Point2f::new(10., 20.), Point2f::new(90., 20.)));
+	assert_eq!(20., lines[0].start_point_y);
+	lines[0].start_point_y = 80.;
+	lines[0].end_point_y = 80.;
+	assert_eq!(80., lines.get(0)?.end_point_y);
+	lines.get_mut(0)?.class_id = 7;
+	assert_eq!(7, lines[0].class_id);
+	assert!(lines.get_mut(1).is_err());
+
+	let mut out = Mat::default();
+	line_descriptor::draw_keylines(&img, &lines, &mut out, Scalar::new(0., 255., 0., 0.), DrawLinesMatchesFlags::DEFAULT)?;
+	let green = Vec3b::from([0, 255, 0]);
+	assert_eq!(green, *out.at_2d::<Vec3b>(80, 50)?);
+	assert_eq!(Vec3b::all(0), *out.at_2d::<Vec3b>(20, 50)?);
+	Ok(())
+}
+
+#[test]
+fn draw_keylines_to_new() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(100, 120, core::CV_8UC3, Scalar::all(0.))?;
+	imgproc::line(&mut img, core::Point::new(10, 50), core::Point::new(90, 50), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let mut lines = VectorOfKeyLine::new();
+	LSDDetector::default()?.detect(&img, &mut lines, 2, 1, &Mat::default())?;
+	assert!(!lines.is_empty());
+	for &flags in &[DrawLinesMatchesFlags::DEFAULT, DrawLinesMatchesFlags::DRAW_OVER_OUTIMG] {
+		let out = line_descriptor::draw_keylines_to_new(&img, &lines, Scalar::green(), flags)?;
+		assert_eq!(img.size()?, out.size()?);
+		assert_eq!(img.channels()?, out.channels()?);
+		let green = Vec3b::from([0, 255, 0]);
+		assert!(out.data_typed::<Vec3b>()?.iter().any(|&px| px == green));
+	}
+	Ok(())
+}
+
+#[test]
+fn draw_keylines_checked() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC3, Scalar::all(0.))?;
+	let color = Scalar::new(0., 255., 0., 0.);
+
+	let outside = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(-50., -50.), Point2f::new(-10., -30.))]);
+	let mut out = img.try_clone()?;
+	line_descriptor::draw_keylines_checked(&img, &outside, &mut out, color, DrawLinesMatchesFlags::DRAW_OVER_OUTIMG, false)?;
+	assert_eq!(0, core::count_non_zero(&out.reshape(1, 0)?)?);
+
+	let partial = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(-50., 50.), Point2f::new(50., 50.))]);
+	let mut out = img.try_clone()?;
+	line_descriptor::draw_keylines_checked(&img, &partial, &mut out, color, DrawLinesMatchesFlags::DRAW_OVER_OUTIMG, false)?;
+	let green = Vec3b::from([0, 255, 0]);
+	assert_eq!(green, *out.at_2d::<Vec3b>(50, 0)?);
+	assert_eq!(green, *out.at_2d::<Vec3b>(50, 50)?);
+	assert_eq!(Vec3b::default(), *out.at_2d::<Vec3b>(50, 60)?);
+	assert_eq!(Vec3b::default(), *out.at_2d::<Vec3b>(40, 0)?);
+
+	let mut out = img.try_clone()?;
+	line_descriptor::draw_keylines_checked(&img, &outside, &mut out, color, DrawLinesMatchesFlags::DRAW_OVER_OUTIMG, true)?;
+	assert!(core::count_non_zero(&out.reshape(1, 0)?)? > 0);
+
+	let non_finite = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(f32::NAN, 50.), Point2f::new(50., f32::INFINITY))]);
+	let mut out = img.try_clone()?;
+	line_descriptor::draw_keylines_checked(&img, &non_finite, &mut out, color, DrawLinesMatchesFlags::DRAW_OVER_OUTIMG, true)?;
+	assert_eq!(0, core::count_non_zero(&out.reshape(1, 0)?)?);
+	Ok(())
+}
+
+#[test]
+fn keyline_display() {
+	let line = KeyLine::from_points(Point2f::new(10., 20.), Point2f::new(30.5, 20.));
+	assert_eq!("KeyLine[(10.0,20.0)->(30.5,20.0), len=20.5, angle=0.0deg, oct=0]", line.to_string());
+	let line = KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(0., 10.));
+	assert_eq!("KeyLine[(0.0,0.0)->(0.0,10.0), len=10.0, angle=90.0deg, oct=0]", line.to_string());
+}
+
+#[test]
+fn keyline_vector_iter() -> Result<()> {
+	let img = blox()?;
+	let mut det = LSDDetector::default()?;
+	let mut lines = VectorOfKeyLine::new();
+	det.detect(&img, &mut lines, 2, 1, &Mat::default())?;
+	let long = lines.iter()
+		.filter(|kl| kl.line_length > 50.)
+		.collect::<Vec<KeyLine>>();
+	assert!(!long.is_empty());
+	assert_eq!(lines.as_slice().iter().filter(|kl| kl.line_length > 50.).count(), long.len());
+	let mut count = 0;
+	for kl in &lines {
+		assert!(kl.line_length > 0.);
+		count += 1;
+	}
+	assert_eq!(lines.len(), count);
+	Ok(())
+}
+
+#[test]
+fn keyline_try_new() -> Result<()> {
+	let size = Size::new(640, 480);
+	// simple deterministic LCG to avoid pulling in a random crate
+	let mut seed = 0x2545_f491_u32;
+	let mut rand = move || {
+		seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+		(seed >> 8) as f32 / (1 << 24) as f32
+	};
+	for _ in 0..1000 {
+		let start = Point2f::new(rand() * 640., rand() * 480.);
+		let end = Point2f::new(rand() * 640., rand() * 480.);
+		let octave = (rand() * 4.) as i32;
+		let line = KeyLine::try_new(start, end, size, 7, octave)?;
+		assert_eq!(7, line.class_id);
+		assert_eq!(octave, line.octave);
+		assert_eq!(start, line.get_start_point()?);
+		assert_eq!(end, line.get_end_point()?);
+		assert!((line.line_length - (end - start).norm() as f32).abs() < 1e-3);
+		assert!((line.pt.x - (start.x + end.x) / 2.).abs() < 1e-3);
+		assert!((line.pt.y - (start.y + end.y) / 2.).abs() < 1e-3);
+		assert!(line.angle >= -std::f32::consts::PI && line.angle <= std::f32::consts::PI);
+		assert!((line.response - line.line_length / 640.).abs() < 1e-6);
+		assert!(line.num_of_pixels >= 1);
+		assert!(line.num_of_pixels as f32 <= line.line_length + 2.);
+		assert_eq!(start, line.get_start_point_in_octave()?);
+
+		let scaled = line.with_octave_scaled(2.);
+		let factor = 2f32.powi(octave);
+		assert!((scaled.s_point_in_octave_x * factor - start.x).abs() < 1e-3);
+		assert!((scaled.e_point_in_octave_y * factor - end.y).abs() < 1e-3);
+		assert_eq!(line.get_start_point()?, scaled.get_start_point()?);
+	}
+
+	assert_eq!(11, KeyLine::try_new(Point2f::new(0., 0.), Point2f::new(10., 3.), size, 0, 0)?.num_of_pixels);
+	assert_matches!(KeyLine::try_new(Point2f::new(1., 1.), Point2f::new(1., 1.), size, 0, 0), Err(Error { code: core::StsBadArg, .. }));
+	assert_matches!(KeyLine::try_new(Point2f::new(f32::NAN, 1.), Point2f::new(1., 1.), size, 0, 0), Err(Error { code: core::StsBadArg, .. }));
+	assert_matches!(KeyLine::try_new(Point2f::new(0., 1.), Point2f::new(1., 1.), size, 0, -1), Err(Error { code: core::StsBadArg, .. }));
+	Ok(())
+}
+
+#[test]
+fn binary_descriptor_params_builder() -> Result<()> {
+	let params = BinaryDescriptor_Params::builder()
+		.width_of_band(11)
+		.num_of_octaves(2)
+		.build()?;
+	assert_eq!(11, params.width_of_band_());
+	assert_eq!(2, params.num_of_octave_());
+	assert_eq!(BinaryDescriptor_Params::default()?.reduction_ratio(), params.reduction_ratio());
+
+	let mut bd = BinaryDescriptor::create_binary_descriptor_1(params)?;
+	assert_eq!(11, bd.get_width_of_band()?);
+	assert_eq!(2, bd.get_num_of_octaves()?);
+
+	assert_matches!(
+		BinaryDescriptor_Params::builder().reduction_ratio(0).build().map(|_| ()),
+		Err(Error { code: core::StsBadArg, .. })
+	);
+	Ok(())
+}
+
+/// Call every bound constructor and method of the module once to catch binding regressions
+///
+/// This list is maintained by hand, the binding generator doesn't emit it. When the module bindings change, every
+/// new function must be either called here or added to the skip list below with a reason.
+///
+/// Skipped:
+/// * `as_raw_*`, `Drop::drop` - called implicitly by every other call and at the end of the test
+#[test]
+fn touch_all_line_descriptor() -> Result<()> {
+	let img = blox()?;
+	let no_mask = Mat::default();
+
+	let lsd_param = LSDParam::default()?;
+	let mut lsd_detectors: Vec<Box<dyn LSDDetectorTrait>> = vec![
+		Box::new(LSDDetector::default()?),
+		Box::new(LSDDetector::new(lsd_param)?),
+		Box::new(LSDDetector::create_lsd_detector()?),
+		Box::new(LSDDetector::create_lsd_detector_with_params(lsd_param)?),
+	];
+	for det in &mut lsd_detectors {
+		let mut lines = VectorOfKeyLine::new();
+		det.detect(&img, &mut lines, 2, 1, &no_mask)?;
+		let mut multi = VectorOfVectorOfKeyLine::new();
+		det.detect_multiple(&VectorOfMat::from_iter(vec![img.try_clone()?]), &mut multi, 2, 1, &VectorOfMat::new())?;
+		assert_eq!(1, multi.len());
+	}
+
+	let mut params = BinaryDescriptor_Params::default()?;
+	params.set_ksize_(params.ksize_());
+	params.set_num_of_octave_(params.num_of_octave_());
+	params.set_width_of_band_(params.width_of_band_());
+	params.set_reduction_ratio(params.reduction_ratio());
+	let mut descriptors: Vec<Box<dyn BinaryDescriptorTrait>> = vec![
+		Box::new(BinaryDescriptor::new(&params)?),
+		Box::new(BinaryDescriptor::create_binary_descriptor()?),
+		Box::new(BinaryDescriptor::create_binary_descriptor_1(BinaryDescriptor_Params::default()?)?),
+	];
+	let mut lines = VectorOfKeyLine::new();
+	let mut desc = Mat::default();
+	for bd in &mut descriptors {
+		let num_of_octaves = bd.get_num_of_octaves()?;
+		bd.set_num_of_octaves(num_of_octaves)?;
+		let width_of_band = bd.get_width_of_band()?;
+		bd.set_width_of_band(width_of_band)?;
+		let reduction_ratio = bd.get_reduction_ratio()?;
+		bd.set_reduction_ratio(reduction_ratio)?;
+		bd.descriptor_size()?;
+		bd.descriptor_type()?;
+		bd.default_norm()?;
+		bd.detect(&img, &mut lines, &no_mask)?;
+		bd.compute(&img, &mut lines, &mut desc, false)?;
+		let images = VectorOfMat::from_iter(vec![img.try_clone()?]);
+		let mut multi_lines = VectorOfVectorOfKeyLine::new();
+		bd.detect_1(&images, &mut multi_lines, &VectorOfMat::new())?;
+		let mut multi_desc = VectorOfMat::new();
+		bd.compute_1(&images, &mut multi_lines, &mut multi_desc, false)?;
+	}
+
+	#[cfg(ocvrs_opencv_branch_4)]
+	{
+		use opencv::core::{FileStorage, FileStorage_Mode};
+
+		let mut st = FileStorage::new(".yml", FileStorage_Mode::WRITE as i32 | FileStorage_Mode::MEMORY as i32, "")?;
+		BinaryDescriptorTrait::write(&*descriptors[0], &mut st)?;
+		let serialized = st.release_and_get_string()?;
+		let st = FileStorage::new(&serialized, FileStorage_Mode::MEMORY as i32, "")?;
+		BinaryDescriptorTrait::read(&mut *descriptors[0], &st.root(0)?)?;
+	}
+
+	let mut matchers: Vec<Box<dyn BinaryDescriptorMatcherTrait>> = vec![
+		Box::new(BinaryDescriptorMatcher::default()?),
+		Box::new(BinaryDescriptorMatcher::create_binary_descriptor_matcher()?),
+	];
+	for matcher in &mut matchers {
+		let mut matches = VectorOfDMatch::new();
+		matcher.match_(&desc, &desc, &mut matches, &no_mask)?;
+		let mut knn = VectorOfVectorOfDMatch::new();
+		matcher.knn_match(&desc, &desc, &mut knn, 2, &no_mask, false)?;
+		matcher.radius_match(&desc, &desc, &mut knn, 30., &no_mask, false)?;
+		matcher.add(&VectorOfMat::from_iter(vec![desc.try_clone()?]))?;
+		matcher.train()?;
+		matcher.match_query(&desc, &mut matches, &VectorOfMat::new())?;
+		matcher.knn_match_query(&desc, &mut knn, 2, &VectorOfMat::new(), false)?;
+		matcher.radius_match_1(&desc, &mut knn, 30., &VectorOfMat::new(), false)?;
+		BinaryDescriptorMatcherTrait::clear(&mut **matcher)?;
+	}
+
+	let kl = KeyLine::default()?;
+	kl.get_start_point()?;
+	kl.get_end_point()?;
+	kl.get_start_point_in_octave()?;
+	kl.get_end_point_in_octave()?;
+
+	let mut out = Mat::default();
+	line_descriptor::draw_keylines(&img, &lines, &mut out, Scalar::all(-1.), DrawLinesMatchesFlags::DEFAULT)?;
+	let mut matches = VectorOfDMatch::new();
+	BinaryDescriptorMatcher::default()?.match_(&desc, &desc, &mut matches, &no_mask)?;
+	line_descriptor::draw_line_matches(
+		&img,
+		&lines,
+		&img,
+		&lines,
+		&matches,
+		&mut out,
+		Scalar::all(-1.),
+		Scalar::all(-1.),
+		&VectorOfi8::new(),
+		DrawLinesMatchesFlags::DEFAULT,
+	)?;
+	Ok(())
+}
+
+#[test]
+fn radius_match_vec() -> Result<()> {
+	let img = blox()?;
+	let (_, desc) = detect_and_compute(&img)?;
+	assert!(desc.rows() > 0);
+
+	let matcher = BinaryDescriptorMatcher::default()?;
+	let matches = matcher.radius_match_vec(&desc, &desc, 0.)?;
+	assert_eq!(desc.rows() as usize, matches.len());
+	for (query_idx, query_matches) in matches.iter().enumerate() {
+		assert!(query_matches.iter().any(|m| m.train_idx == query_idx as i32 && m.distance == 0.));
+	}
+	Ok(())
+}
+
+#[test]
+fn lsd_detector_ptr() -> Result<()> {
+	let img = blox()?;
+	let mut det = LSDDetector::create_lsd_detector()?;
+	let mut lines = VectorOfKeyLine::new();
+	det.detect(&img, &mut lines, 2, 1, &Mat::default())?;
+	assert!(!lines.is_empty());
+	assert_eq!(lines.to_vec(), det.detect_lines(&img, 2, 1)?);
+
+	let mut det = LSDDetector::create_lsd_detector_with_params(LSDParam::fast())?;
+	assert!(det.detect_nonempty(&img, 2, 1)?.is_some());
+	Ok(())
+}
+
+#[test]
+fn detect_nonempty() -> Result<()> {
+	let mut lsd = LSDDetector::default()?;
+	let mut img = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC1, Scalar::all(0.))?;
+	assert_eq!(None, lsd.detect_nonempty(&img, 2, 1)?);
+	imgproc::line(&mut img, core::Point::new(10, 50), core::Point::new(90, 50), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let lines = lsd.detect_nonempty(&img, 2, 1)?.expect("Lines must be detected");
+	assert!(!lines.is_empty());
+	Ok(())
+}
+
+#[test]
+fn knn_match_vec() -> Result<()> {
+	let img = blox()?;
+	let (_, desc) = detect_and_compute(&img)?;
+	assert!(desc.rows() >= 2);
+
+	let matcher = BinaryDescriptorMatcher::default()?;
+	let matches = matcher.knn_match_vec(&desc, &desc, 2)?;
+	assert_eq!(desc.rows() as usize, matches.len());
+	for query_matches in &matches {
+		assert_eq!(2, query_matches.len());
+		assert!(query_matches[0].distance <= query_matches[1].distance);
+		assert_eq!(0., query_matches[0].distance);
+	}
+	Ok(())
+}
+
+#[test]
+fn keyline_length_angle() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(200, 200, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut img, Rect::new(50, 50, 100, 80), Scalar::all(255.), 3, imgproc::LINE_8, 0)?;
+	let lines = LSDDetector::default()?.detect_lines(&img, 2, 1)?;
+	assert!(!lines.is_empty());
+	for line in lines {
+		assert_eq!(0, line.octave);
+		assert!((line.length() - line.line_length).abs() < 1e-3);
+	}
+
+	let mut line = KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(3., 4.));
+	line.line_length = 0.;
+	assert_eq!(5., line.length());
+	let line = KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(0., 10.));
+	assert!((line.angle_degrees() - 90.).abs() < 1e-4);
+	Ok(())
+}
+
+#[test]
+fn render_lines_f32() -> Result<()> {
+	let line = KeyLine::from_points(Point2f::new(5., 10.25), Point2f::new(30., 10.25));
+	let lines = VectorOfKeyLine::from_iter(vec![line]);
+	let size = Size::new(40, 20);
+
+	let linear = line_descriptor::render_lines_f32(&lines, size, 1., line_descriptor::Falloff::Linear)?;
+	assert_eq!(core::CV_32FC1, linear.typ()?);
+	let profile = linear.col_typed::<f32>(15)?;
+	for (y, &val) in profile.iter().enumerate() {
+		let expected = match y {
+			10 => 0.75,
+			11 => 0.25,
+			_ => 0.,
+		};
+		assert!((val - expected).abs() < 1e-5, "y: {}, val: {}", y, val);
+	}
+
+	let gaussian = line_descriptor::render_lines_f32(&lines, size, 2., line_descriptor::Falloff::Gaussian)?;
+	for (y, &val) in gaussian.col_typed::<f32>(15)?.iter().enumerate() {
+		let d = y as f32 - 10.25;
+		let expected = if d.abs() <= 3. { (-d * d / 2.).exp() } else { 0. };
+		assert!((val - expected).abs() < 1e-5, "y: {}, val: {}", y, val);
+	}
+
+	let corner = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(39., 19.))]);
+	for &falloff in &[line_descriptor::Falloff::Linear, line_descriptor::Falloff::Gaussian] {
+		let thin = line_descriptor::render_lines_f32(&corner, size, 0.4, falloff)?;
+		let data = thin.data_typed::<f32>()?;
+		assert!(data.iter().all(|v| v.is_finite() && *v >= 0. && *v <= 0.4 + 1e-6));
+		assert!(*thin.at_2d::<f32>(0, 0)? > 0.);
+		assert!(*thin.at_2d::<f32>(19, 39)? > 0.);
+	}
+
+	assert_matches!(line_descriptor::render_lines_f32(&lines, size, 0., line_descriptor::Falloff::Linear), Err(Error { code: core::StsBadArg, .. }));
+	Ok(())
+}
+
+#[test]
+fn matcher_dataset_round_trip() -> Result<()> {
+	let mut train = Mat::new_rows_cols_with_default(50, 32, core::CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut train, &Scalar::all(0.), &Scalar::all(256.))?;
+	let mut query = Mat::new_rows_cols_with_default(10, 32, core::CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut query, &Scalar::all(0.), &Scalar::all(256.))?;
+	let dataset = VectorOfMat::from_iter(vec![train]);
+
+	let mut matcher = BinaryDescriptorMatcher::default()?;
+	matcher.add(&dataset)?;
+	matcher.train()?;
+	let mut expected = VectorOfDMatch::new();
+	matcher.match_query(&query, &mut expected, &VectorOfMat::new())?;
+
+	let path = temp_path("dataset_round_trip.bin");
+	let path = path.to_str().unwrap();
+	line_descriptor::save_descriptors(path, &dataset)?;
+	let mut loaded = BinaryDescriptorMatcher::default()?;
+	loaded.load_dataset(path)?;
+	let mut actual = VectorOfDMatch::new();
+	loaded.match_query(&query, &mut actual, &VectorOfMat::new())?;
+	std::fs::remove_file(path).ok();
+
+	assert_eq!(expected.len(), actual.len());
+	for (e, a) in expected.iter().zip(actual.iter()) {
+		assert_eq!((e.query_idx, e.train_idx, e.img_idx, e.distance), (a.query_idx, a.train_idx, a.img_idx, a.distance));
+	}
+
+	assert_matches!(line_descriptor::load_descriptors("/non/existent/path"), Err(Error { code: core::StsError, .. }));
+
+	let crafted_path = temp_path("dataset_crafted.bin");
+	let crafted_path = crafted_path.to_str().unwrap();
+	let crafted = |count: u32, rows: i32, cols: i32| -> Result<VectorOfMat> {
+		let mut data = b"OCVRSLD1".to_vec();
+		data.extend_from_slice(&count.to_le_bytes());
+		data.extend_from_slice(&rows.to_le_bytes());
+		data.extend_from_slice(&cols.to_le_bytes());
+		data.extend_from_slice(&[0; 16]);
+		std::fs::write(crafted_path, data).unwrap();
+		line_descriptor::load_descriptors(crafted_path)
+	};
+	assert_eq!(1, crafted(1, 2, 8)?.len());
+	assert_matches!(crafted(u32::MAX, 2, 8), Err(Error { code: core::StsParseError, .. }));
+	assert_matches!(crafted(1, i32::MAX, i32::MAX), Err(Error { code: core::StsParseError, .. }));
+	assert_matches!(crafted(1, 3, 8), Err(Error { code: core::StsParseError, .. }));
+	assert_matches!(crafted(1, -1, 8), Err(Error { code: core::StsParseError, .. }));
+	std::fs::remove_file(crafted_path).ok();
+	Ok(())
+}
+
+#[test]
+fn sync_matcher() -> Result<()> {
+	let mut train = Mat::new_rows_cols_with_default(40, 32, core::CV_8UC1, Scalar::all(0.))?;
+	core::randu(&mut train, &Scalar::all(0.), &Scalar::all(256.))?;
+	let matcher = std::sync::Arc::new(SyncMatcher::from_dataset(&[train.try_clone()?])?);
+
+	let handles = (0..4)
+		.map(|i| -> Result<_> {
+			let query = train.row_bounds(i * 10, i * 10 + 10)?.try_clone()?;
+			let matcher = matcher.clone();
+			Ok(std::thread::spawn(move || -> Result<Vec<DMatch>> {
+				let mut out = vec![];
+				for _ in 0..5 {
+					out = matcher.match_query(&query)?;
+				}
+				Ok(out)
+			}))
+		})
+		.collect::<Result<Vec<_>>>()?;
+	for (i, handle) in handles.into_iter().enumerate() {
+		let matches = handle.join().unwrap()?;
+		assert_eq!(10, matches.len());
+		for (j, m) in matches.iter().enumerate() {
+			assert_eq!(j as i32, m.query_idx);
+			assert_eq!((i * 10 + j) as i32, m.train_idx);
+			assert_eq!(0., m.distance);
+		}
+	}
+
+	let knn = matcher.knn_match_query(&train.row_bounds(0, 3)?.try_clone()?, 2)?;
+	assert_eq!(3, knn.len());
+	assert!(knn.iter().all(|m| m.len() == 2 && m[0].distance == 0.));
+	let matcher = std::sync::Arc::try_unwrap(matcher).ok().unwrap();
+	BinaryDescriptorMatcherTrait::clear(&mut *matcher.lock()?)?;
+	matcher.into_inner()?;
+	Ok(())
+}
+
+#[test]
+fn keyframe_selector() -> Result<()> {
+	let line = |y: f32| KeyLine::from_points(Point2f::new(0.5, y), Point2f::new(9.5, y));
+	let mut selector = line_descriptor::KeyframeSelector::new(Size::new(10, 10), 0.5)?;
+
+	let first = VectorOfKeyLine::from_iter(vec![line(0.5)]);
+	assert_eq!(line_descriptor::KeyframeDecision::Accepted(1.), selector.consider(&first, None)?);
+	assert!((selector.coverage() - 0.1).abs() < 1e-6);
+
+	let overlapping = VectorOfKeyLine::from_iter(vec![line(0.5), line(1.5)]);
+	assert_eq!(line_descriptor::KeyframeDecision::Accepted(0.5), selector.consider(&overlapping, None)?);
+	assert_eq!(line_descriptor::KeyframeDecision::Rejected(0.), selector.consider(&overlapping, None)?);
+
+	let shift = Mat::from_slice_2d(&[[1f64, 0., 0.], [0., 1., 5.], [0., 0., 1.]])?;
+	let disjoint = VectorOfKeyLine::from_iter(vec![line(0.5)]);
+	let decision = selector.consider(&disjoint, Some(&shift))?;
+	assert!(decision.is_accepted());
+	assert_eq!(1., decision.new_coverage());
+	assert_eq!(line_descriptor::KeyframeDecision::Rejected(0.), selector.consider(&disjoint, Some(&shift))?);
+	assert!((selector.coverage() - 0.3).abs() < 1e-6);
+
+	selector.reset();
+	assert_eq!(0., selector.coverage());
+	assert!(selector.consider(&VectorOfKeyLine::new(), None)?.new_coverage() == 0.);
+
+	// only the part of the line within the grid is rasterized
+	let huge = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(-1e30, 2.5), Point2f::new(1e30, 2.5))]);
+	assert_eq!(line_descriptor::KeyframeDecision::Accepted(1.), selector.consider(&huge, None)?);
+	assert!((selector.coverage() - 0.1).abs() < 1e-6);
+	let outside = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(-5., -5.), Point2f::new(-1., 20.))]);
+	assert_eq!(line_descriptor::KeyframeDecision::Rejected(0.), selector.consider(&outside, None)?);
+	let nan = VectorOfKeyLine::from_iter(vec![KeyLine::from_points(Point2f::new(f32::NAN, 1.), Point2f::new(5., 1.))]);
+	assert_eq!(line_descriptor::KeyframeDecision::Rejected(0.), selector.consider(&nan, None)?);
+
+	assert_matches!(line_descriptor::KeyframeSelector::new(Size::new(i32::MAX, 2), 0.5), Err(Error { code: core::StsBadArg, .. }));
+	assert_matches!(line_descriptor::KeyframeSelector::new(Size::new(-4, -4), 0.5), Err(Error { code: core::StsBadArg, .. }));
+
+	#[cfg(feature = "serde")]
+	{
+		let json = serde_json::to_string(&selector).unwrap();
+		let mut restored: line_descriptor::KeyframeSelector = serde_json::from_str(&json).unwrap();
+		assert!((restored.coverage() - 0.1).abs() < 1e-6);
+		assert_eq!(line_descriptor::KeyframeDecision::Rejected(0.), restored.consider(&huge, None)?);
+		let truncated = json.replacen("[false,", "[", 1);
+		assert!(serde_json::from_str::<line_descriptor::KeyframeSelector>(&truncated).is_err());
+	}
+	Ok(())
+}
+
+#[test]
+fn lsd_param_presets() -> Result<()> {
+	let img = blox()?;
+	let fast = LSDDetector::new(LSDParam::fast())?.detect_lines(&img, 2, 1)?;
+	let precise = LSDDetector::new(LSDParam::high_precision())?.detect_lines(&img, 2, 1)?;
+	assert!(!precise.is_empty());
+	assert!(fast.len() <= precise.len());
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn coco_export() -> Result<()> {
+	use line_descriptor::export::{self, CocoWriter};
+
+	let lines = VectorOfKeyLine::from_iter(vec![
+		KeyLine::from_points(Point2f::new(10., 20.), Point2f::new(30., 5.)),
+		KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(0., 40.)),
+	]);
+	let annotations = export::to_coco_lines(7, &lines, Some(&[0.9, 0.4]))?;
+	assert_eq!(2, annotations.as_array().unwrap().len());
+	assert_eq!(serde_json::json!([10., 5., 20., 15.]), annotations[0]["bbox"]);
+	assert!(annotations[1]["id"].is_null());
+	assert_matches!(export::to_coco_lines(7, &lines, Some(&[0.9])), Err(Error { code: core::StsUnmatchedSizes, .. }));
+
+	let mut writer = CocoWriter::new();
+	writer.add_image(7, "first.png", Size::new(50, 50), &lines, Some(&[0.9, 0.4]))?;
+	writer.add_image(8, "second.png", Size::new(50, 50), &VectorOfKeyLine::new(), None)?;
+	assert_eq!(Some(2), writer.document().annotations[1].id);
+	let path = temp_path("coco_lines.json");
+	let path = path.to_str().unwrap();
+	writer.write(path)?;
+
+	let doc: serde_json::Value = serde_json::from_slice(&std::fs::read(path).unwrap()).unwrap();
+	for section in &["images", "annotations", "categories"] {
+		assert!(doc[section].is_array(), "missing section: {}", section);
+	}
+	for annotation in doc["annotations"].as_array().unwrap() {
+		for key in &["id", "image_id", "category_id", "segmentation", "bbox", "area", "iscrowd", "score"] {
+			assert!(!annotation[key].is_null(), "missing annotation key: {}", key);
+		}
+	}
+
+	let read = export::from_coco_lines(path)?;
+	std::fs::remove_file(path).ok();
+	assert_eq!(2, read.len());
+	assert!(read[&8].is_empty());
+	let read_lines = read[&7].to_vec();
+	assert_eq!(2, read_lines.len());
+	assert_eq!((10., 20., 30., 5.), (read_lines[0].start_point_x, read_lines[0].start_point_y, read_lines[0].end_point_x, read_lines[0].end_point_y));
+	assert!((read_lines[1].response - 0.4).abs() < 1e-6);
+
+	let mut invalid = writer.document();
+	invalid.annotations[0].segmentation.clear();
+	assert_matches!(export::from_coco_document(invalid), Err(Error { code: core::StsParseError, .. }));
+	let garbage = temp_path("coco_garbage.json");
+	let garbage = garbage.to_str().unwrap();
+	std::fs::write(garbage, b"{\"images\": 1}").unwrap();
+	let res = export::from_coco_lines(garbage);
+	std::fs::remove_file(garbage).ok();
+	assert_matches!(res, Err(Error { code: core::StsParseError, .. }));
+	Ok(())
+}
+
+#[test]
+fn binary_descriptor_try_clone() -> Result<()> {
+	let mut params = BinaryDescriptor_Params::default()?;
+	params.set_ksize_(7);
+	params.set_reduction_ratio(3);
+	let mut bd = BinaryDescriptor::create_binary_descriptor_1(params)?;
+	bd.set_width_of_band(9)?;
+	bd.set_num_of_octaves(2)?;
+	let mut cloned = bd.try_clone()?;
+	assert_eq!(9, cloned.get_width_of_band()?);
+	assert_eq!(2, cloned.get_num_of_octaves()?);
+	assert_eq!(3, cloned.get_reduction_ratio()?);
+	let cloned_params = cloned.params()?;
+	assert_eq!(7, cloned_params.ksize_());
+	assert_eq!(2, cloned_params.num_of_octave_());
+	assert_eq!(9, cloned_params.width_of_band_());
+	cloned.set_width_of_band(5)?;
+	assert_eq!(9, bd.get_width_of_band()?);
+
+	let img = blox()?;
+	let (mut expected, mut cloned_lines) = (VectorOfKeyLine::new(), VectorOfKeyLine::new());
+	bd.detect(&img, &mut expected, &Mat::default())?;
+	bd.try_clone()?.detect(&img, &mut cloned_lines, &Mat::default())?;
+	assert_eq!(expected.to_vec(), cloned_lines.to_vec());
+	Ok(())
+}
+
+#[test]
+fn detector_registry() -> Result<()> {
+	use std::sync::Arc;
+
+	use line_descriptor::registry::{self, PipelineConfig};
+
+	struct MockDetector(usize);
+
+	impl LineDetector for MockDetector {
+		fn detect_keylines(&mut self, _image: &Mat, _mask: Option<&Mat>) -> Result<VectorOfKeyLine> {
+			Ok((0..self.0).map(|i| KeyLine::from_points(Point2f::new(0., i as f32), Point2f::new(10., i as f32))).collect())
+		}
+	}
+
+	registry::register_detector("mock", Arc::new(|config| {
+		Ok(Box::new(MockDetector(config.get_i32("lines")?.unwrap_or(1) as usize)))
+	}))?;
+	assert_matches!(registry::register_detector("mock", Arc::new(|_| Ok(Box::new(MockDetector(0))))), Err(Error { code: core::StsBadArg, .. }));
+
+	// factory using the registry must not deadlock
+	registry::register_detector("nested", Arc::new(|config| {
+		registry::register_detector("nested_inner", Arc::new(|_| Ok(Box::new(MockDetector(2)))))?;
+		registry::create_detector("mock", config)
+	}))?;
+	let mut nested = registry::create_detector("nested", &PipelineConfig::parse("lines=4")?)?;
+	assert_eq!(4, nested.detect_keylines(&Mat::default(), None)?.len());
+	assert!(registry::list_detectors().iter().any(|d| d == "nested_inner"));
+
+	let config = PipelineConfig::parse("lines=3; unused = x")?;
+	let mut detector = registry::create_detector("mock", &config)?;
+	assert_eq!(3, detector.detect_keylines(&Mat::default(), None)?.len());
+
+	let list = registry::list_detectors();
+	let mut sorted = list.clone();
+	sorted.sort();
+	assert_eq!(sorted, list);
+	assert_eq!(list, registry::list_detectors());
+	assert!(list.iter().any(|d| d == "binary") && list.iter().any(|d| d == "lsd"));
+
+	let mut lsd = registry::create_detector("lsd", &PipelineConfig::parse("octaves=1")?)?;
+	assert!(!lsd.detect_keylines(&blox()?, None)?.is_empty());
+	match registry::create_detector("missing", &config) {
+		Err(e) => assert!(e.message.contains("binary, lsd, mock")),
+		Ok(_) => panic!("Unknown detector must fail"),
+	}
+	assert_matches!(PipelineConfig::parse("octaves").map(|_| ()), Err(Error { code: core::StsParseError, .. }));
+	Ok(())
+}
+
+#[test]
+fn detect_masked() -> Result<()> {
+	let img = blox()?;
+	let empty = Mat::default();
+
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let (mut with_empty, mut with_none) = (VectorOfKeyLine::new(), VectorOfKeyLine::new());
+	bd.detect(&img, &mut with_empty, &empty)?;
+	bd.detect_masked(&img, &mut with_none, None)?;
+	assert!(!with_none.is_empty());
+	assert_eq!(with_empty.to_vec(), with_none.to_vec());
+
+	let mut lsd = LSDDetector::default()?;
+	let (mut with_empty, mut with_none) = (VectorOfKeyLine::new(), VectorOfKeyLine::new());
+	lsd.detect(&img, &mut with_empty, 2, 1, &empty)?;
+	lsd.detect_masked(&img, &mut with_none, 2, 1, None)?;
+	assert!(!with_none.is_empty());
+	assert_eq!(with_empty.to_vec(), with_none.to_vec());
+	Ok(())
+}
+
+#[test]
+fn draw_keylines_scalar_from_tuple() -> Result<()> {
+	let img = Mat::new_rows_cols_with_default(20, 20, core::CV_8UC3, Scalar::all(0.))?;
+	let mut lines = VectorOfKeyLine::new();
+	lines.push(KeyLine::from_points(Point2f::new(2., 10.), Point2f::new(17., 10.)));
+	let mut out = Mat::default();
+	line_descriptor::draw_keylines(&img, &lines, &mut out, Scalar::from((255., 0., 0.)), DrawLinesMatchesFlags::DEFAULT)?;
+	assert_eq!(Vec3b::from([255, 0, 0]), *out.at_2d::<Vec3b>(10, 10)?);
+	Ok(())
+}
+
+#[test]
+fn keyline_bounding_rect() {
+	let diagonal = KeyLine::from_points(Point2f::new(30., 5.), Point2f::new(10., 25.));
+	assert_eq!(Rect::new(10, 5, 20, 20), diagonal.bounding_rect());
+	let fractional = KeyLine::from_points(Point2f::new(1.2, 2.8), Point2f::new(5.5, 7.1));
+	assert_eq!(Rect::new(1, 2, 5, 6), fractional.bounding_rect());
+	let horizontal = KeyLine::from_points(Point2f::new(3., 7.), Point2f::new(13., 7.));
+	assert_eq!(Rect::new(3, 7, 10, 1), horizontal.bounding_rect());
+	let vertical = KeyLine::from_points(Point2f::new(4., 20.), Point2f::new(4., 2.));
+	assert_eq!(Rect::new(4, 2, 1, 18), vertical.bounding_rect());
+}
+
+#[test]
+fn keyline_to_vec4() -> Result<()> {
+	let lines = LSDDetector::default()?.detect_lines(&blox()?, 2, 1)?;
+	let line = lines.first().copied().unwrap();
+	let v = Vec4f::from(line);
+	assert_eq!([line.start_point_x, line.start_point_y, line.end_point_x, line.end_point_y], *v);
+
+	let line = KeyLine::from_points(Point2f::new(1.4, 2.6), Point2f::new(10.5, -3.5));
+	assert_eq!(Vec4i::from([1, 3, 11, -4]), line.to_vec4i());
+	Ok(())
+}
+
+#[test]
+fn filter_keylines() -> Result<()> {
+	let mut lines = VectorOfKeyLine::new();
+	for (len, octave) in &[(2., 0), (30., 0), (5., 1), (50., 1), (10., 0)] {
+		let mut line = KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(*len, 0.));
+		line.octave = *octave;
+		lines.push(line);
+	}
+	let long = line_descriptor::filter_keylines_by_length(&lines, 10.);
+	assert_eq!(vec![30., 50., 10.], long.iter().map(|l| l.line_length).collect::<Vec<_>>());
+	assert!(line_descriptor::filter_keylines_by_length(&lines, 100.).is_empty());
+	assert_eq!(5, line_descriptor::filter_keylines_by_length(&lines, 0.).len());
+
+	let octave1 = line_descriptor::filter_keylines_by_octave(&lines, 1);
+	assert_eq!(vec![5., 50.], octave1.iter().map(|l| l.line_length).collect::<Vec<_>>());
+	assert!(line_descriptor::filter_keylines_by_octave(&lines, 2).is_empty());
+	Ok(())
+}
+
+#[test]
+fn group_by_class_id() -> Result<()> {
+	let mut lines = VectorOfKeyLine::new();
+	for (class_id, octave) in &[(0, 0), (1, 0), (0, 1), (2, 0), (1, 1), (0, 2)] {
+		let mut line = KeyLine::from_points(Point2f::new(0., 0.), Point2f::new(10., 0.));
+		line.class_id = *class_id;
+		line.octave = *octave;
+		lines.push(line);
+	}
+	let groups = line_descriptor::group_by_class_id(&lines);
+	assert_eq!(3, groups.len());
+	assert_eq!(vec![0, 1, 2], groups[&0].iter().map(|l| l.octave).collect::<Vec<_>>());
+	assert_eq!(vec![0, 1], groups[&1].iter().map(|l| l.octave).collect::<Vec<_>>());
+	assert_eq!(vec![0], groups[&2].iter().map(|l| l.octave).collect::<Vec<_>>());
+	assert!(line_descriptor::group_by_class_id(&VectorOfKeyLine::new()).is_empty());
+	Ok(())
+}
+
+#[test]
+fn make_polygon_mask() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(200, 200, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut img, Rect::new(20, 30, 50, 60), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	imgproc::rectangle(&mut img, Rect::new(130, 110, 50, 60), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let left_half = VectorOfPoint::from_iter(vec![
+		core::Point::new(0, 0),
+		core::Point::new(99, 0),
+		core::Point::new(99, 199),
+		core::Point::new(0, 199),
+	]);
+	let mask = line_descriptor::make_polygon_mask(img.size()?, &left_half)?;
+	assert_eq!(img.size()?, mask.size()?);
+	assert_eq!(core::CV_8UC1, mask.typ()?);
+	assert_eq!(255, *mask.at_2d::<u8>(100, 50)?);
+	assert_eq!(0, *mask.at_2d::<u8>(100, 150)?);
+
+	let in_right_half = |l: &KeyLine| l.start_point_x > 100. && l.end_point_x > 100.;
+	let mut lsd = LSDDetector::default()?;
+	let all = lsd.detect_lines(&img, 2, 1)?;
+	assert!(all.iter().any(in_right_half));
+	let mut masked = VectorOfKeyLine::new();
+	lsd.detect(&img, &mut masked, 2, 1, &mask)?;
+	assert!(!masked.is_empty());
+	assert!(!masked.iter().any(|l| in_right_half(&l)));
+
+	let empty = line_descriptor::make_polygon_mask(img.size()?, &VectorOfPoint::new())?;
+	assert_eq!(0, core::count_non_zero(&empty)?);
+	assert_matches!(line_descriptor::make_polygon_mask(core::Size::new(0, 10), &left_half), Err(Error { code: core::StsBadSize, .. }));
+	Ok(())
+}
+
+#[test]
+fn detect_roi() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(200, 200, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut img, Rect::new(20, 20, 40, 40), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	imgproc::rectangle(&mut img, Rect::new(120, 110, 50, 60), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let roi = Rect::new(100, 100, 90, 90);
+
+	let mut lsd = LSDDetector::default()?;
+	let mut lines = VectorOfKeyLine::new();
+	lsd.detect_roi(&img, roi, &mut lines, 2, 2)?;
+	assert!(!lines.is_empty());
+	let mut roi_f = roi.to::<f32>().unwrap();
+	roi_f.width += 1.;
+	roi_f.height += 1.;
+	for line in &lines {
+		for &(x, y) in &[(line.start_point_x, line.start_point_y), (line.end_point_x, line.end_point_y), (line.pt.x, line.pt.y)] {
+			assert!(roi_f.contains(Point2f::new(x, y)), "{} is outside of the ROI", line);
+		}
+		let octave_scale = 2f32.powi(line.octave);
+		assert!((line.s_point_in_octave_x * octave_scale - line.start_point_x).abs() < 1.);
+		assert!((line.e_point_in_octave_y * octave_scale - line.end_point_y).abs() < 1.);
+	}
+
+	let view = MatView::roi(&img, roi)?;
+	let mut view_lines = VectorOfKeyLine::new();
+	lsd.detect_view(&view, &mut view_lines, 2, 2)?;
+	assert_eq!(view_lines.len(), lines.len());
+	for (view_line, line) in view_lines.iter().zip(lines.iter()) {
+		assert_eq!(view_line.start_point_x + 100., line.start_point_x);
+		assert_eq!(view_line.end_point_y + 100., line.end_point_y);
+	}
+
+	assert_matches!(lsd.detect_roi(&img, Rect::new(150, 150, 100, 100), &mut lines, 2, 1), Err(Error { code: core::StsOutOfRange, .. }));
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn detect_parallel() -> Result<()> {
+	let img = blox()?;
+	let images = (0..8).map(|_| img.try_clone()).collect::<Result<Vec<_>>>()?;
+	let mut params = BinaryDescriptor_Params::default()?;
+	params.set_ksize_(9);
+	let mut bd = BinaryDescriptor::create_binary_descriptor_1(params)?;
+	let all_lines = bd.detect_parallel(&images)?;
+	assert_eq!(8, all_lines.len());
+	let mut expected = VectorOfKeyLine::new();
+	bd.detect(&img, &mut expected, &Mat::default())?;
+	let expected = expected.to_vec();
+	assert!(!expected.is_empty());
+	for lines in all_lines {
+		assert_eq!(expected, lines);
+	}
+	assert!(bd.detect_parallel(&[])?.is_empty());
+	Ok(())
+}
+
+#[test]
+fn merge_collinear() {
+	let mut lines = VectorOfKeyLine::new();
+	lines.push(KeyLine::from_points(Point2f::new(10., 20.), Point2f::new(50., 20.5)));
+	lines.push(KeyLine::from_points(Point2f::new(90., 21.), Point2f::new(52., 20.5)));
+	// parallel, but not on the same line
+	lines.push(KeyLine::from_points(Point2f::new(10., 40.), Point2f::new(50., 40.)));
+	let merged = line_descriptor::merge_collinear(&lines, 2., 3.);
+	assert_eq!(2, merged.len());
+	let line = merged.get(0).unwrap();
+	assert!((line.start_point_x - 10.).abs() < 0.1);
+	assert!((line.end_point_x - 90.).abs() < 0.1);
+	assert!((line.length() - 80.).abs() < 0.1);
+	assert!((line.line_length - line.length()).abs() < 1e-3);
+	assert!((line.pt.x - 50.).abs() < 0.1);
+	assert!(line.angle.abs() < 1f32.to_radians());
+	assert_eq!(lines.get(2).unwrap(), merged.get(1).unwrap());
+
+	// near-vertical halves with opposite directions, angles close to 90° and -90°
+	let mut lines = VectorOfKeyLine::new();
+	lines.push(KeyLine::from_points(Point2f::new(30., 0.), Point2f::new(30.1, 50.)));
+	lines.push(KeyLine::from_points(Point2f::new(29.9, 100.), Point2f::new(30.1, 51.)));
+	assert!(lines.get(0).unwrap().angle_degrees() > 89.);
+	assert!(lines.get(1).unwrap().angle_degrees() < -89.);
+	let merged = line_descriptor::merge_collinear(&lines, 1., 2.);
+	assert_eq!(1, merged.len());
+	assert!((merged.get(0).unwrap().length() - 100.).abs() < 0.5);
+
+	// gap too big
+	assert_eq!(2, line_descriptor::merge_collinear(&lines, 1., 0.5).len());
+}
+
+#[test]
+fn detect_with_filled_mask() -> Result<()> {
+	let mut img = Mat::new_rows_cols_with_default(200, 200, core::CV_8UC1, Scalar::all(0.))?;
+	imgproc::rectangle(&mut img, Rect::new(20, 20, 50, 50), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	imgproc::rectangle(&mut img, Rect::new(120, 120, 50, 50), Scalar::all(255.), 2, imgproc::LINE_8, 0)?;
+	let region = Rect::new(100, 100, 100, 100);
+	let mut mask = Mat::new_size_with_default(img.size()?, core::CV_8UC1, Scalar::all(0.))?;
+	Mat::roi(&mask, region)?.set_masked(Scalar::all(255.), None)?;
+	assert_eq!(region.area(), core::count_non_zero(&mask)?);
+
+	let mut bd = BinaryDescriptor::create_binary_descriptor()?;
+	let mut all = VectorOfKeyLine::new();
+	bd.detect_masked(&img, &mut all, None)?;
+	let mut masked = VectorOfKeyLine::new();
+	bd.detect_masked(&img, &mut masked, Some(&mask))?;
+	assert!(!masked.is_empty());
+	assert!(masked.len() < all.len());
+	let mut region_f = region.to::<f32>().unwrap();
+	region_f.width += 1.;
+	region_f.height += 1.;
+	for line in masked {
+		assert!(region_f.contains(Point2f::new(line.start_point_x, line.start_point_y)), "{} is outside of the mask", line);
+		assert!(region_f.contains(Point2f::new(line.end_point_x, line.end_point_y)), "{} is outside of the mask", line);
+	}
+
+	let small_mask = Mat::new_rows_cols_with_default(10, 10, core::CV_8UC1, Scalar::all(0.))?;
+	assert_matches!(mask.set_masked(Scalar::all(1.), Some(&small_mask)), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	let float_mask = Mat::new_size_with_default(img.size()?, core::CV_32FC1, Scalar::all(0.))?;
+	assert_matches!(mask.set_masked(Scalar::all(1.), Some(&float_mask)), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
@@ -1,9 +1,9 @@
-use std::ffi::c_void;
+use std::{convert::TryFrom, ffi::c_void};
 
 use matches::assert_matches;
 
 use opencv::{
-	core::{self, MatConstIterator, Point, Rect, Scalar, Size, Vec2b, Vec3d, Vec3f, Vec4w},
+	core::{self, Mat_, MatConstIterator, MatOrUser, MatView, Point, Range, Rect, Scalar, Size, Vec2b, Vec3b, Vec3d, Vec3f, Vec4w},
 	Error,
 	prelude::*,
 	Result,
@@ -298,6 +298,165 @@ fn mat_vec() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_row_col_roi() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(4, 32, u8::typ(), Scalar::all(0.))?;
+	for row in 0..4 {
+		for (i, x) in mat.at_row_mut::<u8>(row)?.iter_mut().enumerate() {
+			*x = (row * 32) as u8 + i as u8;
+		}
+	}
+	let roi = Mat::roi(&mat, Rect::new(8, 1, 16, 3))?;
+	assert!(!roi.is_continuous()?);
+	for row in 0..roi.rows() {
+		let data = roi.row_typed::<u8>(row)?;
+		assert_eq!(16, data.len());
+		assert_eq!(roi.at_row::<u8>(row)?, data);
+		for (col, &x) in data.iter().enumerate() {
+			assert_eq!(*roi.at_2d::<u8>(row, col as i32)?, x);
+		}
+	}
+	let mut roi_mut = Mat::roi(&mat, Rect::new(8, 1, 16, 3))?;
+	roi_mut.row_typed_mut::<u8>(2)?[1] = 0;
+	assert_eq!(0, *mat.at_2d::<u8>(3, 9)?);
+	assert_matches!(roi.row_typed::<u8>(3), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_eq!(vec![40, 72, 104], roi.col_typed::<u8>(0)?);
+	assert_eq!(vec![1, 33, 65, 97], mat.col_typed::<u8>(1)?);
+	assert_matches!(roi.col_typed::<u8>(16), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_matches!(roi.col_typed::<f32>(0), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	assert_eq!(Vec::<u8>::new(), Mat::default().col_typed::<u8>(0)?);
+	Ok(())
+}
+
+#[test]
+fn mat_view() -> Result<()> {
+	let data: Vec<u8> = (0..12).collect();
+	let view = MatView::from_slice(&data, 3, 4)?;
+	assert_eq!(3, view.rows());
+	assert_eq!(4, view.cols());
+	assert_eq!(u8::typ(), view.typ()?);
+	assert_eq!(data.as_ptr(), view.data_typed::<u8>()?.as_ptr());
+	assert_eq!(6, view.data_typed::<u8>()?[4 + 2]);
+
+	let owned = view.try_clone()?;
+	assert_ne!(data.as_ptr(), owned.data()? as *const u8);
+	assert_eq!(data.as_slice(), owned.data_typed::<u8>()?);
+
+	let view = MatView::from_bytes(&data, 2, 2, core::CV_8UC3)?;
+	assert_eq!(Vec3b::from([3, 4, 5]), view.data_typed::<Vec3b>()?[1]);
+
+	assert_matches!(MatView::from_slice(&data, 3, 5), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	assert_matches!(MatView::from_bytes(&data, 2, 3, core::CV_8UC3), Err(Error { code: core::StsUnmatchedSizes, .. }));
+
+	let floats = [1f32, 2., 3., 4., 5.];
+	let bytes = unsafe { std::slice::from_raw_parts(floats.as_ptr() as *const u8, floats.len() * 4) };
+	let view = MatView::from_bytes(&bytes[4..], 2, 2, core::CV_32FC1)?;
+	assert_eq!(&[2., 3., 4., 5.], view.data_typed::<f32>()?);
+	assert_matches!(MatView::from_bytes(&bytes[1..17], 2, 2, core::CV_32FC1), Err(Error { code: core::StsBadArg, .. }));
+	assert_matches!(MatView::from_bytes(&bytes[1..17], 2, 8, core::CV_8UC1).map(|_| ()), Ok(()));
+	Ok(())
+}
+
+#[test]
+fn mat_rows_iter() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(4, 5, i32::typ(), Scalar::all(0.))?;
+	let mut rows = mat.rows_iter_mut::<i32>()?;
+	assert_eq!(4, rows.len());
+	for (row_n, row) in rows.by_ref().enumerate() {
+		for (col_n, x) in row.iter_mut().enumerate() {
+			*x = row_n as i32 * 10 + col_n as i32;
+		}
+	}
+	assert_eq!(0, rows.len());
+	assert!(rows.next().is_none());
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 3, 2))?;
+	assert!(!roi.is_continuous()?);
+	let rows = roi.rows_iter::<i32>()?.collect::<Vec<_>>();
+	assert_eq!(vec![&[11, 12, 13][..], &[21, 22, 23][..]], rows);
+	let last = roi.rows_iter::<i32>()?.rev().next();
+	assert_eq!(Some(&[21, 22, 23][..]), last);
+
+	assert_matches!(mat.rows_iter::<f32>().map(|_| ()), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_for_each_row() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(5, 32, u8::typ(), Scalar::all(0.))?;
+	for (i, x) in mat.data_typed_mut::<u8>()?.iter_mut().enumerate() {
+		*x = i as u8;
+	}
+	let roi = Mat::roi(&mat, Rect::new(4, 1, 16, 4))?;
+	let mut visited = 0;
+	roi.for_each_row::<u8, Error>(|row_n, row| {
+		for (col_n, &x) in row.iter().enumerate() {
+			assert_eq!(*roi.at_2d::<u8>(row_n as i32, col_n as i32)?, x);
+		}
+		visited += 1;
+		Ok(())
+	}).map_err(|e| match e {
+		MatOrUser::Mat(e) | MatOrUser::User(e) => e,
+	})?;
+	assert_eq!(4, visited);
+
+	let res = roi.for_each_row::<u8, _>(|row_n, _| if row_n == 2 { Err(row_n) } else { Ok(()) });
+	assert_matches!(res, Err(MatOrUser::User(2)));
+	let res = roi.for_each_row::<f32, ()>(|_, _| Ok(()));
+	assert_matches!(res, Err(MatOrUser::Mat(Error { code: core::StsUnmatchedFormats, .. })));
+	Ok(())
+}
+
+#[test]
+fn mat_ops() -> Result<()> {
+	let a = Mat::from_slice_2d(&[[10f32, 20.], [30., 40.]])?;
+	let b = Mat::from_slice_2d(&[[1f32, 2.], [3., 4.]])?;
+
+	let mut expected = Mat::default();
+	core::subtract(&a, &b, &mut expected, &Mat::default(), -1)?;
+	assert_eq!(expected.to_vec_2d::<f32>()?, (&a - &b)?.to_mat()?.to_vec_2d::<f32>()?);
+
+	assert_eq!(vec![vec![11., 22.], vec![33., 44.]], (&a + &b)?.to_mat()?.to_vec_2d::<f32>()?);
+	assert_eq!(vec![vec![5., 10.], vec![15., 20.]], (&a / 2.)?.to_mat()?.to_vec_2d::<f32>()?);
+	assert_eq!(vec![vec![10., 10.], vec![10., 10.]], (&a / &b)?.to_mat()?.to_vec_2d::<f32>()?);
+	assert_eq!(vec![vec![70., 100.], vec![150., 220.]], (&a * &b)?.to_mat()?.to_vec_2d::<f32>()?);
+	assert_eq!(vec![vec![-10., -20.], vec![-30., -40.]], (-&a)?.to_mat()?.to_vec_2d::<f32>()?);
+	let expr = (&a * 2.)?;
+	assert_eq!(vec![vec![21., 42.], vec![63., 84.]], (&expr + &b)?.to_mat()?.to_vec_2d::<f32>()?);
+	assert_eq!(vec![vec![19., 38.], vec![57., 76.]], (&expr - Scalar::all(1.))?.to_mat()?.to_vec_2d::<f32>()?);
+
+	let m = Mat::from_slice(&[0u8, 1, 255])?;
+	assert_eq!(&[255, 254, 0], (!&m)?.to_mat()?.data_typed::<u8>()?);
+	let expr = (&m + Scalar::all(1.))?;
+	assert_eq!(&[254, 253, 0], (!&expr)?.to_mat()?.data_typed::<u8>()?);
+	Ok(())
+}
+
+#[test]
+fn mat_display() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[
+		[1f32, 2., 3.],
+		[4., 5.5, 6.],
+		[7., 8., -9.],
+	])?;
+	assert_eq!("[1, 2, 3;\n 4, 5.5, 6;\n 7, 8, -9]", mat.to_string());
+	assert_eq!("[]", Mat::default().to_string());
+
+	let mat = Mat::new_rows_cols_with_default(1, 2, Vec2b::typ(), Scalar::new(1., 2., 0., 0.))?;
+	assert_eq!("[1, 2, 1, 2]", mat.to_string());
+
+	let mat = Mat::new_rows_cols_with_default(20, 20, u8::typ(), Scalar::all(0.))?;
+	let out = mat.to_string();
+	assert_eq!(17, out.lines().count());
+	assert!(out.lines().next().unwrap().ends_with(", ..."));
+	assert!(out.ends_with("\n ...]"));
+
+	let debug = format!("{:?}", mat);
+	assert!(debug.contains("rows: 20"));
+	assert!(debug.contains("is_continuous: true"));
+	Ok(())
+}
+
 #[test]
 fn mat_continuous() -> Result<()> {
 	let s: Vec<Vec<f32>> = vec![
@@ -367,6 +526,27 @@ fn mat_continuous() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_data_typed() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(3, 32, u8::typ(), Scalar::all(0.))?;
+	{
+		let data = mat.data_typed_mut::<u8>()?;
+		assert_eq!(3 * 32, data.len());
+		data[32] = 0xFF;
+	}
+	let data = mat.data_typed::<u8>()?;
+	assert_eq!(3 * 32, data.len());
+	assert_eq!(0xFF, data[32]);
+	assert_eq!(0xFF, *mat.at_2d::<u8>(1, 0)?);
+	assert_matches!(mat.data_typed::<f32>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	assert_matches!(mat.data_typed_mut::<i32>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+
+	let mut empty = Mat::default();
+	assert!(empty.data_typed::<u8>()?.is_empty());
+	assert!(empty.data_typed_mut::<u8>()?.is_empty());
+	Ok(())
+}
+
 #[test]
 fn mat_operations() -> Result<()> {
 	let mut src = VectorOfMat::new();
@@ -513,6 +693,26 @@ fn mat_convert() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn mat_typed() -> Result<()> {
+	let mut mat = Mat_::<u8>::zeros(2, 3)?;
+	assert_eq!(Size::new(3, 2), mat.size()?);
+	assert_eq!(0, mat[(1, 2)]);
+	mat[(1, 2)] = 7;
+	assert_eq!(7, *mat.at_2d(1, 2)?);
+	assert_matches!(mat.at_2d(2, 0), Err(Error { code: core::StsOutOfRange, .. }));
+
+	let untyped: &Mat = &mat;
+	assert_eq!(7, *untyped.at_2d::<u8>(1, 2)?);
+
+	let mat = Mat_::from_slice(&[1u8, 2, 3])?;
+	assert_eq!(2, mat[(0, 1)]);
+
+	let float = Mat::from_slice(&[1f32, 2.])?;
+	assert_matches!(float.try_into_typed::<u8>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
 #[test]
 fn mat_mul() -> Result<()> {
 	{
@@ -543,3 +743,352 @@ fn mat_mul() -> Result<()> {
 	}
 	Ok(())
 }
+
+#[test]
+#[cfg(feature = "serde")]
+fn mat_serde() -> Result<()> {
+	let gray = Mat::from_slice_2d(&[[1u8, 2, 3], [4, 5, 6]])?;
+	let color = Mat::from_slice_2d(&[[Vec3f::from([1., 2., 3.]), Vec3f::from([4., 5., 6.])]])?;
+	let big = Mat::from_slice_2d(&[[1u8, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]])?;
+	let roi = Mat::roi(&big, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous()?);
+
+	for mat in &[gray, color, roi] {
+		let bin: Mat = bincode::deserialize(&bincode::serialize(mat).unwrap()).unwrap();
+		let json: Mat = serde_json::from_str(&serde_json::to_string(mat).unwrap()).unwrap();
+		for restored in &[bin, json] {
+			assert_eq!(mat.typ()?, restored.typ()?);
+			assert_eq!(mat.size()?, restored.size()?);
+			assert_eq!(0., core::norm2(mat, restored, core::NORM_INF, &Mat::default())?);
+		}
+	}
+
+	let err = serde_json::from_str::<Mat>(r#"{"rows":2,"cols":2,"typ":0,"data":[1,2,3]}"#);
+	assert!(err.is_err());
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn mat_ndarray() -> Result<()> {
+	let mat = Mat::from_slice_2d(&[[1f32, 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]])?;
+	let array = mat.try_as_array::<f32>()?;
+	assert_eq!((3, 4), array.dim());
+	assert_eq!(7., array[[1, 2]]);
+	assert_matches!(mat.try_as_array::<f64>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous()?);
+	let roi_array = roi.try_as_array::<f32>()?;
+	assert_eq!(ndarray::arr2(&[[6f32, 7.], [10., 11.]]), roi_array);
+
+	let color = Mat::from_slice_2d(&[[Vec3b::from([1, 2, 3]), Vec3b::from([4, 5, 6])]])?;
+	let color_array = color.try_as_array3::<u8>()?;
+	assert_eq!((1, 2, 3), color_array.dim());
+	assert_eq!(6, color_array[[0, 1, 2]]);
+	assert_matches!(color.try_as_array3::<f32>(), Err(Error { code: core::StsUnmatchedFormats, .. }));
+
+	let back = Mat::from_array(&roi_array)?;
+	assert_eq!(f32::typ(), back.typ()?);
+	assert_eq!(&[6f32, 7., 10., 11.], back.data_typed::<f32>()?);
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "image")]
+fn mat_image() -> Result<()> {
+	use image::{DynamicImage, GrayImage, Rgb, RgbImage, Rgba, RgbaImage};
+
+	// pure red in RGB order must become (0, 0, 255) in BGR order
+	let rgb = DynamicImage::ImageRgb8(RgbImage::from_pixel(3, 2, Rgb([255, 0, 0])));
+	let mat = Mat::from_image(&rgb)?;
+	assert_eq!(core::CV_8UC3, mat.typ()?);
+	assert_eq!(Size::new(3, 2), mat.size()?);
+	assert_eq!(Vec3b::from([0, 0, 255]), *mat.at_2d::<Vec3b>(1, 2)?);
+	assert_eq!(rgb, mat.to_image()?);
+
+	let rgba = DynamicImage::ImageRgba8(RgbaImage::from_pixel(2, 2, Rgba([10, 20, 30, 40])));
+	let mat = Mat::from_image(&rgba)?;
+	assert_eq!(core::CV_8UC4, mat.typ()?);
+	assert_eq!(&[30, 20, 10, 40], &mat.data_typed::<core::Vec4b>()?[0][..]);
+	assert_eq!(rgba, mat.to_image()?);
+
+	let gray = DynamicImage::ImageLuma8(GrayImage::from_raw(2, 2, vec![1, 2, 3, 4]).unwrap());
+	let mat = Mat::from_image(&gray)?;
+	assert_eq!(&[1, 2, 3, 4], mat.data_typed::<u8>()?);
+	let roi = Mat::roi(&mat, Rect::new(1, 0, 1, 2))?;
+	assert_eq!(DynamicImage::ImageLuma8(GrayImage::from_raw(1, 2, vec![2, 4]).unwrap()), roi.to_image()?);
+
+	let empty = DynamicImage::ImageRgb8(RgbImage::new(0, 3));
+	let mat = Mat::from_image(&empty)?;
+	assert_eq!(0, mat.cols());
+	let empty = Mat::default().to_image()?;
+	assert_eq!((0, 0), (empty.width(), empty.height()));
+
+	let float = Mat::from_slice(&[1f32])?;
+	assert_matches!(float.to_image(), Err(Error { code: core::StsUnsupportedFormat, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_ranges() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(5, 4, u8::typ(), Scalar::all(0.))?;
+	{
+		let mut rows = mat.row_range(&Range::try_from(1..3)?)?;
+		assert_eq!(Size::new(4, 2), rows.size()?);
+		rows.set_to(&Scalar::all(7.), &core::no_array()?)?;
+	}
+	assert_eq!(0, *mat.at_2d::<u8>(0, 0)?);
+	assert_eq!(7, *mat.at_2d::<u8>(1, 3)?);
+	assert_eq!(7, *mat.at_2d::<u8>(2, 0)?);
+	assert_eq!(0, *mat.at_2d::<u8>(3, 0)?);
+
+	{
+		let mut view = Mat::rowscols(&mat, &Range::all()?, &Range::try_from(3..4)?)?;
+		assert_eq!(Size::new(1, 5), view.size()?);
+		*view.at_2d_mut::<u8>(4, 0)? = 9;
+	}
+	assert_eq!(9, *mat.at_2d::<u8>(4, 3)?);
+	assert_eq!(Size::new(2, 5), mat.col_bounds(1, 3)?.size()?);
+
+	assert_eq!(1..3, Range::try_from(1..3)?.to_std());
+	assert_eq!(i32::MIN..i32::MAX, Range::all()?.to_std());
+	assert_matches!(mat.row_bounds(3, 6), Err(Error { code: core::StsAssert, .. }));
+	assert_matches!(mat.col_range(&Range::try_from(-1..2)?), Err(Error { code: core::StsAssert, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_split_merge() -> Result<()> {
+	let mut src = Mat::new_rows_cols_with_default(3, 4, Vec3b::typ(), Scalar::default())?;
+	for (i, px) in src.data_typed_mut::<Vec3b>()?.iter_mut().enumerate() {
+		*px = Vec3b::from([i as u8, 100 + i as u8, 200 + i as u8]);
+	}
+	let planes = core::split_to_vec(&src)?;
+	assert_eq!(3, planes.len());
+	for plane in &planes {
+		assert_eq!(u8::typ(), plane.typ()?);
+		assert_eq!(src.size()?, plane.size()?);
+	}
+	assert_eq!(105, *planes[1].at_2d::<u8>(1, 1)?);
+
+	let merged = core::merge_from_slice(&planes)?;
+	assert_eq!(src.typ()?, merged.typ()?);
+	assert_eq!(src.data_typed::<Vec3b>()?, merged.data_typed::<Vec3b>()?);
+
+	let small = Mat::new_rows_cols_with_default(2, 4, u8::typ(), Scalar::default())?;
+	let res = core::merge_from_slice(&[Mat::copy(&planes[0])?, Mat::copy(&planes[1])?, small]);
+	assert_matches!(res, Err(Error { code: core::StsUnmatchedSizes, ref message }) if message.contains("index 2"));
+	let float = Mat::new_rows_cols_with_default(3, 4, f32::typ(), Scalar::default())?;
+	let res = core::merge_from_slice(&[Mat::copy(&planes[0])?, float]);
+	assert_matches!(res, Err(Error { code: core::StsUnmatchedFormats, ref message }) if message.contains("index 1"));
+	assert_matches!(core::merge_from_slice(&[]), Err(Error { code: core::StsBadArg, .. }));
+	Ok(())
+}
+
+#[test]
+fn mix_channels_checked() -> Result<()> {
+	let mut bgr = Mat::new_rows_cols_with_default(2, 3, Vec3b::typ(), Scalar::default())?;
+	for (i, px) in bgr.data_typed_mut::<Vec3b>()?.iter_mut().enumerate() {
+		*px = Vec3b::from([i as u8, 100 + i as u8, 200 + i as u8]);
+	}
+	let mut rgb = [Mat::new_rows_cols_with_default(2, 3, Vec3b::typ(), Scalar::default())?];
+	core::mix_channels_checked(&[Mat::copy(&bgr)?], &mut rgb, &[(0, 2), (1, 1), (2, 0)])?;
+	for (src, dst) in bgr.data_typed::<Vec3b>()?.iter().zip(rgb[0].data_typed::<Vec3b>()?) {
+		assert_eq!([src[2], src[1], src[0]], **dst);
+	}
+
+	// split off the red channel and zero the blue one
+	let mut dst = [
+		Mat::new_rows_cols_with_default(2, 3, u8::typ(), Scalar::all(7.))?,
+		Mat::new_rows_cols_with_default(2, 3, u8::typ(), Scalar::all(7.))?,
+	];
+	core::mix_channels_checked(&[Mat::copy(&bgr)?], &mut dst, &[(2, 0), (-1, 1)])?;
+	assert_eq!(204, *dst[0].at_2d::<u8>(1, 1)?);
+	assert!(dst[1].data_typed::<u8>()?.iter().all(|&v| v == 0));
+
+	let src = [Mat::copy(&bgr)?];
+	let res = core::mix_channels_checked(&src, &mut rgb, &[(0, 0), (3, 1)]);
+	assert_matches!(res, Err(Error { code: core::StsOutOfRange, ref message }) if message.contains("(3, 1)"));
+	let res = core::mix_channels_checked(&src, &mut rgb, &[(0, 3)]);
+	assert_matches!(res, Err(Error { code: core::StsOutOfRange, ref message }) if message.contains("(0, 3)"));
+	let res = core::mix_channels_checked(&src, &mut rgb, &[(-2, 0)]);
+	assert_matches!(res, Err(Error { code: core::StsOutOfRange, ref message }) if message.contains("(-2, 0)"));
+	let mut small = [Mat::new_rows_cols_with_default(1, 3, Vec3b::typ(), Scalar::default())?];
+	assert_matches!(core::mix_channels_checked(&src, &mut small, &[(0, 0)]), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	let mut unallocated = [Mat::default()];
+	assert_matches!(core::mix_channels_checked(&src, &mut unallocated, &[(0, 0)]), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	assert_matches!(core::mix_channels_checked(&[], &mut rgb, &[(0, 0)]), Err(Error { code: core::StsBadArg, .. }));
+	Ok(())
+}
+
+#[test]
+fn lut() -> Result<()> {
+	let mut src = Mat::new_rows_cols_with_default(2, 3, Vec3b::typ(), Scalar::default())?;
+	for (i, px) in src.data_typed_mut::<Vec3b>()?.iter_mut().enumerate() {
+		*px = Vec3b::from([i as u8, 100 + i as u8, 250 + i as u8]);
+	}
+	let mut identity = [0u8; 256];
+	identity.iter_mut().enumerate().for_each(|(i, v)| *v = i as u8);
+	let mut inversion = identity;
+	inversion.reverse();
+
+	let mut dst = Mat::default();
+	core::lut_u8(&src, &identity, &mut dst)?;
+	assert_eq!(src.typ()?, dst.typ()?);
+	assert_eq!(src.data_typed::<Vec3b>()?, dst.data_typed::<Vec3b>()?);
+	core::lut_u8(&src, &inversion, &mut dst)?;
+	for (s, d) in src.data_typed::<Vec3b>()?.iter().zip(dst.data_typed::<Vec3b>()?) {
+		assert_eq!([255 - s[0], 255 - s[1], 255 - s[2]], **d);
+	}
+
+	// per-channel table: identity for blue, inversion for green, constant for red
+	let table = identity.iter().zip(&inversion)
+		.map(|(&i, &inv)| Vec3b::from([i, inv, 42]))
+		.collect::<Vec<_>>();
+	let table = Mat::from_slice(&table)?;
+	core::lut_checked(&src, &table, &mut dst)?;
+	assert_eq!(Vec3b::from([1, 154, 42]), *dst.at_2d::<Vec3b>(0, 1)?);
+
+	let float = Mat::new_rows_cols_with_default(2, 3, f32::typ(), Scalar::default())?;
+	assert_matches!(core::lut_u8(&float, &identity, &mut dst), Err(Error { code: core::StsUnsupportedFormat, .. }));
+	let short = Mat::from_slice(&identity[..128])?;
+	assert_matches!(core::lut_checked(&src, &short, &mut dst), Err(Error { code: core::StsBadSize, .. }));
+	let two_channels = Mat::new_rows_cols_with_default(1, 256, core::Vec2b::typ(), Scalar::default())?;
+	assert_matches!(core::lut_checked(&src, &two_channels, &mut dst), Err(Error { code: core::BadNumChannels, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_iter_2d() -> Result<()> {
+	let mut mat = Mat::new_rows_cols_with_default(4, 5, i32::typ(), Scalar::default())?;
+	for ((row, col), val) in mat.iter_2d_mut::<i32>()? {
+		*val = (row * 10 + col) as i32;
+	}
+	let iter = mat.iter_2d::<i32>()?;
+	assert_eq!(20, iter.len());
+	let items = iter.collect::<Vec<_>>();
+	assert_eq!(((0, 0), &0), items[0]);
+	assert_eq!(((1, 0), &10), items[5]);
+	assert_eq!(((3, 4), &34), items[19]);
+	for ((row, col), val) in &items {
+		assert_eq!(*mat.at_2d::<i32>(*row as i32, *col as i32)?, **val);
+	}
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 3, 2))?;
+	assert!(!roi.is_continuous()?);
+	let mut iter = roi.iter_2d::<i32>()?;
+	assert_eq!(6, iter.len());
+	assert_eq!(Some(((0, 0), &11)), iter.next());
+	assert_eq!(5, iter.len());
+	assert_eq!(vec![12, 13, 21, 22, 23], iter.map(|(_, v)| *v).collect::<Vec<_>>());
+
+	let empty = Mat::new_rows_cols_with_default(3, 0, i32::typ(), Scalar::default())?;
+	assert_eq!(0, empty.iter_2d::<i32>()?.len());
+	assert_eq!(None, empty.iter_2d::<i32>()?.next());
+	assert_matches!(mat.iter_2d::<u8>().map(|_| ()), Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
+#[test]
+fn mat_from_rows() -> Result<()> {
+	let arr = [[0u8, 1, 2], [3, 4, 5]];
+	let mat = Mat::from_slice_2d(&arr)?;
+	assert_eq!(Size::new(3, 2), mat.size()?);
+	assert_eq!(5, *mat.at_2d::<u8>(1, 2)?);
+	assert_eq!(1, *mat.at_2d::<u8>(0, 1)?);
+
+	let rows: [&[i32]; 3] = [&[1, 2], &[3, 4], &[5, 6]];
+	let mat = Mat::from_slice_2d(&rows)?;
+	assert_eq!(Size::new(2, 3), mat.size()?);
+	assert_eq!(i32::typ(), mat.typ()?);
+	assert_eq!(4, *mat.at_2d::<i32>(1, 1)?);
+
+	let ragged: [&[i32]; 2] = [&[1, 2, 3], &[4, 5]];
+	assert_matches!(
+		Mat::from_slice_2d(&ragged),
+		Err(Error { code: core::StsUnmatchedSizes, ref message }) if message == "Unexpected number of items: 2 in a row index: 1, expected: 3"
+	);
+
+	let mat = opencv::mat![[1.5f32, 2.5], [3.5, 4.5], [5.5, 6.5]]?;
+	assert_eq!(Size::new(2, 3), mat.size()?);
+	assert_eq!(f32::typ(), mat.typ()?);
+	assert_eq!(4.5, *mat.at_2d::<f32>(1, 1)?);
+	assert_matches!(opencv::mat![[1, 2], [3]], Err(Error { code: core::StsUnmatchedSizes, .. }));
+
+	let rows = vec![vec![7u16; 2]; 2];
+	let mat = Mat::from_slice_2d(&rows)?;
+	drop(rows);
+	assert_eq!(7, *mat.at_2d::<u16>(1, 1)?);
+	Ok(())
+}
+
+#[test]
+fn mat_deep_copy() -> Result<()> {
+	let src = opencv::mat![[1u8, 2, 3], [4, 5, 6]]?;
+
+	let mut cloned = src.try_clone()?;
+	*cloned.at_2d_mut::<u8>(0, 0)? = 100;
+	assert_eq!(1, *src.at_2d::<u8>(0, 0)?);
+	let mut cloned = src.clone();
+	*cloned.at_2d_mut::<u8>(0, 1)? = 100;
+	assert_eq!(2, *src.at_2d::<u8>(0, 1)?);
+
+	let mut copied = Mat::default();
+	src.copy_to(&mut copied)?;
+	*copied.at_2d_mut::<u8>(1, 0)? = 100;
+	assert_eq!(4, *src.at_2d::<u8>(1, 0)?);
+
+	let mask = opencv::mat![[255u8, 0, 255], [0, 255, 0]]?;
+	let mut masked = Mat::new_rows_cols_with_default(2, 3, u8::typ(), Scalar::all(9.))?;
+	src.copy_to_masked(&mut masked, &mask)?;
+	assert_eq!(&[1, 9, 3, 9, 5, 9], masked.data_typed::<u8>()?);
+
+	let mut shallow = Mat::copy(&src)?;
+	*shallow.at_2d_mut::<u8>(1, 2)? = 100;
+	assert_eq!(100, *src.at_2d::<u8>(1, 2)?);
+	Ok(())
+}
+
+#[test]
+fn mat_layout() -> Result<()> {
+	let mat = Mat::new_rows_cols_with_default(4, 6, Vec3f::typ(), Scalar::default())?;
+	assert_eq!(12, mat.elem_size()?);
+	assert_eq!(4, mat.elem_size1()?);
+	assert_eq!(24, mat.total()?);
+	assert!(mat.is_continuous()?);
+	assert!(!mat.is_submatrix()?);
+	assert_eq!(vec![6 * 12, 12], mat.steps()?);
+	assert_eq!(6 * 3, mat.step1(0)?);
+
+	let roi = Mat::roi(&mat, Rect::new(1, 1, 2, 2))?;
+	assert!(!roi.is_continuous()?);
+	assert!(roi.is_submatrix()?);
+	assert_eq!(4, roi.total()?);
+	let steps = roi.steps()?;
+	assert_eq!(6 * 12, steps[0]);
+	assert_ne!(roi.cols() as usize * roi.elem_size()?, steps[0]);
+
+	let nd = Mat::new_nd_with_default(&[2, 3, 4], u16::typ(), Scalar::default())?;
+	assert_eq!(vec![3 * 4 * 2, 4 * 2, 2], nd.steps()?);
+	assert!(Mat::default().steps()?.is_empty());
+	Ok(())
+}
+
+#[test]
+fn mat_convert_to_depth() -> Result<()> {
+	let src = opencv::mat![[0.5f32, 1.5, -1.], [0.25, 2., 300.]]?;
+	let mut dst = Mat::default();
+	src.convert_to_depth(&mut dst, core::Depth::U8, 100., 10.)?;
+	assert_eq!(u8::typ(), dst.typ()?);
+	assert_eq!(&[60, 160, 0, 35, 210, 255], dst.data_typed::<u8>()?);
+
+	src.convert_to_depth_def(&mut dst, core::Depth::S16)?;
+	assert_eq!(i16::typ(), dst.typ()?);
+	assert_eq!(&[0, 2, -1, 0, 2, 300], dst.data_typed::<i16>()?);
+
+	assert_eq!(core::CV_64F, i32::from(core::Depth::F64));
+	assert_eq!(core::Depth::U16, core::Depth::try_from(core::CV_16UC3)?);
+	assert_matches!(core::Depth::try_from(7), Err(Error { code: core::StsUnsupportedFormat, .. }));
+	Ok(())
+}
@@ -1,7 +1,10 @@
+use std::convert::TryFrom;
+
 use matches::assert_matches;
 
 use opencv::{
-	core::{self, Matx22d, Matx23f, Matx32f, Matx33d, Matx66f, Point2f, Scalar},
+	core::{self, Matx22d, Matx23f, Matx32f, Matx33d, Matx33f, Matx34d, Matx66f, Point2f, Scalar, Vec3d, Vec4d},
+	Error,
 	imgproc,
 	prelude::*,
 	Result,
@@ -101,3 +104,74 @@ fn matx_all() -> Result<()> {
 	assert_eq!(mat[(3, 4)], 81.);
 	Ok(())
 }
+
+#[test]
+fn matx_mul() -> Result<()> {
+	let k = Matx33d::from([
+		2., 0., 1.,
+		0., 3., 2.,
+		0., 0., 1.,
+	]);
+	assert_eq!(Vec3d::from([5., 11., 1.]), k * Vec3d::from([2., 3., 1.]));
+	assert_eq!(k, k * Matx33d::eye());
+	assert_eq!(Vec3d::from([2., 3., 1.]), Matx33d::eye() * Vec3d::from([2., 3., 1.]));
+	let rt = Matx34d::from([
+		1., 0., 0., 1.,
+		0., 1., 0., 2.,
+		0., 0., 1., 3.,
+	]);
+	let p = k * rt;
+	assert_eq!(Vec3d::from([6., 14., 4.]), p * Vec4d::from([0., 0., 1., 1.]));
+	Ok(())
+}
+
+#[test]
+fn matx_mat_conversion() -> Result<()> {
+	let k = Matx33d::from([1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+	let mat = k.to_mat()?;
+	assert_eq!(3, mat.rows());
+	assert_eq!(3, mat.cols());
+	assert_eq!(core::CV_64FC1, mat.typ()?);
+	assert_eq!(6., *mat.at_2d::<f64>(1, 2)?);
+	assert_eq!(k, Matx33d::try_from(&mat)?);
+	let res = Matx22d::try_from(&mat);
+	assert_matches!(res, Err(Error { code: core::StsUnmatchedSizes, .. }));
+	let res = Matx33f::try_from(&mat);
+	assert_matches!(res, Err(Error { code: core::StsUnmatchedFormats, .. }));
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "nalgebra")]
+fn matx_nalgebra() -> Result<()> {
+	use nalgebra::{Matrix3, Matrix4, Point2, Point3, Vector3};
+	use opencv::core::{Matx44d, Point3d, Vec3d};
+
+	let pt = Point2f::new(1.5, -2.);
+	let na_pt: Point2<f32> = pt.into();
+	assert_eq!(Point2::new(1.5, -2.), na_pt);
+	assert_eq!(pt, Point2f::from(na_pt));
+
+	let pt3 = Point3d::new(1., 2., 3.);
+	assert_eq!(pt3, Point3d::from(Point3::from(pt3)));
+	let v = Vec3d::from([4., 5., 6.]);
+	assert_eq!(Vector3::new(4., 5., 6.), Vector3::from(v));
+	assert_eq!(v, Vec3d::from(Vector3::from(v)));
+
+	let m = Matx33d::from([1., 2., 3., 4., 5., 6., 7., 8., 9.]);
+	let na_m = Matrix3::from(m);
+	assert_eq!(2., na_m[(0, 1)]);
+	assert_eq!(4., na_m[(1, 0)]);
+	let back: Matx33d = na_m.into();
+	assert_eq!(m.val, back.val);
+	let m4 = Matx44d::eye();
+	assert_eq!(Matrix4::identity(), Matrix4::from(m4));
+
+	let homography = Mat::from_slice_2d(&[[1f64, 0., 10.], [0., 2., 20.], [0., 0., 1.]])?;
+	let h = homography.try_to_matrix3::<f64>()?;
+	assert_eq!(10., h[(0, 2)]);
+	assert_eq!(2., h[(1, 1)]);
+	assert_matches!(homography.try_to_matrix3::<f32>(), Err(opencv::Error { code: core::StsUnmatchedFormats, .. }));
+	assert_matches!(Mat::from_slice(&[1f64; 3])?.try_to_matrix3::<f64>(), Err(opencv::Error { code: core::StsUnmatchedSizes, .. }));
+	Ok(())
+}
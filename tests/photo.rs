@@ -0,0 +1,56 @@
+#![cfg(ocvrs_has_module_photo)]
+
+use matches::assert_matches;
+
+use opencv::{
+	core::{self, Point, Rect, Scalar, Vec3b},
+	Error,
+	photo,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn seamless_clone_checked() -> Result<()> {
+	let src = Mat::new_rows_cols_with_default(20, 20, core::CV_8UC3, Scalar::all(230.))?;
+	let dst = Mat::new_rows_cols_with_default(100, 100, core::CV_8UC3, Scalar::all(20.))?;
+	let mask = Mat::new_rows_cols_with_default(20, 20, core::CV_8UC1, Scalar::all(0.))?;
+	Mat::roi(&mask, Rect::new(2, 2, 16, 16))?.set_to(&Scalar::all(255.), &core::no_array()?)?;
+
+	let mut blend = Mat::default();
+	photo::seamless_clone_checked(&src, &dst, &mask, Point::new(50, 50), &mut blend, photo::NORMAL_CLONE)?;
+	assert_eq!(dst.size()?, blend.size()?);
+	assert_ne!(*dst.at_2d::<Vec3b>(50, 50)?, *blend.at_2d::<Vec3b>(50, 50)?);
+	for &(row, col) in &[(0, 0), (0, 99), (99, 0), (99, 99)] {
+		assert_eq!(*dst.at_2d::<Vec3b>(row, col)?, *blend.at_2d::<Vec3b>(row, col)?);
+	}
+
+	assert_matches!(
+		photo::seamless_clone_checked(&src, &dst, &mask, Point::new(3, 50), &mut blend, photo::NORMAL_CLONE),
+		Err(Error { code: core::StsOutOfRange, .. })
+	);
+	assert_matches!(
+		photo::seamless_clone_checked(&src, &dst, &mask, Point::new(50, 50), &mut blend, 42),
+		Err(Error { code: core::StsBadFlag, .. })
+	);
+	let small_mask = Mat::new_rows_cols_with_default(10, 10, core::CV_8UC1, Scalar::all(255.))?;
+	assert_matches!(
+		photo::seamless_clone_checked(&src, &dst, &small_mask, Point::new(50, 50), &mut blend, photo::NORMAL_CLONE),
+		Err(Error { code: core::StsUnmatchedSizes, .. })
+	);
+	let empty_mask = Mat::new_rows_cols_with_default(20, 20, core::CV_8UC1, Scalar::all(0.))?;
+	assert_matches!(
+		photo::seamless_clone_checked(&src, &dst, &empty_mask, Point::new(50, 50), &mut blend, photo::NORMAL_CLONE),
+		Err(Error { code: core::StsBadArg, .. })
+	);
+	let gray = Mat::new_rows_cols_with_default(20, 20, core::CV_8UC1, Scalar::all(230.))?;
+	assert_matches!(
+		photo::seamless_clone_checked(&gray, &dst, &mask, Point::new(50, 50), &mut blend, photo::NORMAL_CLONE),
+		Err(Error { code: core::StsUnsupportedFormat, .. })
+	);
+
+	let mut changed = Mat::default();
+	photo::illumination_change_checked(&src, &mask, &mut changed, 0.2, 0.4)?;
+	assert_eq!(src.size()?, changed.size()?);
+	Ok(())
+}
@@ -72,6 +72,15 @@ fn point_div() {
     }
 }
 
+#[test]
+fn point_neg() {
+    assert_eq!(Point::new(-1, 2), -Point::new(1, -2));
+    assert_eq!(Point2f::new(-1.5, 0.), -Point2f::new(1.5, 0.));
+    let (a, b) = (Point2f::new(1., 2.), Point2f::new(3., 6.));
+    assert_eq!(Point2f::new(2., 4.), (a + b) * 0.5);
+    assert_eq!(b - a, b + -a);
+}
+
 #[test]
 fn point_constructor() {
     let r = Point2l::from_size(Size2l::new(1, 2));
@@ -72,6 +72,12 @@ fn point3_div() {
     }
 }
 
+#[test]
+fn point3_neg() {
+    assert_eq!(Point3i::new(-1, 2, -3), -Point3i::new(1, -2, 3));
+    assert_eq!(Point3d::new(-0.5, 0., 1.), -Point3d::new(0.5, 0., -1.));
+}
+
 #[test]
 fn point3_constructor() {
     let r = Point3d::from_point(Point2d::new(10., 20.));
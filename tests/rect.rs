@@ -104,6 +104,25 @@ fn rect_union() {
     }
 }
 
+#[test]
+fn rect_intersect_union_methods() {
+    let a = Rect::new(0, 0, 10, 10);
+    assert_eq!(Some(Rect::new(5, 5, 5, 5)), a.intersect(&Rect::new(5, 5, 10, 10)));
+    assert_eq!(None, a.intersect(&Rect::new(20, 20, 10, 10)));
+    // touching edges
+    assert_eq!(None, a.intersect(&Rect::new(10, 0, 10, 10)));
+    assert_eq!(None, a.intersect(&Rect::new(0, 10, 10, 10)));
+    assert_eq!(None, a.intersect(&Rect::new(0, 0, 0, 0)));
+    assert_eq!(Some(Rect2f::new(9.5, 0., 0.5, 10.)), Rect2f::new(0., 0., 10., 10.).intersect(&Rect2f::new(9.5, 0., 10., 10.)));
+
+    assert_eq!(Rect::new(0, 0, 20, 10), a.union(&Rect::new(10, 0, 10, 10)));
+    assert_eq!(Rect::new(0, 0, 30, 30), a.union(&Rect::new(20, 20, 10, 10)));
+    assert_eq!(a, a.union(&Rect::default()));
+    assert_eq!(a, Rect::default().union(&a));
+    assert!(!a.contains(a.br()));
+    assert!(a.contains(a.tl()));
+}
+
 #[test]
 fn rect_constructor() {
     let r = Rect2i::from_point_size(Point2i::new(1, 2), Size2i::new(3,4));
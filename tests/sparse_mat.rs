@@ -0,0 +1,43 @@
+use matches::assert_matches;
+
+use opencv::{
+	core::{self, SparseMat},
+	Error,
+	prelude::*,
+	Result,
+};
+
+#[test]
+fn sparse_mat() -> Result<()> {
+	let mut mat = SparseMat::new_sizes(&[3, 4], f32::typ())?;
+	assert_eq!(2, mat.dims()?);
+	assert_eq!(0, mat.nzcount()?);
+	*mat.ref_value::<f32>(&[0, 1])? = 1.5;
+	*mat.ref_value::<f32>(&[2, 3])? = -4.;
+	assert_eq!(2, mat.nzcount()?);
+	assert_eq!(1.5, mat.value::<f32>(&[0, 1])?);
+	assert_eq!(0., mat.value::<f32>(&[1, 1])?);
+	assert_eq!(None, mat.find::<f32>(&[1, 1])?);
+
+	assert_matches!(mat.value::<f32>(&[3, 0]), Err(Error { code: core::StsOutOfRange, .. }));
+	assert_matches!(mat.value::<f32>(&[0]), Err(Error { code: core::StsUnmatchedSizes, .. }));
+	assert_matches!(mat.value::<u8>(&[0, 1]), Err(Error { code: core::StsUnmatchedFormats, .. }));
+
+	let mut nodes = mat.nodes()?.collect::<Vec<_>>();
+	nodes.sort_by(|a, b| a.0.cmp(&b.0));
+	assert_eq!(vec![(vec![0, 1], 1.5), (vec![2, 3], -4.)], nodes);
+
+	let mut mat_f64 = SparseMat::new_sizes(&[2, 2, 2], f64::typ())?;
+	*mat_f64.ref_value::<f64>(&[1, 0, 1])? = 1e300;
+	assert_eq!(vec![(vec![1, 0, 1], 1e300)], mat_f64.nodes()?.collect::<Vec<_>>());
+	let mat_f16 = SparseMat::new_sizes(&[3, 4], core::CV_16F)?;
+	assert_matches!(mat_f16.nodes().err(), Some(Error { code: core::StsUnsupportedFormat, .. }));
+
+	let mut dense = Mat::default();
+	mat.copy_to_mat(&mut dense)?;
+	assert_eq!(core::Size::new(4, 3), dense.size()?);
+	assert_eq!(1.5, *dense.at_2d::<f32>(0, 1)?);
+	assert_eq!(-4., *dense.at_2d::<f32>(2, 3)?);
+	assert_eq!(0., *dense.at_2d::<f32>(1, 1)?);
+	Ok(())
+}
@@ -126,3 +126,30 @@ fn umat_continuous() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(ocvrs_has_module_imgproc)]
+fn umat_imgproc() -> Result<()> {
+    use opencv::{core, imgproc};
+
+    let mut mat = Mat::new_rows_cols_with_default(64, 64, core::CV_8UC3, core::Scalar::all(0.))?;
+    imgproc::rectangle(&mut mat, Rect::new(10, 10, 30, 20), core::Scalar::new(255., 128., 0., 0.), -1, imgproc::LINE_8, 0)?;
+
+    let mut gray = Mat::default();
+    imgproc::cvt_color(&mat, &mut gray, imgproc::COLOR_BGR2GRAY, 0)?;
+    let mut blurred = Mat::default();
+    imgproc::gaussian_blur(&gray, &mut blurred, Size::new(5, 5), 1.5, 0., core::BORDER_DEFAULT)?;
+
+    let umat = mat.get_umat(ACCESS_READ, UMatUsageFlags::USAGE_DEFAULT)?;
+    let mut gray_umat = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+    imgproc::cvt_color(&umat, &mut gray_umat, imgproc::COLOR_BGR2GRAY, 0)?;
+    let mut blurred_umat = UMat::new(UMatUsageFlags::USAGE_DEFAULT);
+    imgproc::gaussian_blur(&gray_umat, &mut blurred_umat, Size::new(5, 5), 1.5, 0., core::BORDER_DEFAULT)?;
+
+    let blurred_from_umat = blurred_umat.get_mat(ACCESS_READ)?;
+    assert_eq!(blurred.size()?, blurred_from_umat.size()?);
+    assert_eq!(blurred.typ()?, blurred_from_umat.typ()?);
+    // OpenCL kernels are allowed to round differently
+    assert!(core::norm2(&blurred, &blurred_from_umat, core::NORM_INF, &Mat::default())? <= 1.);
+    Ok(())
+}
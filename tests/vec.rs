@@ -1,4 +1,4 @@
-use opencv::core::Vec3b;
+use opencv::core::{Scalar, Vec3b};
 
 #[test]
 fn vec() {
@@ -14,3 +14,24 @@ fn vec() {
 fn vec_deref() {
     assert_eq!(vec![10, 20, 30], Vec3b::from([10, 20, 30]).to_vec());
 }
+
+#[test]
+fn scalar_ops() {
+    let a = Scalar::from((1., 2., 3.));
+    assert_eq!(Scalar::new(1., 2., 3., 0.), a);
+    assert_eq!(Scalar::new(1., 2., 3., 4.), Scalar::from([1., 2., 3., 4.]));
+    assert_eq!(Scalar::new(5., 0., 0., 0.), Scalar::from(5.));
+    assert_eq!(Scalar::new(2., 3., 4., 1.), a + Scalar::all(1.));
+    assert_eq!(Scalar::new(0., 1., 2., -1.), a - Scalar::all(1.));
+    assert_eq!(Scalar::new(2., 4., 6., 0.), a * 2.);
+
+    let mut b = a;
+    b += a;
+    b *= 0.5;
+    b -= Scalar::from(1.);
+    assert_eq!(Scalar::new(0., 2., 3., 0.), b);
+
+    assert_eq!(Scalar::new(255., 0., 0., 0.), Scalar::blue());
+    assert_eq!(Scalar::red(), Scalar::from((0., 0., 255.)));
+    assert_eq!(Scalar::white(), Scalar::red() + Scalar::green() + Scalar::blue());
+}
@@ -422,6 +422,101 @@ fn iter() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn iterator_laws() -> Result<()> {
+	{
+		let vec = VectorOfDMatch::new();
+		let mut vec_iter = vec.iter();
+		assert_eq!((0, Some(0)), vec_iter.size_hint());
+		assert!(vec_iter.next().is_none());
+		assert!(vec_iter.next().is_none());
+		assert_eq!((0, Some(0)), vec_iter.size_hint());
+		assert_eq!(0, vec.into_iter().count());
+	}
+
+	{
+		let vec = VectorOfi32::from_iter(vec![1, 2, 3, 4, 5]);
+		let mut vec_iter = vec.iter();
+		assert_eq!(Some(1), vec_iter.next());
+		assert_eq!(Some(3), vec_iter.nth(1));
+		assert_eq!((2, Some(2)), vec_iter.size_hint());
+		assert_eq!(None, vec_iter.nth(5));
+		assert_eq!((0, Some(0)), vec_iter.size_hint());
+		assert_eq!(None, vec_iter.next());
+		assert_eq!((0, Some(0)), vec_iter.size_hint());
+
+		let mut vec_iter = vec.into_iter();
+		assert_eq!(Some(2), vec_iter.nth(1));
+		assert_eq!(Some(3), vec_iter.next());
+		assert_eq!(vec![4, 5], vec_iter.by_ref().collect::<Vec<_>>());
+		assert_eq!(None, vec_iter.next());
+		assert_eq!(0, vec_iter.len());
+	}
+
+	{
+		let matches = (0..10).map(|i| DMatch::new(i, i * 2, i as f32).unwrap());
+		let mut vec = VectorOfDMatch::from_iter(matches);
+		assert_eq!(10, vec.iter().len());
+		vec.extend(vec.clone().into_iter().filter(|m| m.distance < 2.));
+		assert_eq!(12, vec.len());
+		let close = vec.iter().filter(|m| m.distance < 5.).collect::<VectorOfDMatch>();
+		assert_eq!(7, close.len());
+		assert_eq!(vec![0, 1, 2, 3, 4, 0, 1], close.iter().map(|m| m.query_idx).collect::<Vec<_>>());
+	}
+
+	Ok(())
+}
+
+#[test]
+fn index() -> Result<()> {
+	let mut vec = VectorOfDMatch::from_iter(vec![DMatch::new(0, 5, 3.)?, DMatch::new(1, 2, 1.)?]);
+	assert_eq!(5, vec[0].train_idx);
+	assert_eq!(1., vec[1].distance);
+	vec[1].distance = 0.5;
+	assert_eq!(0.5, vec.get(1)?.distance);
+	vec.get_mut(0)?.img_idx = 4;
+	assert_eq!(4, vec.get(0)?.img_idx);
+	assert_matches!(vec.get_mut(2), Err(Error { code: core::StsOutOfRange, .. }));
+	let best = vec.iter().fold(f32::INFINITY, |best, m| best.min(m.distance));
+	assert_eq!(best, vec[1].distance);
+	Ok(())
+}
+
+#[test]
+#[should_panic]
+fn index_out_of_bounds() {
+	let vec = VectorOfi32::from_iter(vec![1, 2, 3]);
+	let _ = vec[3];
+}
+
+#[test]
+fn from_slice() -> Result<()> {
+	{
+		let src = (0..20).map(|i| DMatch::new_index(i, 19 - i, i % 3, i as f32 * 0.25)).collect::<Result<Vec<_>>>()?;
+		let vec = VectorOfDMatch::from(src.as_slice());
+		assert_eq!(src, vec.to_vec());
+		let vec = VectorOfDMatch::from(src.clone());
+		assert_eq!(src.as_slice(), vec.as_slice());
+	}
+
+	{
+		let src = vec![Point2f::new(1.5, -2.), Point2f::new(0., f32::MAX), Point2f::new(-0.25, 1e-7)];
+		let vec = VectorOfPoint2f::from(&src[..]);
+		assert_eq!(src, vec.to_vec());
+		assert_eq!(src, VectorOfPoint2f::from(src.clone()).to_vec());
+	}
+
+	{
+		let src: &[i8] = &[i8::MIN, -1, 0, 1, i8::MAX];
+		let vec = VectorOfi8::from(src);
+		assert_eq!(src, vec.to_vec().as_slice());
+		assert_eq!(src, VectorOfi8::from(src.to_vec()).as_slice());
+		assert!(VectorOfi8::from(&[][..]).is_empty());
+	}
+
+	Ok(())
+}
+
 #[test]
 fn as_slice() -> Result<()> {
 	{
@@ -606,3 +701,43 @@ fn clone() -> Result<()> {
 
 	Ok(())
 }
+
+#[test]
+fn generic_api() -> Result<()> {
+	{
+		let mut vec = core::Vector::<DMatch>::with_capacity(3);
+		assert!(vec.is_empty());
+		assert!(vec.capacity() >= 3);
+		vec.push(DMatch::new(0, 5, 1.5)?);
+		vec.push(DMatch::new(1, 6, 2.5)?);
+		vec.set(1, DMatch::new_index(1, 7, 2, 3.5)?)?;
+		assert_eq!(2, vec.len());
+		assert_eq!(7, vec.get(1)?.train_idx);
+		assert_eq!(2, vec.get(1)?.img_idx);
+		assert_matches!(vec.get(2), Err(Error { code: core::StsOutOfRange, .. }));
+		let alias: VectorOfDMatch = vec.clone();
+		let items = alias.to_vec();
+		assert_eq!(vec![0, 1], items.iter().map(|m| m.query_idx).collect::<Vec<_>>());
+		let collected = core::Vector::<DMatch>::from_iter(items);
+		assert_eq!(vec.as_slice(), collected.as_slice());
+		vec.clear();
+		assert!(vec.is_empty());
+	}
+	{
+		let mut vec = core::Vector::<Mat>::new();
+		vec.push(Mat::new_rows_cols_with_default(2, 2, u8::typ(), Scalar::all(1.))?);
+		vec.push(Mat::new_rows_cols_with_default(3, 3, u8::typ(), Scalar::all(2.))?);
+		vec.set(0, Mat::new_rows_cols_with_default(4, 4, u8::typ(), Scalar::all(3.))?)?;
+		assert_eq!(2, vec.len());
+		assert_eq!(16, vec.get(0)?.total()?);
+		assert_eq!(3, *vec.get(0)?.at_2d::<u8>(1, 1)?);
+		let alias: &VectorOfMat = &vec;
+		let mats = alias.to_vec();
+		assert_eq!(9, mats[1].total()?);
+		let collected = core::Vector::<Mat>::from_iter(mats);
+		assert_eq!(2, collected.len());
+		vec.clear();
+		assert!(vec.is_empty());
+	}
+	Ok(())
+}
@@ -0,0 +1,45 @@
+#![cfg(ocvrs_has_module_videoio)]
+
+use std::time::Duration;
+
+use opencv::{
+	core::{self, Scalar, Size},
+	prelude::*,
+	Result,
+	videoio::{self, VideoWriter},
+};
+
+fn write_video(path: &str, frames: i32, fps: f64) -> Result<()> {
+	let fourcc = VideoWriter::fourcc('M' as i8, 'J' as i8, 'P' as i8, 'G' as i8)?;
+	let mut writer = VideoWriter::new(path, fourcc, fps, Size::new(32, 32), true)?;
+	assert!(writer.is_opened()?);
+	for i in 0..frames {
+		let frame = Mat::new_rows_cols_with_default(32, 32, core::CV_8UC3, Scalar::all(f64::from(i * 20)))?;
+		writer.write(&frame)?;
+	}
+	writer.release()
+}
+
+#[test]
+fn frame_iter() -> Result<()> {
+	let path = std::env::temp_dir().join("ocvrs_frame_iter.avi");
+	let path = path.to_str().unwrap();
+	write_video(path, 10, 10.)?;
+
+	let frames = videoio::frames(path)?.collect::<Result<Vec<_>>>()?;
+	assert_eq!(10, frames.len());
+	assert_eq!(Size::new(32, 32), frames[0].size()?);
+
+	assert_eq!(4, videoio::frames(path)?.with_stride(3).count());
+	assert_eq!(10, videoio::frames(path)?.with_stride(0).count());
+	assert_eq!(5, videoio::frames(path)?.take_duration(Duration::from_millis(500))?.count());
+	assert_eq!(3, videoio::frames(path)?.with_stride(2).take_duration(Duration::from_millis(500))?.count());
+
+	let mut iter = videoio::frames(path)?;
+	assert_eq!(10, iter.by_ref().count());
+	assert!(iter.next().is_none());
+
+	assert!(videoio::frames("non-existent.avi").is_err());
+	std::fs::remove_file(path).ok();
+	Ok(())
+}